@@ -0,0 +1,94 @@
+#![allow(unexpected_cfgs)]
+#![allow(clippy::result_large_err)]
+//! Minimal caller of `mirage_bridge::bridge_cpi::burn_from_program`.
+//!
+//! A `["vault"]` PDA owns a MIRAGE token account and bridges from it. Not
+//! deployed; the tests load it to exercise the CPI path end to end.
+use anchor_lang::prelude::*;
+use mirage_bridge::bridge_cpi::{self, BurnFromProgramAccounts};
+use mirage_bridge::program::MirageBridge;
+use mirage_bridge::state::MirageRecipient;
+
+declare_id!("FuR3b5srq8qMf4M93LKJqjyJqeyzphNNCLTPmgVM3xhj");
+
+#[program]
+pub mod bridge_caller {
+    use super::*;
+
+    pub fn bridge_from_vault(
+        ctx: Context<BridgeFromVault>,
+        mirage_recipient: MirageRecipient,
+        amount: u64,
+    ) -> Result<()> {
+        let bump = [ctx.bumps.vault];
+        let signer_seeds: &[&[u8]] = &[b"vault", &bump];
+
+        let accounts = BurnFromProgramAccounts {
+            bridge_program: ctx.accounts.bridge_program.to_account_info(),
+            program_signer: ctx.accounts.vault.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            caller_program: ctx.accounts.caller_program.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            token_mint: ctx.accounts.token_mint.to_account_info(),
+            bridge_config: ctx.accounts.bridge_config.to_account_info(),
+            burn_shard: ctx.accounts.burn_shard.to_account_info(),
+            burn_record: ctx.accounts.burn_record.to_account_info(),
+            owner_denylist_shard: ctx.accounts.owner_denylist_shard.to_account_info(),
+            signer_denylist_shard: ctx.accounts.signer_denylist_shard.to_account_info(),
+            recipient_denylist_shard: ctx.accounts.recipient_denylist_shard.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        };
+
+        bridge_cpi::burn_from_program(accounts, mirage_recipient, amount, signer_seeds)
+    }
+}
+
+/// Bridge accounts are passed through unchecked; the bridge validates them
+#[derive(Accounts)]
+pub struct BridgeFromVault<'info> {
+    /// CHECK: Signing PDA that owns `token_account`
+    #[account(seeds = [b"vault"], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This program, recorded by the bridge as the caller
+    #[account(address = crate::ID)]
+    pub caller_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    #[account(mut)]
+    pub token_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    pub bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    #[account(mut)]
+    pub burn_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the bridge
+    #[account(mut)]
+    pub burn_record: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    pub owner_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    pub signer_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    pub recipient_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the bridge
+    pub token_program: UncheckedAccount<'info>,
+
+    pub bridge_program: Program<'info, MirageBridge>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,107 @@
+//! Typed helpers for programs that bridge to Mirage via CPI.
+//!
+//! Depend on this crate with the `cpi` feature, then call
+//! [`burn_from_program`] from an instruction whose vault PDA owns (or is a
+//! delegate of) the token account being burned. The PDA signs the CPI, and
+//! the bridge records the calling program on the burn.
+
+use anchor_lang::prelude::*;
+
+use crate::state::MirageRecipient;
+use crate::utils::{mirage_denylist_key, solana_denylist_key};
+
+/// Accounts for [`burn_from_program`]. The burn record and denylist shards
+/// can be derived with the address helpers below.
+#[cfg(feature = "cpi")]
+pub struct BurnFromProgramAccounts<'info> {
+    pub bridge_program: AccountInfo<'info>,
+    /// Caller's PDA that owns or is a delegate of `token_account`
+    pub program_signer: AccountInfo<'info>,
+    /// Pays for the burn record; must sign the outer transaction, or be
+    /// `program_signer` itself
+    pub payer: AccountInfo<'info>,
+    /// The calling program's own account
+    pub caller_program: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub bridge_config: AccountInfo<'info>,
+    /// Any initialized burn shard
+    pub burn_shard: AccountInfo<'info>,
+    pub burn_record: AccountInfo<'info>,
+    pub owner_denylist_shard: AccountInfo<'info>,
+    /// Shard for `program_signer` itself, checked in case it is a delegate
+    pub signer_denylist_shard: AccountInfo<'info>,
+    pub recipient_denylist_shard: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Burns `amount` from `token_account` towards `mirage_recipient`, signing
+/// as `program_signer` with `signer_seeds` (bump included)
+#[cfg(feature = "cpi")]
+pub fn burn_from_program<'info>(
+    accounts: BurnFromProgramAccounts<'info>,
+    mirage_recipient: MirageRecipient,
+    amount: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let cpi_accounts = crate::cpi::accounts::BurnFromProgram {
+        program_signer: accounts.program_signer,
+        payer: accounts.payer,
+        caller_program: accounts.caller_program,
+        token_account: accounts.token_account,
+        token_mint: accounts.token_mint,
+        bridge_config: accounts.bridge_config,
+        burn_shard: accounts.burn_shard,
+        burn_record: accounts.burn_record,
+        owner_denylist_shard: accounts.owner_denylist_shard,
+        signer_denylist_shard: accounts.signer_denylist_shard,
+        recipient_denylist_shard: accounts.recipient_denylist_shard,
+        token_program: accounts.token_program,
+        system_program: accounts.system_program,
+    };
+
+    let params = crate::instructions::BurnFromProgramParams {
+        mirage_recipient,
+        amount,
+        signer_seeds: signer_seeds.iter().map(|seed| seed.to_vec()).collect(),
+    };
+
+    crate::cpi::burn_from_program(
+        CpiContext::new_with_signer(accounts.bridge_program, cpi_accounts, &[signer_seeds]),
+        params,
+    )
+}
+
+pub fn bridge_config_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"bridge_config"], &crate::ID).0
+}
+
+pub fn token_mint_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"mint"], &crate::ID).0
+}
+
+pub fn burn_shard_address(shard_id: u16) -> Pubkey {
+    Pubkey::find_program_address(&[b"burn_shard", &shard_id.to_le_bytes()], &crate::ID).0
+}
+
+/// Burn record for `burn_id`; the next burn through a shard uses that
+/// shard's `BurnShard::next_burn_id()`
+pub fn burn_record_address(burn_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[b"burn_record", &burn_id.to_le_bytes()], &crate::ID).0
+}
+
+/// Denylist shard checked for a Solana token owner
+pub fn solana_denylist_shard_address(owner: &Pubkey) -> Pubkey {
+    denylist_shard_address(&solana_denylist_key(owner))
+}
+
+/// Denylist shard checked for a Mirage recipient in canonical (`Hex` or
+/// `Module`) form
+pub fn mirage_denylist_shard_address(recipient: &MirageRecipient) -> Pubkey {
+    denylist_shard_address(&mirage_denylist_key(recipient.as_bytes()))
+}
+
+fn denylist_shard_address(key: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[b"denylist", &[key[0]]], &crate::ID).0
+}
@@ -1,14 +1,29 @@
-pub const MAX_VALIDATORS: usize = 100;
+pub const MAX_VALIDATORS: usize = 256; // Upper bound on registry capacity
+pub const DEFAULT_VALIDATOR_CAPACITY: usize = 100;
 pub const MAX_VALIDATOR_ADDR_LEN: usize = 52; // miragevaloper1... is ~52 chars
-pub const MAX_RECIPIENT_LEN: usize = 64; // mirage1... is ~45 chars
+pub const MAX_RECIPIENT_LEN: usize = 90; // mirage1... is ~45 chars (~65 for module accounts), 90 is the bech32 limit
 pub const MAX_CHAIN_ID_LEN: usize = 32;
 pub const BASIS_POINTS_DENOMINATOR: u64 = 10000;
+pub const BURN_SHARD_COUNT: u16 = 64; // Burn counters that can be written in parallel
+pub const BURN_SHARD_BITS: u32 = 16;  // burn_id = (shard nonce << BURN_SHARD_BITS) | shard_id
+pub const DEFAULT_MAX_BURN_BATCH_SIZE: u8 = 10;
+pub const MAX_BURN_BATCH_SIZE: u8 = 25; // 2 accounts per entry + 10 fixed keys stay under the 64 account-lock limit
+pub const MAX_ROLE_MEMBERS: usize = 32;
+pub const MAX_ADMIN_ACTION_DELAY: i64 = 30 * 24 * 60 * 60; // Keeps a typo from freezing administration
+pub const MAX_ADMIN_SIGNERS: usize = 10;
 
-// Account size calculation for ValidatorRegistry:
+// `BridgeConfig.paused_flags` bits
+pub const PAUSE_BURNS: u8 = 1 << 0; // Outbound: every burn path
+pub const PAUSE_MINTS: u8 = 1 << 1; // Inbound: delivering tokens via finalize, claim, or sweep
+pub const PAUSE_ATTESTATIONS: u8 = 1 << 2; // Recording orchestrator signatures
+pub const PAUSE_FINALIZATION: u8 = 1 << 3; // Finalizing attested mint records
+pub const PAUSE_ALL: u8 = PAUSE_BURNS | PAUSE_MINTS | PAUSE_ATTESTATIONS | PAUSE_FINALIZATION;
+
+// Account size calculation for ValidatorRegistry (zero-copy):
 // - discriminator: 8 bytes
-// - validators vec length: 4 bytes
-// - validators: MAX_VALIDATORS * (32 pubkey + 4 string_len + MAX_VALIDATOR_ADDR_LEN + 8 u64)
-//             = 100 * (32 + 4 + 52 + 8) = 100 * 96 = 9,600 bytes
-// - total_stake: 8 bytes
-// - bump: 1 byte
-// Total: 8 + 4 + 9,600 + 8 + 1 = 9,621 bytes (under 10,240 limit)
+// - header: total_stake 8 + valset_nonce 8 + capacity 4 + len 4 + bump 1 + version 1
+//           + padding 6 + reserved 32 = 64 bytes
+// - entries: capacity * (32 pubkey + 8 stake + MAX_VALIDATOR_ADDR_LEN + 1 addr_len + 3 padding)
+//          = capacity * 96 bytes
+// Default capacity: 8 + 64 + 100 * 96 = 9,672 bytes (under the 10,240 byte init limit).
+// Larger sets grow the account with `resize_validator_registry`.
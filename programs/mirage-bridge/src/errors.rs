@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum BridgeError {
-    #[msg("Chain ID cannot be empty")]
+    #[msg("Chain ID must be non-empty and at most 32 bytes")]
     InvalidChainId,
     #[msg("Attestation threshold must be between 1 and 10000")]
     InvalidThreshold,
@@ -55,4 +55,158 @@ pub enum BridgeError {
     AlreadyMinted,
     #[msg("Invalid mint address")]
     InvalidMint,
+
+    // Validator registry errors
+    #[msg("Validator stake must be greater than 0")]
+    ZeroStake,
+    #[msg("Duplicate orchestrator in validator set")]
+    DuplicateValidator,
+    #[msg("Validator not found in registry")]
+    ValidatorNotFound,
+    #[msg("Invalid Mirage validator address")]
+    InvalidMirageValidator,
+    #[msg("Validator set nonce must be exactly one above the current nonce")]
+    InvalidValsetNonce,
+    #[msg("Validator set hash mismatch with existing update")]
+    ValsetHashMismatch,
+    #[msg("Authority validator set updates are disabled")]
+    AuthorityValsetUpdatesDisabled,
+    #[msg("Validator registry is full; resize it first")]
+    RegistryFull,
+    #[msg("Invalid validator registry capacity")]
+    InvalidValidatorCapacity,
+
+    // Mint finalization errors
+    #[msg("Sequence mismatch with existing record")]
+    SequenceMismatch,
+    #[msg("Attested power has not reached the threshold")]
+    ThresholdNotReached,
+    #[msg("Attestations were collected under a previous validator set")]
+    StaleAttestations,
+
+    // Claim errors
+    #[msg("Claim mode requires a positive expiry and a sweep address")]
+    InvalidClaimConfig,
+    #[msg("Recipient token account is required outside claim mode")]
+    MissingRecipientTokenAccount,
+    #[msg("Mint is not claimable")]
+    MintNotClaimable,
+    #[msg("Claim period has expired")]
+    ClaimExpired,
+    #[msg("Claim period has not expired yet")]
+    ClaimNotExpired,
+    #[msg("Token account is not owned by the sweep address")]
+    InvalidSweepAccount,
+
+    // Gas drop-off errors
+    #[msg("Native drop-off exceeds the configured maximum")]
+    NativeDropoffTooLarge,
+    #[msg("Native drop-off mismatch with existing record")]
+    NativeDropoffMismatch,
+    #[msg("Gas reserve account is required for a native drop-off")]
+    MissingGasReserve,
+    #[msg("Insufficient gas reserve balance")]
+    InsufficientGasReserve,
+
+    // Denylist errors
+    #[msg("Address is on the denylist")]
+    AddressDenylisted,
+    #[msg("Wrong denylist shard for this address")]
+    InvalidDenylistShard,
+    #[msg("Address is already on the denylist")]
+    AlreadyDenylisted,
+    #[msg("Address is not on the denylist")]
+    NotDenylisted,
+
+    // Freeze errors
+    #[msg("Mint freeze authority has been renounced")]
+    FreezeAuthorityRenounced,
+
+    // Transfer limit errors
+    #[msg("Amount is below the minimum per transfer")]
+    AmountBelowMinimum,
+    #[msg("Amount is above the maximum per transfer")]
+    AmountAboveMaximum,
+    #[msg("Minimum amount exceeds maximum amount")]
+    InvalidTransferLimits,
+
+    // Burn permit errors
+    #[msg("Burn intent has expired")]
+    PermitExpired,
+    #[msg("Burn intent nonce is not the next expected nonce")]
+    InvalidPermitNonce,
+    #[msg("Burn intent is not signed by the token owner")]
+    InvalidPermitSignature,
+
+    // Burn authority errors
+    #[msg("Signer is neither the owner nor a sufficiently approved delegate")]
+    InvalidBurnAuthority,
+    #[msg("Program signer is not a PDA of the caller program")]
+    InvalidProgramSigner,
+
+    // Burn shard errors
+    #[msg("Burn shard ID is out of range")]
+    InvalidBurnShard,
+
+    // Burn batch errors
+    #[msg("Burn batch is empty or larger than the maximum batch size")]
+    InvalidBurnBatchSize,
+    #[msg("Remaining accounts do not match the burn batch entries")]
+    InvalidBurnBatchAccounts,
+    #[msg("Maximum burn batch size is out of range")]
+    InvalidMaxBurnBatchSize,
+
+    // Role errors
+    #[msg("Member already holds this role")]
+    RoleAlreadyGranted,
+    #[msg("Member does not hold this role")]
+    RoleNotGranted,
+    #[msg("Too many role members")]
+    TooManyRoleMembers,
+
+    // Authority transfer errors
+    #[msg("No authority transfer is pending for this signer")]
+    NoPendingAuthorityTransfer,
+    #[msg("Authority transfer delay has not passed")]
+    AuthorityTransferLocked,
+    #[msg("Authority transfer delay must not be negative")]
+    InvalidAuthorityTransferDelay,
+
+    // Admin timelock errors
+    #[msg("Admin timelock is on; queue this change with queue_action")]
+    TimelockRequired,
+    #[msg("Queued admin action is not ready to execute")]
+    AdminActionNotReady,
+    #[msg("Admin action delay is out of range")]
+    InvalidAdminActionDelay,
+    #[msg("Queued admin action needs accounts that were not provided")]
+    MissingActionAccounts,
+    #[msg("Staged validator set does not match the queued commit")]
+    StagedSetMismatch,
+
+    // Admin multisig errors
+    #[msg("Admin multisig is on; propose this change with queue_action")]
+    MultisigRequired,
+    #[msg("Admin signers must be distinct, at most the maximum, and meet the threshold")]
+    InvalidAdminMultisig,
+    #[msg("Signer already approved this action")]
+    AlreadyApproved,
+    #[msg("Queued admin action does not have enough approvals")]
+    InsufficientApprovals,
+
+    // Pause errors
+    #[msg("Pause flags are empty or contain unknown bits")]
+    InvalidPauseFlags,
+    #[msg("Emergency pause by validator quorum is disabled")]
+    EmergencyPauseDisabled,
+    #[msg("Emergency pause threshold is out of range")]
+    InvalidEmergencyPauseThreshold,
+
+    // Migration errors
+    #[msg("Account is already at the current layout version")]
+    AlreadyMigrated,
+    #[msg("Account uses an old layout; run its migrate instruction first")]
+    AccountNotMigrated,
+    #[msg("Account data does not match the expected legacy layout")]
+    InvalidLegacyLayout,
 }
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{AdminAction, AmountLimits, DenylistAddress, MirageRecipient, Role};
+
 #[event]
 pub struct BurnInitiated {
     pub burn_id: u64,
     pub solana_sender: Pubkey,
-    pub mirage_recipient: String,
+    pub mirage_recipient: MirageRecipient,
     pub amount: u64,
     pub timestamp: i64,
+    pub caller_program: Option<Pubkey>, // Set for burns made via `burn_from_program`
 }
 
 #[event]
@@ -28,12 +31,31 @@ pub struct MintCompleted {
 #[event]
 pub struct BridgePaused {
     pub authority: Pubkey,
+    pub changed_flags: u8, // Bits this call actually flipped
+    pub paused_flags: u8,  // Resulting `BridgeConfig.paused_flags`
     pub timestamp: i64,
 }
 
 #[event]
 pub struct BridgeUnpaused {
     pub authority: Pubkey,
+    pub changed_flags: u8, // Bits this call actually flipped
+    pub paused_flags: u8,  // Resulting `BridgeConfig.paused_flags`
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub unlock_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
     pub timestamp: i64,
 }
 
@@ -52,3 +74,322 @@ pub struct MetadataUpdated {
     pub created: bool,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ValidatorAdded {
+    pub orchestrator: Pubkey,
+    pub mirage_validator: String,
+    pub stake: u64,
+    pub total_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorRemoved {
+    pub orchestrator: Pubkey,
+    pub total_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorStakeUpdated {
+    pub orchestrator: Pubkey,
+    pub old_stake: u64,
+    pub new_stake: u64,
+    pub total_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorSetUpdated {
+    pub validator_count: u32,
+    pub total_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValsetUpdateSigned {
+    pub valset_nonce: u64,
+    pub valset_hash: [u8; 32],
+    pub signed_power: u64,
+    pub threshold: u64,
+}
+
+#[event]
+pub struct ValidatorSetRotated {
+    pub valset_nonce: u64,
+    pub valset_hash: [u8; 32],
+    pub validator_count: u32,
+    pub total_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityValsetUpdatesDisabled {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorRegistryResized {
+    pub old_capacity: u32,
+    pub new_capacity: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimConfigUpdated {
+    pub claim_mode: bool,
+    pub claim_expiry: i64,
+    pub unclaimed_sweep_address: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintClaimable {
+    pub burn_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintClaimed {
+    pub burn_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnclaimedMintSwept {
+    pub burn_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub sweep_token_account: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GasReserveFunded {
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GasReserveWithdrawn {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxNativeDropoffUpdated {
+    pub old_max_native_dropoff_lamports: u64,
+    pub new_max_native_dropoff_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NativeDropoff {
+    pub burn_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub lamports: u64,
+    pub paid: bool, // False if it would leave a fresh recipient below rent exemption
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub member: Pubkey,
+    pub role: Role,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub member: Pubkey,
+    pub role: Role,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DenylistUpdated {
+    pub address: DenylistAddress,
+    pub address_hash: [u8; 32],
+    pub denylisted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintParked {
+    pub burn_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenAccountFrozen {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub compliance_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenAccountThawed {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub compliance_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FreezeAuthorityRenounced {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferLimitsUpdated {
+    pub burn_limits: AmountLimits,
+    pub mint_limits: AmountLimits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxBurnBatchSizeUpdated {
+    pub old_max_burn_batch_size: u8,
+    pub new_max_burn_batch_size: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BurnPermitUsed {
+    pub user: Pubkey,
+    pub relayer: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BurnTotalsSynced {
+    pub shard_id: u16,
+    pub amount: u64,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionQueued {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub queued_by: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionExecuted {
+    pub action_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionCancelled {
+    pub action_id: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationThresholdUpdated {
+    pub old_attestation_threshold: u64,
+    pub new_attestation_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionApproved {
+    pub action_id: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminMultisigUpdated {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminActionDelayUpdated {
+    pub old_admin_action_delay: i64,
+    pub new_admin_action_delay: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyPauseSigned {
+    pub flags: u8,
+    pub emergency_pause_nonce: u64,
+    pub signed_power: u64,
+    pub threshold: u64,
+}
+
+#[event]
+pub struct EmergencyPaused {
+    pub emergency_pause_nonce: u64,
+    pub changed_flags: u8,
+    pub paused_flags: u8,
+    pub signed_power: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyPauseThresholdUpdated {
+    pub old_emergency_pause_threshold: u64,
+    pub new_emergency_pause_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub old_attestation_threshold: u64,
+    pub new_attestation_threshold: u64,
+    pub old_mirage_chain_id: String,
+    pub new_mirage_chain_id: String,
+    pub old_burn_limits: AmountLimits,
+    pub new_burn_limits: AmountLimits,
+    pub old_mint_limits: AmountLimits,
+    pub new_mint_limits: AmountLimits,
+    pub old_claim_expiry: i64,
+    pub new_claim_expiry: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LegacyMintRecordClosed {
+    pub burn_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub attested_power: u64, // Collected under the old payload; must be re-attested
+    pub refunded_to: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AuthorityTransferred;
+use crate::state::{BridgeConfig, BridgeState};
+
+/// Second step of an authority transfer, signed by the proposed authority
+/// once the proposal's delay has passed
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let new_authority = ctx.accounts.new_authority.key();
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    require!(
+        bridge_config.pending_authority == Some(new_authority),
+        BridgeError::NoPendingAuthorityTransfer
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= bridge_config.authority_transfer_unlock_at,
+        BridgeError::AuthorityTransferLocked
+    );
+
+    let old_authority = bridge_config.authority;
+    bridge_config.authority = new_authority;
+    bridge_config.pending_authority = None;
+    bridge_config.authority_transfer_unlock_at = 0;
+    ctx.accounts.bridge_state.authority = new_authority;
+
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Authority transferred from {} to {}",
+        old_authority,
+        new_authority
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_state"],
+        bump = bridge_state.bump
+    )]
+    pub bridge_state: Account<'info, BridgeState>,
+}
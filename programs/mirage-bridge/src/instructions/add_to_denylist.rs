@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::DenylistUpdated;
+use crate::state::{BridgeConfig, BridgeRoles, DenylistAddress, DenylistShard, Role};
+use crate::utils::{denylist_key, resize_account};
+
+/// Adds an address to the denylist, creating its shard on first use.
+/// `shard` must be the first byte of the address's denylist key.
+pub fn add_to_denylist(ctx: Context<AddToDenylist>, params: DenylistParams) -> Result<()> {
+    let key = denylist_key(&params.address)?;
+    require!(key[0] == params.shard, BridgeError::InvalidDenylistShard);
+
+    let shard = &mut ctx.accounts.denylist_shard;
+    shard.shard = params.shard;
+    shard.bump = ctx.bumps.denylist_shard;
+
+    let position = match shard.entries.binary_search(&key) {
+        Ok(_) => return err!(BridgeError::AlreadyDenylisted),
+        Err(position) => position,
+    };
+    shard.entries.insert(position, key);
+
+    let new_space = DenylistShard::space(shard.entries.len());
+    resize_account(
+        &ctx.accounts.denylist_shard.to_account_info(),
+        &ctx.accounts.compliance_authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_space,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(DenylistUpdated {
+        address: params.address,
+        address_hash: key,
+        denylisted: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DenylistParams {
+    pub address: DenylistAddress,
+    pub shard: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: DenylistParams)]
+pub struct AddToDenylist<'info> {
+    /// Pays for shard growth
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &compliance_authority.key(), Role::Compliance) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        init_if_needed,
+        payer = compliance_authority,
+        space = DenylistShard::space(0),
+        seeds = [b"denylist".as_ref(), &[params.shard]],
+        bump
+    )]
+    pub denylist_shard: Account<'info, DenylistShard>,
+
+    pub system_program: Program<'info, System>,
+}
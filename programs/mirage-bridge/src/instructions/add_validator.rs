@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ValidatorAdded;
+use crate::state::{BridgeConfig, BridgeRoles, Role, ValidatorInfo, ValidatorRegistry};
+
+pub fn add_validator(ctx: Context<AddValidator>, params: AddValidatorParams) -> Result<()> {
+    apply_add_validator(&ctx.accounts.validator_registry, params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_add_validator(
+    validator_registry: &AccountLoader<ValidatorRegistry>,
+    params: AddValidatorParams,
+) -> Result<()> {
+    let validator = params.validator;
+
+    let (mut registry, mut entries) = ValidatorRegistry::load_mut(validator_registry)?;
+    registry.insert(&mut entries, &validator)?;
+    registry.advance_valset_nonce()?;
+
+    let clock = Clock::get()?;
+    emit!(ValidatorAdded {
+        orchestrator: validator.orchestrator_pubkey,
+        mirage_validator: validator.mirage_validator,
+        stake: validator.stake,
+        total_stake: registry.total_stake,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AddValidatorParams {
+    pub validator: ValidatorInfo,
+}
+
+#[derive(Accounts)]
+pub struct AddValidator<'info> {
+    pub validator_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.authority_valset_updates_disabled @ BridgeError::AuthorityValsetUpdatesDisabled,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &validator_manager.key(), Role::ValidatorManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AdminActionApproved;
+use crate::state::{BridgeConfig, QueuedAction};
+
+/// Records an admin signer's approval of a queued action
+pub fn approve_action(ctx: Context<ApproveAction>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let queued_action = &mut ctx.accounts.queued_action;
+    require!(
+        !queued_action.approvals.contains(&approver),
+        BridgeError::AlreadyApproved
+    );
+    // Drop approvals from keys that have since left the signer set so the
+    // reserved space is never exceeded
+    let bridge_config = &ctx.accounts.bridge_config;
+    queued_action
+        .approvals
+        .retain(|a| bridge_config.is_admin_signer(a));
+    queued_action.approvals.push(approver);
+
+    let clock = Clock::get()?;
+    emit!(AdminActionApproved {
+        action_id: queued_action.action_id,
+        approver,
+        approvals: queued_action.approvals.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveAction<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.is_admin_signer(&approver.key()) @ BridgeError::Unauthorized
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"queued_action", &queued_action.action_id.to_le_bytes()[..]],
+        bump = queued_action.bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+
+use crate::constants::PAUSE_ATTESTATIONS;
+use crate::errors::BridgeError;
+use crate::events::MintAttested;
+use crate::state::{find_validator_index, BridgeConfig, BridgeState, MintRecord, ValidatorRegistry};
+use crate::utils::{build_attestation_payload, load_ed25519_signers};
+
+/// Records orchestrator signatures for an inbound transfer. Tokens are only
+/// minted by `finalize_mint` once the record has reached quorum.
+pub fn attest(ctx: Context<Attest>, params: AttestParams) -> Result<()> {
+    let bridge_config = &ctx.accounts.bridge_config;
+
+    bridge_config.check_not_paused(PAUSE_ATTESTATIONS)?;
+    require!(params.amount > 0, BridgeError::InvalidAmount);
+    bridge_config.mint_limits.check(params.amount)?;
+    require!(
+        params.native_dropoff_lamports <= bridge_config.max_native_dropoff_lamports,
+        BridgeError::NativeDropoffTooLarge
+    );
+
+    ctx.accounts.bridge_state.check_sequence(params.sequence)?;
+
+    let expected_message = build_attestation_payload(
+        ctx.program_id,
+        &bridge_config.mirage_chain_id,
+        &ctx.accounts.recipient.key(),
+        &params,
+    );
+
+    // Orchestrators are identified by their verified Ed25519 signatures, so
+    // any relayer can submit them (several per transaction if they fit)
+    let signers = load_ed25519_signers(&ctx.accounts.instructions_sysvar, &expected_message)?;
+
+    let (validator_registry, validators) = ValidatorRegistry::load(&ctx.accounts.validator_registry)?;
+    require!(
+        validator_registry.total_stake > 0,
+        BridgeError::InvalidValidatorSet
+    );
+
+    let attestors: Vec<(usize, Pubkey, u64)> = signers
+        .into_iter()
+        .filter_map(|signer| {
+            find_validator_index(&validators, &signer).map(|i| (i, signer, validators[i].stake))
+        })
+        .collect();
+    require!(!attestors.is_empty(), BridgeError::UnauthorizedOrchestrator);
+
+    let total_stake = validator_registry.total_stake;
+    let valset_nonce = validator_registry.valset_nonce;
+    drop(validators);
+    drop(validator_registry);
+
+    let mint_record = &mut ctx.accounts.mint_record;
+
+    if !mint_record.is_initialized() {
+        mint_record.payer = ctx.accounts.relayer.key();
+        mint_record.burn_tx_hash = params.burn_tx_hash;
+        mint_record.recipient = ctx.accounts.recipient.key();
+        mint_record.amount = params.amount;
+        mint_record.sequence = params.sequence;
+        mint_record.native_dropoff_lamports = params.native_dropoff_lamports;
+        mint_record.reset_attestations(valset_nonce);
+        mint_record.bump = ctx.bumps.mint_record;
+        mint_record.version = MintRecord::VERSION;
+    } else {
+        require!(
+            mint_record.burn_tx_hash == params.burn_tx_hash,
+            BridgeError::BurnHashMismatch
+        );
+        require!(
+            mint_record.recipient == ctx.accounts.recipient.key(),
+            BridgeError::RecipientMismatch
+        );
+        require!(
+            mint_record.amount == params.amount,
+            BridgeError::AmountMismatch
+        );
+        // Both sequences are signed, so a mismatch means orchestrators
+        // signed the same burn twice with different sequences
+        require!(
+            mint_record.sequence == params.sequence,
+            BridgeError::SequenceMismatch
+        );
+        require!(
+            mint_record.native_dropoff_lamports == params.native_dropoff_lamports,
+            BridgeError::NativeDropoffMismatch
+        );
+    }
+
+    // Every registry change, stake-only updates included, discards the
+    // attestations collected so far. In-flight transfers stall until a
+    // relayer resubmits the signatures; they do not bind the nonce, so
+    // orchestrators do not need to sign again.
+    if mint_record.valset_nonce != valset_nonce {
+        mint_record.reset_attestations(valset_nonce);
+    }
+
+    let required_stake = bridge_config.required_stake(total_stake)?;
+
+    for (index, orchestrator, stake) in attestors {
+        if mint_record.has_attested(index) {
+            continue;
+        }
+
+        mint_record.record_attestation(index);
+        mint_record.attested_power = mint_record
+            .attested_power
+            .checked_add(stake)
+            .ok_or(BridgeError::PowerOverflow)?;
+
+        emit!(MintAttested {
+            burn_tx_hash: params.burn_tx_hash,
+            orchestrator,
+            current_power: mint_record.attested_power,
+            threshold: required_stake,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AttestParams {
+    pub burn_tx_hash: [u8; 32],
+    pub mirage_sender: String,
+    pub amount: u64,
+    pub sequence: u64,
+    pub native_dropoff_lamports: u64, // Optional SOL for the recipient; 0 for none
+}
+
+#[derive(Accounts)]
+#[instruction(params: AttestParams)]
+pub struct Attest<'info> {
+    /// Pays for the mint record (refunded on finalization); need not be a validator
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// CHECK: Recipient wallet, bound into the signed payload
+    pub recipient: AccountInfo<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_state"],
+        bump = bridge_state.bump
+    )]
+    pub bridge_state: Account<'info, BridgeState>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + MintRecord::INIT_SPACE,
+        seeds = [b"mint_record", &params.burn_tx_hash[..]],
+        bump
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+
+    /// CHECK: Only created once the transfer is minted; must not exist yet
+    #[account(
+        seeds = [b"processed_mint", &params.burn_tx_hash[..]],
+        bump,
+        constraint = processed_mint.data_is_empty() @ BridgeError::AlreadyMinted
+    )]
+    pub processed_mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+
+    /// CHECK: Instructions sysvar for Ed25519 verification
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
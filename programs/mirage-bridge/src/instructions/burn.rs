@@ -1,18 +1,25 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Token, TokenAccount};
 
-use crate::errors::BridgeError;
-use crate::events::BurnInitiated;
-use crate::state::{BridgeConfig, BurnRecord};
-use crate::utils::validate_mirage_address;
+use crate::state::{BridgeConfig, BurnRecord, BurnShard, MirageRecipient};
+use crate::utils::{check_burn_authority, check_burn_signer, record_burn, validate_burn};
 
+/// Burns from any token account of the bridged mint that `user` owns or is
+/// an approved delegate of. The account owner is recorded as the sender.
 pub fn burn(ctx: Context<BurnTokens>, params: BurnParams) -> Result<()> {
-    let bridge_config = &ctx.accounts.bridge_config;
-
-    require!(!bridge_config.paused, BridgeError::BridgePaused);
-    require!(params.amount > 0, BridgeError::InvalidAmount);
-
-    validate_mirage_address(&params.mirage_recipient)?;
+    let user = ctx.accounts.user.key();
+    let owner = ctx.accounts.user_token_account.owner;
+    check_burn_authority(&ctx.accounts.user_token_account, &user, params.amount)?;
+    check_burn_signer(&user, &ctx.accounts.signer_denylist_shard)?;
+
+    let mirage_recipient = validate_burn(
+        &ctx.accounts.bridge_config,
+        &owner,
+        &params.mirage_recipient,
+        params.amount,
+        &ctx.accounts.user_denylist_shard,
+        &ctx.accounts.recipient_denylist_shard,
+    )?;
 
     token::burn(
         CpiContext::new(
@@ -26,54 +33,31 @@ pub fn burn(ctx: Context<BurnTokens>, params: BurnParams) -> Result<()> {
         params.amount,
     )?;
 
-    let bridge_config = &mut ctx.accounts.bridge_config;
-    let current_nonce = bridge_config.burn_nonce;
-    bridge_config.burn_nonce = bridge_config
-        .burn_nonce
-        .checked_add(1)
-        .ok_or(BridgeError::NonceOverflow)?;
-    bridge_config.total_burned = bridge_config
-        .total_burned
-        .checked_add(params.amount)
-        .ok_or(BridgeError::AmountOverflow)?;
-
-    let clock = Clock::get()?;
-    let burn_record = &mut ctx.accounts.burn_record;
-    burn_record.burn_id = current_nonce;
-    burn_record.solana_sender = ctx.accounts.user.key();
-    burn_record.mirage_recipient = params.mirage_recipient.clone();
-    burn_record.amount = params.amount;
-    burn_record.timestamp = clock.unix_timestamp;
-    burn_record.bump = ctx.bumps.burn_record;
-
-    emit!(BurnInitiated {
-        burn_id: current_nonce,
-        solana_sender: ctx.accounts.user.key(),
-        mirage_recipient: params.mirage_recipient,
-        amount: params.amount,
-        timestamp: clock.unix_timestamp,
-    });
-
-    Ok(())
+    record_burn(
+        &mut ctx.accounts.burn_shard,
+        &mut ctx.accounts.burn_record,
+        ctx.bumps.burn_record,
+        owner,
+        mirage_recipient,
+        params.amount,
+        None,
+    )
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BurnParams {
-    pub mirage_recipient: String,
+    pub mirage_recipient: MirageRecipient,
     pub amount: u64,
 }
 
 #[derive(Accounts)]
 #[instruction(params: BurnParams)]
 pub struct BurnTokens<'info> {
+    /// Token account owner or approved delegate
     #[account(mut)]
     pub user: Signer<'info>,
 
-    #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = user
-    )]
+    #[account(mut, token::mint = token_mint)]
     pub user_token_account: Account<'info, TokenAccount>,
 
     #[account(
@@ -84,21 +68,37 @@ pub struct BurnTokens<'info> {
     pub token_mint: Account<'info, anchor_spl::token::Mint>,
 
     #[account(
-        mut,
         seeds = [b"bridge_config"],
         bump = bridge_config.bump
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
 
+    /// Any initialized shard; clients spread burns across shards
+    #[account(
+        mut,
+        seeds = [b"burn_shard", &burn_shard.shard_id.to_le_bytes()[..]],
+        bump = burn_shard.bump
+    )]
+    pub burn_shard: Account<'info, BurnShard>,
+
    #[account(
        init,
        payer = user,
         space = 8 + BurnRecord::INIT_SPACE,
-       seeds = [b"burn_record", &bridge_config.burn_nonce.to_le_bytes()[..]],
+       seeds = [b"burn_record", &burn_shard.next_burn_id().to_le_bytes()[..]],
        bump
    )]
     pub burn_record: Account<'info, BurnRecord>,
 
+    /// CHECK: Denylist shard for the token account owner; validated in `is_denylisted`
+    pub user_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Denylist shard for `user`, which may be a delegate; validated in `is_denylisted`
+    pub signer_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Denylist shard for the Mirage recipient; validated in `is_denylisted`
+    pub recipient_denylist_shard: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
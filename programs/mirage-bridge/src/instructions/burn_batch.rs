@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Token, TokenAccount};
+
+use crate::errors::BridgeError;
+use crate::state::{BridgeConfig, BurnRecord, BurnShard, MirageRecipient};
+use crate::utils::{
+    check_burn_authority, check_burn_signer, create_pda_account, record_burn, validate_burn,
+};
+
+/// Burns the batch total from one token account in a single transfer and
+/// records one outbound transfer per entry, each with its own burn ID and
+/// `BurnInitiated` event.
+///
+/// Remaining accounts, per entry in order: the recipient's denylist shard,
+/// then the burn record PDA for the entry's burn ID. Burn IDs are issued
+/// consecutively from `burn_shard`.
+pub fn burn_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BurnBatch<'info>>,
+    params: BurnBatchParams,
+) -> Result<()> {
+    let entries = &params.entries;
+    require!(
+        !entries.is_empty()
+            && entries.len() <= ctx.accounts.bridge_config.max_burn_batch_size as usize,
+        BridgeError::InvalidBurnBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * 2,
+        BridgeError::InvalidBurnBatchAccounts
+    );
+
+    let user = ctx.accounts.user.key();
+    let owner = ctx.accounts.user_token_account.owner;
+
+    let mut total: u64 = 0;
+    let mut recipients = Vec::with_capacity(entries.len());
+    for (entry, accounts) in entries.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        recipients.push(validate_burn(
+            &ctx.accounts.bridge_config,
+            &owner,
+            &entry.mirage_recipient,
+            entry.amount,
+            &ctx.accounts.user_denylist_shard,
+            &accounts[0],
+        )?);
+        total = total
+            .checked_add(entry.amount)
+            .ok_or(BridgeError::AmountOverflow)?;
+    }
+    check_burn_authority(&ctx.accounts.user_token_account, &user, total)?;
+    check_burn_signer(&user, &ctx.accounts.signer_denylist_shard)?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                from: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        total,
+    )?;
+
+    let space = 8 + BurnRecord::INIT_SPACE;
+    for ((entry, mirage_recipient), accounts) in entries
+        .iter()
+        .zip(recipients)
+        .zip(ctx.remaining_accounts.chunks(2))
+    {
+        let burn_record_info = &accounts[1];
+        let burn_id_bytes = ctx.accounts.burn_shard.next_burn_id().to_le_bytes();
+        let (expected, bump) =
+            Pubkey::find_program_address(&[b"burn_record", &burn_id_bytes], &crate::ID);
+        require_keys_eq!(
+            burn_record_info.key(),
+            expected,
+            BridgeError::InvalidBurnBatchAccounts
+        );
+
+        create_pda_account(
+            burn_record_info,
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            space,
+            &[b"burn_record", &burn_id_bytes, &[bump]],
+        )?;
+
+        let mut burn_record = BurnRecord {
+            burn_id: 0,
+            solana_sender: owner,
+            mirage_recipient: mirage_recipient.clone(),
+            amount: entry.amount,
+            timestamp: 0,
+            bump,
+            caller_program: None,
+            version: BurnRecord::VERSION,
+            _reserved: [0; 16],
+        };
+        record_burn(
+            &mut ctx.accounts.burn_shard,
+            &mut burn_record,
+            bump,
+            owner,
+            mirage_recipient,
+            entry.amount,
+            None,
+        )?;
+
+        let mut data = burn_record_info.try_borrow_mut_data()?;
+        burn_record.try_serialize(&mut &mut data[..])?;
+    }
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BurnBatchEntry {
+    pub mirage_recipient: MirageRecipient,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BurnBatchParams {
+    pub entries: Vec<BurnBatchEntry>,
+}
+
+#[derive(Accounts)]
+pub struct BurnBatch<'info> {
+    /// Token account owner or approved delegate; pays for the burn records
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(mut, token::mint = token_mint)]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Any initialized shard; issues one burn ID per entry
+    #[account(
+        mut,
+        seeds = [b"burn_shard", &burn_shard.shard_id.to_le_bytes()[..]],
+        bump = burn_shard.bump
+    )]
+    pub burn_shard: Account<'info, BurnShard>,
+
+    /// CHECK: Denylist shard for the token account owner; validated in `is_denylisted`
+    pub user_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Denylist shard for `user`, which may be a delegate; validated in `is_denylisted`
+    pub signer_denylist_shard: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
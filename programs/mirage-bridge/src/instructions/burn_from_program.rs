@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Token, TokenAccount};
+
+use crate::errors::BridgeError;
+use crate::state::{BridgeConfig, BurnRecord, BurnShard, MirageRecipient};
+use crate::utils::{check_burn_authority, check_burn_signer, record_burn, validate_burn};
+
+/// Entry point for other programs bridging from PDA-owned token accounts.
+///
+/// `program_signer` is a PDA of `caller_program` that signs the CPI with
+/// `invoke_signed`. Re-deriving it from `signer_seeds` proves which program
+/// made the call, and that program is recorded on the burn. The PDA must
+/// own, or be an approved delegate of, `token_account`. See `bridge_cpi`
+/// for typed builders.
+pub fn burn_from_program(
+    ctx: Context<BurnFromProgram>,
+    params: BurnFromProgramParams,
+) -> Result<()> {
+    let caller_program = ctx.accounts.caller_program.key();
+    let program_signer = ctx.accounts.program_signer.key();
+
+    let seeds: Vec<&[u8]> = params.signer_seeds.iter().map(|s| s.as_slice()).collect();
+    let derived = Pubkey::create_program_address(&seeds, &caller_program)
+        .map_err(|_| BridgeError::InvalidProgramSigner)?;
+    require_keys_eq!(derived, program_signer, BridgeError::InvalidProgramSigner);
+
+    let owner = ctx.accounts.token_account.owner;
+    check_burn_authority(&ctx.accounts.token_account, &program_signer, params.amount)?;
+    check_burn_signer(&program_signer, &ctx.accounts.signer_denylist_shard)?;
+
+    let mirage_recipient = validate_burn(
+        &ctx.accounts.bridge_config,
+        &owner,
+        &params.mirage_recipient,
+        params.amount,
+        &ctx.accounts.owner_denylist_shard,
+        &ctx.accounts.recipient_denylist_shard,
+    )?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                from: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.program_signer.to_account_info(),
+            },
+        ),
+        params.amount,
+    )?;
+
+    record_burn(
+        &mut ctx.accounts.burn_shard,
+        &mut ctx.accounts.burn_record,
+        ctx.bumps.burn_record,
+        owner,
+        mirage_recipient,
+        params.amount,
+        Some(caller_program),
+    )
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BurnFromProgramParams {
+    pub mirage_recipient: MirageRecipient,
+    pub amount: u64,
+    /// Seeds of `program_signer` under `caller_program`, bump included
+    pub signer_seeds: Vec<Vec<u8>>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: BurnFromProgramParams)]
+pub struct BurnFromProgram<'info> {
+    /// PDA of `caller_program`, signed for via `invoke_signed`
+    pub program_signer: Signer<'info>,
+
+    /// Pays for the burn record; may be the same PDA if it holds lamports
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Program that owns `program_signer`; verified by re-deriving it
+    #[account(executable)]
+    pub caller_program: UncheckedAccount<'info>,
+
+    #[account(mut, token::mint = token_mint)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Any initialized shard; clients spread burns across shards
+    #[account(
+        mut,
+        seeds = [b"burn_shard", &burn_shard.shard_id.to_le_bytes()[..]],
+        bump = burn_shard.bump
+    )]
+    pub burn_shard: Account<'info, BurnShard>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", &burn_shard.next_burn_id().to_le_bytes()[..]],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    /// CHECK: Denylist shard for the token account owner; validated in `is_denylisted`
+    pub owner_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Denylist shard for `program_signer`, which may be a delegate; validated in `is_denylisted`
+    pub signer_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Denylist shard for the Mirage recipient; validated in `is_denylisted`
+    pub recipient_denylist_shard: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use anchor_spl::token::{self, Burn, Token, TokenAccount};
+
+use crate::errors::BridgeError;
+use crate::events::BurnPermitUsed;
+use crate::state::{BridgeConfig, BurnRecord, BurnShard, MirageRecipient, PermitNonce};
+use crate::utils::{
+    build_burn_intent_payload, check_burn_signer, load_ed25519_signers, record_burn, validate_burn,
+};
+
+/// Burns on behalf of a user who signed a burn intent with their wallet key
+/// (proven by an Ed25519 precompile instruction). A relayer submits it and
+/// pays all fees and rent. The user must have approved the `bridge_config`
+/// PDA as delegate on their token account for at least `amount`.
+pub fn burn_with_permit(ctx: Context<BurnWithPermit>, params: BurnWithPermitParams) -> Result<()> {
+    let user = ctx.accounts.user.key();
+
+    let mirage_recipient = validate_burn(
+        &ctx.accounts.bridge_config,
+        &user,
+        &params.mirage_recipient,
+        params.amount,
+        &ctx.accounts.user_denylist_shard,
+        &ctx.accounts.recipient_denylist_shard,
+    )?;
+    check_burn_signer(
+        &ctx.accounts.relayer.key(),
+        &ctx.accounts.relayer_denylist_shard,
+    )?;
+
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp <= params.expiry, BridgeError::PermitExpired);
+
+    let permit_nonce = &mut ctx.accounts.permit_nonce;
+    require!(
+        params.nonce == permit_nonce.next_nonce,
+        BridgeError::InvalidPermitNonce
+    );
+
+    let expected_message = build_burn_intent_payload(
+        &crate::ID,
+        &ctx.accounts.bridge_config.mirage_chain_id,
+        &user,
+        &mirage_recipient,
+        params.amount,
+        params.nonce,
+        params.expiry,
+    );
+    let signers = load_ed25519_signers(&ctx.accounts.instructions_sysvar, &expected_message)?;
+    require!(signers.contains(&user), BridgeError::InvalidPermitSignature);
+
+    permit_nonce.next_nonce = permit_nonce
+        .next_nonce
+        .checked_add(1)
+        .ok_or(BridgeError::NonceOverflow)?;
+    permit_nonce.bump = ctx.bumps.permit_nonce;
+
+    let bridge_config = &ctx.accounts.bridge_config;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"bridge_config", &[bridge_config.bump]]];
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                from: ctx.accounts.user_token_account.to_account_info(),
+                authority: bridge_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        params.amount,
+    )?;
+
+    record_burn(
+        &mut ctx.accounts.burn_shard,
+        &mut ctx.accounts.burn_record,
+        ctx.bumps.burn_record,
+        user,
+        mirage_recipient,
+        params.amount,
+        None,
+    )?;
+
+    emit!(BurnPermitUsed {
+        user,
+        relayer: ctx.accounts.relayer.key(),
+        nonce: params.nonce,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BurnWithPermitParams {
+    pub mirage_recipient: MirageRecipient,
+    pub amount: u64,
+    pub nonce: u64,  // Must equal the user's `PermitNonce::next_nonce`
+    pub expiry: i64, // Unix timestamp after which the intent is void
+}
+
+#[derive(Accounts)]
+#[instruction(params: BurnWithPermitParams)]
+pub struct BurnWithPermit<'info> {
+    /// Pays fees and rent; need not hold any tokens
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// CHECK: Token owner; authorizes the burn by signing the intent
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Any initialized shard; clients spread burns across shards
+    #[account(
+        mut,
+        seeds = [b"burn_shard", &burn_shard.shard_id.to_le_bytes()[..]],
+        bump = burn_shard.bump
+    )]
+    pub burn_shard: Account<'info, BurnShard>,
+
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", &burn_shard.next_burn_id().to_le_bytes()[..]],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + PermitNonce::INIT_SPACE,
+        seeds = [b"permit_nonce", user.key().as_ref()],
+        bump
+    )]
+    pub permit_nonce: Account<'info, PermitNonce>,
+
+    /// CHECK: Denylist shard for `user`; validated in `is_denylisted`
+    pub user_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Denylist shard for `relayer`; validated in `is_denylisted`
+    pub relayer_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Denylist shard for the Mirage recipient; validated in `is_denylisted`
+    pub recipient_denylist_shard: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar for Ed25519 verification
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AdminActionCancelled;
+use crate::state::{can_manage_admin_actions, BridgeConfig, BridgeRoles, QueuedAction};
+
+/// Drops a queued admin action and refunds its rent to whoever queued it.
+/// While the multisig is on, any admin signer can veto a proposal this way.
+pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+    let clock = Clock::get()?;
+    emit!(AdminActionCancelled {
+        action_id: ctx.accounts.queued_action.action_id,
+        cancelled_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = can_manage_admin_actions(&bridge_config, &bridge_roles, &admin.key()) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [b"queued_action", &queued_action.action_id.to_le_bytes()[..]],
+        bump = queued_action.bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// CHECK: Rent refund destination, pinned to the original payer
+    #[account(mut, address = queued_action.queued_by)]
+    pub queued_by: UncheckedAccount<'info>,
+}
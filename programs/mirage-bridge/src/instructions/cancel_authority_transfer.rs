@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AuthorityTransferCancelled;
+use crate::state::BridgeConfig;
+
+/// Withdraws a pending authority proposal before it is accepted
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let pending_authority = bridge_config
+        .pending_authority
+        .take()
+        .ok_or(BridgeError::NoPendingAuthorityTransfer)?;
+    bridge_config.authority_transfer_unlock_at = 0;
+
+    let clock = Clock::get()?;
+    emit!(AuthorityTransferCancelled {
+        authority: bridge_config.authority,
+        pending_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = authority @ BridgeError::Unauthorized
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+}
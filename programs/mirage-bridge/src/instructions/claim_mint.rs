@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::PAUSE_MINTS;
+use crate::errors::BridgeError;
+use crate::events::{MintClaimed, MintCompleted};
+use crate::state::{BridgeConfig, GasReserve, MintRecord};
+use crate::utils::{is_denylisted, mint_bridged_tokens, pay_native_dropoff, solana_denylist_key};
+
+/// Delivers a claimable mint into any token account owned by the recipient.
+/// The recipient pays for their own token account.
+pub fn claim_mint(ctx: Context<ClaimMint>, _params: ClaimMintParams) -> Result<()> {
+    ctx.accounts.bridge_config.check_not_paused(PAUSE_MINTS)?;
+
+    let mint_record = &ctx.accounts.mint_record;
+    require!(mint_record.is_claimable(), BridgeError::MintNotClaimable);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < mint_record.claim_expires_at,
+        BridgeError::ClaimExpired
+    );
+    require!(
+        !is_denylisted(
+            &ctx.accounts.recipient_denylist_shard,
+            &solana_denylist_key(&mint_record.recipient)
+        )?,
+        BridgeError::AddressDenylisted
+    );
+
+    mint_bridged_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.recipient_token_account.to_account_info(),
+        &mut ctx.accounts.bridge_config,
+        mint_record.amount,
+    )?;
+
+    pay_native_dropoff(
+        ctx.accounts.gas_reserve.as_ref(),
+        &ctx.accounts.recipient.to_account_info(),
+        mint_record,
+    )?;
+
+    emit!(MintClaimed {
+        burn_tx_hash: mint_record.burn_tx_hash,
+        recipient: mint_record.recipient,
+        token_account: ctx.accounts.recipient_token_account.key(),
+        amount: mint_record.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(MintCompleted {
+        burn_tx_hash: mint_record.burn_tx_hash,
+        recipient: mint_record.recipient,
+        amount: mint_record.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimMintParams {
+    pub burn_tx_hash: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(params: ClaimMintParams)]
+pub struct ClaimMint<'info> {
+    #[account(mut, address = mint_record.recipient @ BridgeError::RecipientMismatch)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: Payer that funded MintRecord (rent refund target)
+    #[account(mut, address = mint_record.payer)]
+    pub mint_record_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_record", &params.burn_tx_hash[..]],
+        bump = mint_record.bump,
+        close = mint_record_payer
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+
+    /// Required only when the record carries a native drop-off
+    #[account(
+        mut,
+        seeds = [b"gas_reserve"],
+        bump = gas_reserve.bump
+    )]
+    pub gas_reserve: Option<Account<'info, GasReserve>>,
+
+    /// CHECK: Denylist shard for the recipient; validated in `is_denylisted`
+    pub recipient_denylist_shard: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
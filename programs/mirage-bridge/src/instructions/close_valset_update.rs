@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ValsetUpdate;
+
+/// Closes an abandoned or stale `ValsetUpdate` and refunds its rent
+pub fn close_valset_update(_ctx: Context<CloseValsetUpdate>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseValsetUpdate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"valset_update", payer.key().as_ref(), &valset_update.valset_nonce.to_le_bytes()[..]],
+        bump = valset_update.bump
+    )]
+    pub valset_update: Account<'info, ValsetUpdate>,
+}
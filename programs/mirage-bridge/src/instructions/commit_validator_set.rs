@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ValidatorSetUpdated;
+use crate::state::{
+    validate_validator_set, BridgeConfig, BridgeRoles, Role, ValidatorRegistry, ValidatorStaging,
+};
+
+/// Replaces the registry with the staged set and closes the staging account
+pub fn commit_validator_set(ctx: Context<CommitValidatorSet>) -> Result<()> {
+    apply_commit_validator_set(
+        &ctx.accounts.validator_registry,
+        &mut ctx.accounts.validator_staging,
+    )
+}
+
+/// Shared with `execute_action`; the caller closes the staging account
+pub fn apply_commit_validator_set(
+    validator_registry: &AccountLoader<ValidatorRegistry>,
+    validator_staging: &mut Account<ValidatorStaging>,
+) -> Result<()> {
+    let validators = std::mem::take(&mut validator_staging.validators);
+    validate_validator_set(&validators)?;
+
+    let (mut registry, mut entries) = ValidatorRegistry::load_mut(validator_registry)?;
+    registry.replace(&mut entries, &validators)?;
+    registry.advance_valset_nonce()?;
+
+    let clock = Clock::get()?;
+    emit!(ValidatorSetUpdated {
+        validator_count: registry.len,
+        total_stake: registry.total_stake,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitValidatorSet<'info> {
+    #[account(mut)]
+    pub validator_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.authority_valset_updates_disabled @ BridgeError::AuthorityValsetUpdatesDisabled,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &validator_manager.key(), Role::ValidatorManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+
+    #[account(
+        mut,
+        close = validator_manager,
+        seeds = [b"validator_staging"],
+        bump = validator_staging.bump
+    )]
+    pub validator_staging: Account<'info, ValidatorStaging>,
+}
@@ -7,7 +7,7 @@ use mpl_token_metadata::{
 
 use crate::errors::BridgeError;
 use crate::events::MetadataCreated;
-use crate::state::BridgeConfig;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
 
 pub fn create_metadata(ctx: Context<CreateMetadata>, params: CreateMetadataParams) -> Result<()> {
     let metadata_data = DataV2 {
@@ -26,7 +26,7 @@ pub fn create_metadata(ctx: Context<CreateMetadata>, params: CreateMetadataParam
         .metadata(&ctx.accounts.metadata)
         .mint(&ctx.accounts.mint.to_account_info())
         .mint_authority(&ctx.accounts.bridge_config.to_account_info())
-        .payer(&ctx.accounts.authority)
+        .payer(&ctx.accounts.metadata_manager)
         .update_authority(&ctx.accounts.bridge_config.to_account_info(), true)
         .system_program(&ctx.accounts.system_program)
         .data(metadata_data)
@@ -35,7 +35,7 @@ pub fn create_metadata(ctx: Context<CreateMetadata>, params: CreateMetadataParam
 
     let clock = Clock::get()?;
     emit!(MetadataCreated {
-        authority: ctx.accounts.authority.key(),
+        authority: ctx.accounts.metadata_manager.key(),
         mint: ctx.accounts.mint.key(),
         metadata: ctx.accounts.metadata.key(),
         timestamp: clock.unix_timestamp,
@@ -54,16 +54,22 @@ pub struct CreateMetadataParams {
 #[derive(Accounts)]
 pub struct CreateMetadata<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub metadata_manager: Signer<'info>,
 
     #[account(
         seeds = [b"bridge_config"],
         bump = bridge_config.bump,
-        has_one = authority @ BridgeError::Unauthorized,
         has_one = mint @ BridgeError::InvalidMint
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
 
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &metadata_manager.key(), Role::MetadataManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
     #[account(
         seeds = [b"mint"],
         bump
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AuthorityValsetUpdatesDisabled;
+use crate::state::{BridgeConfig, BridgeRoles, Role, ValidatorRegistry};
+
+/// Permanently removes the authority's and validator managers' ability to
+/// change the validator set.
+/// Afterwards only `rotate_validator_set`, signed by the current set, can.
+pub fn disable_authority_valset_updates(ctx: Context<DisableAuthorityValsetUpdates>) -> Result<()> {
+    apply_disable_authority_valset_updates(
+        &mut ctx.accounts.bridge_config,
+        &ctx.accounts.validator_registry,
+        ctx.accounts.admin.key(),
+    )
+}
+
+/// Shared with `execute_action`
+pub fn apply_disable_authority_valset_updates(
+    bridge_config: &mut BridgeConfig,
+    validator_registry: &AccountLoader<ValidatorRegistry>,
+    admin: Pubkey,
+) -> Result<()> {
+    require!(
+        !bridge_config.authority_valset_updates_disabled,
+        BridgeError::AuthorityValsetUpdatesDisabled
+    );
+    // Refuse to lock in a set that could never sign a rotation
+    require!(
+        validator_registry.load()?.total_stake > 0,
+        BridgeError::InvalidValidatorSet
+    );
+
+    bridge_config.authority_valset_updates_disabled = true;
+
+    let clock = Clock::get()?;
+    emit!(AuthorityValsetUpdatesDisabled {
+        authority: admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DisableAuthorityValsetUpdates<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.authority_valset_updates_disabled @ BridgeError::AuthorityValsetUpdatesDisabled,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+}
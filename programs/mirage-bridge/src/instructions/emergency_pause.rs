@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+
+use crate::errors::BridgeError;
+use crate::events::{EmergencyPauseSigned, EmergencyPaused};
+use crate::instructions::PauseParams;
+use crate::state::{find_validator_index, BridgeConfig, PauseSignatures, ValidatorRegistry};
+use crate::utils::{build_emergency_pause_payload, is_bit_set, load_ed25519_signers, set_bit};
+
+/// Pauses `params.flags` once validators holding `emergency_pause_threshold`
+/// of the stake have signed the current emergency pause message.
+///
+/// Permissionless: anyone can relay signatures, across as many transactions
+/// as needed. They accumulate in the `PauseSignatures` for these flags, which
+/// is closed to its creator when the pause lands. Only an admin can unpause.
+pub fn emergency_pause(ctx: Context<EmergencyPause>, params: PauseParams) -> Result<()> {
+    params.validate()?;
+
+    let bridge_config = &ctx.accounts.bridge_config;
+    require!(
+        bridge_config.emergency_pause_threshold > 0,
+        BridgeError::EmergencyPauseDisabled
+    );
+
+    let (valset_nonce, total_stake) = {
+        let registry = ctx.accounts.validator_registry.load()?;
+        (registry.valset_nonce, registry.total_stake)
+    };
+    require!(total_stake > 0, BridgeError::InvalidValidatorSet);
+
+    let pause_nonce = bridge_config.emergency_pause_nonce;
+    let pause_signatures = &mut ctx.accounts.pause_signatures;
+    if pause_signatures.payer == Pubkey::default() {
+        pause_signatures.payer = ctx.accounts.payer.key();
+        pause_signatures.flags = params.flags;
+        pause_signatures.bump = ctx.bumps.pause_signatures;
+        pause_signatures.reset(pause_nonce, valset_nonce);
+    } else if pause_signatures.emergency_pause_nonce != pause_nonce
+        || pause_signatures.valset_nonce != valset_nonce
+    {
+        pause_signatures.reset(pause_nonce, valset_nonce);
+    }
+
+    let expected_message = build_emergency_pause_payload(
+        ctx.program_id,
+        &bridge_config.mirage_chain_id,
+        pause_nonce,
+        params.flags,
+    );
+    let signers = load_ed25519_signers(&ctx.accounts.instructions_sysvar, &expected_message)?;
+
+    {
+        let (_, validators) = ValidatorRegistry::load(&ctx.accounts.validator_registry)?;
+        for signer in signers {
+            let Some(index) = find_validator_index(&validators, &signer) else {
+                continue;
+            };
+            if is_bit_set(&pause_signatures.signer_bitmap, index) {
+                continue;
+            }
+            set_bit(&mut pause_signatures.signer_bitmap, index);
+            pause_signatures.signed_power = pause_signatures
+                .signed_power
+                .checked_add(validators[index].stake)
+                .ok_or(BridgeError::PowerOverflow)?;
+        }
+    }
+
+    let required_stake = bridge_config.required_emergency_pause_stake(total_stake)?;
+
+    emit!(EmergencyPauseSigned {
+        flags: params.flags,
+        emergency_pause_nonce: pause_nonce,
+        signed_power: pause_signatures.signed_power,
+        threshold: required_stake,
+    });
+
+    if pause_signatures.signed_power == 0 || pause_signatures.signed_power < required_stake {
+        return Ok(());
+    }
+
+    require_keys_eq!(
+        ctx.accounts.pause_signatures_payer.key(),
+        pause_signatures.payer,
+        BridgeError::Unauthorized
+    );
+    let signed_power = pause_signatures.signed_power;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let changed_flags = params.flags & !bridge_config.paused_flags;
+    bridge_config.paused_flags |= params.flags;
+    bridge_config.emergency_pause_nonce = pause_nonce
+        .checked_add(1)
+        .ok_or(BridgeError::NonceOverflow)?;
+
+    let clock = Clock::get()?;
+    emit!(EmergencyPaused {
+        emergency_pause_nonce: pause_nonce,
+        changed_flags,
+        paused_flags: bridge_config.paused_flags,
+        signed_power,
+        timestamp: clock.unix_timestamp,
+    });
+
+    ctx.accounts
+        .pause_signatures
+        .close(ctx.accounts.pause_signatures_payer.to_account_info())
+}
+
+#[derive(Accounts)]
+#[instruction(params: PauseParams)]
+pub struct EmergencyPause<'info> {
+    /// Relays signatures; pays for `pause_signatures` if it does not exist
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PauseSignatures::INIT_SPACE,
+        seeds = [b"pause_signatures", [params.flags].as_ref()],
+        bump
+    )]
+    pub pause_signatures: Account<'info, PauseSignatures>,
+
+    /// CHECK: Creator of `pause_signatures`, refunded when it closes;
+    /// checked against the stored payer before the refund
+    #[account(mut)]
+    pub pause_signatures_payer: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar for Ed25519 verification
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
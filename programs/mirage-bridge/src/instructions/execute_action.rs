@@ -0,0 +1,288 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use crate::constants::MAX_ADMIN_ACTION_DELAY;
+use crate::errors::BridgeError;
+use crate::events::{
+    AdminActionDelayUpdated, AdminActionExecuted, AdminMultisigUpdated, AttestationThresholdUpdated,
+};
+use crate::instructions::{
+    apply_add_validator, apply_claim_config, apply_commit_validator_set,
+    apply_disable_authority_valset_updates, apply_emergency_pause_threshold, apply_grant_role,
+    apply_max_burn_batch_size, apply_max_native_dropoff, apply_propose_authority,
+    apply_remove_validator, apply_renounce_freeze_authority, apply_revoke_role,
+    apply_transfer_limits, apply_update_config, apply_update_validator_stake,
+    apply_update_validators, apply_withdraw_gas_reserve,
+};
+use crate::state::{
+    validate_admin_multisig, validate_attestation_threshold, AdminAction, BridgeConfig,
+    BridgeRoles, GasReserve, QueuedAction, ValidatorRegistry, ValidatorStaging,
+};
+
+/// Applies a queued admin action once its delay has passed and, while the
+/// multisig is on, enough admin signers have approved it. Permissionless;
+/// the rent goes back to whoever queued it.
+///
+/// Role changes also need `bridge_roles`, `payer` and `system_program`:
+/// `payer` funds the roles account growth on a grant and receives the
+/// freed rent on a revoke. `CommitValidatorSet` needs `validator_staging`,
+/// which is closed with its rent going to whoever queued the commit.
+/// `RenounceFreezeAuthority` needs `token_mint` and `token_program`, and
+/// `WithdrawGasReserve` needs `gas_reserve`.
+pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let queued_action = &ctx.accounts.queued_action;
+    require!(
+        clock.unix_timestamp >= queued_action.eta,
+        BridgeError::AdminActionNotReady
+    );
+
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    require!(
+        bridge_config.count_approvals(&queued_action.approvals)
+            >= bridge_config.admin_threshold as usize,
+        BridgeError::InsufficientApprovals
+    );
+
+    match &queued_action.action {
+        AdminAction::SetAttestationThreshold {
+            attestation_threshold,
+        } => {
+            validate_attestation_threshold(*attestation_threshold)?;
+            let old_attestation_threshold = bridge_config.attestation_threshold;
+            bridge_config.attestation_threshold = *attestation_threshold;
+            emit!(AttestationThresholdUpdated {
+                old_attestation_threshold,
+                new_attestation_threshold: *attestation_threshold,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::UpdateValidators(params) => {
+            require_authority_valset_updates(bridge_config)?;
+            apply_update_validators(&ctx.accounts.validator_registry, params)?;
+        }
+        AdminAction::CommitValidatorSet { staged_set_hash } => {
+            require_authority_valset_updates(bridge_config)?;
+            let Some(validator_staging) = ctx.accounts.validator_staging.as_mut() else {
+                return err!(BridgeError::MissingActionAccounts);
+            };
+            require!(
+                validator_staging.set_hash() == *staged_set_hash,
+                BridgeError::StagedSetMismatch
+            );
+            apply_commit_validator_set(&ctx.accounts.validator_registry, validator_staging)?;
+            validator_staging.close(ctx.accounts.queued_by.to_account_info())?;
+        }
+        AdminAction::AddValidator(params) => {
+            require_authority_valset_updates(bridge_config)?;
+            apply_add_validator(&ctx.accounts.validator_registry, params.clone())?;
+        }
+        AdminAction::RemoveValidator(params) => {
+            require_authority_valset_updates(bridge_config)?;
+            apply_remove_validator(&ctx.accounts.validator_registry, params)?;
+        }
+        AdminAction::UpdateValidatorStake(params) => {
+            require_authority_valset_updates(bridge_config)?;
+            apply_update_validator_stake(&ctx.accounts.validator_registry, params)?;
+        }
+        AdminAction::SetTransferLimits(params) => apply_transfer_limits(bridge_config, params)?,
+        AdminAction::SetMaxNativeDropoff(params) => {
+            apply_max_native_dropoff(bridge_config, params)?
+        }
+        AdminAction::ProposeAuthority(params) => apply_propose_authority(bridge_config, params)?,
+        AdminAction::SetAdminActionDelay { admin_action_delay } => {
+            require!(
+                (0..=MAX_ADMIN_ACTION_DELAY).contains(admin_action_delay),
+                BridgeError::InvalidAdminActionDelay
+            );
+            let old_admin_action_delay = bridge_config.admin_action_delay;
+            bridge_config.admin_action_delay = *admin_action_delay;
+            emit!(AdminActionDelayUpdated {
+                old_admin_action_delay,
+                new_admin_action_delay: *admin_action_delay,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::SetAdminMultisig { signers, threshold } => {
+            validate_admin_multisig(signers, *threshold)?;
+            bridge_config.admin_signers = signers.clone();
+            bridge_config.admin_threshold = *threshold;
+            emit!(AdminMultisigUpdated {
+                signers: signers.clone(),
+                threshold: *threshold,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        AdminAction::UpdateConfig(params) => apply_update_config(bridge_config, params)?,
+        AdminAction::SetClaimConfig(params) => apply_claim_config(bridge_config, params)?,
+        AdminAction::GrantRole(params) => {
+            let (bridge_roles, payer, system_program) = role_accounts(
+                &mut ctx.accounts.bridge_roles,
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+            )?;
+            apply_grant_role(
+                bridge_roles,
+                &payer,
+                &system_program,
+                queued_action.queued_by,
+                params,
+            )?
+        }
+        AdminAction::RevokeRole(params) => {
+            let (bridge_roles, payer, system_program) = role_accounts(
+                &mut ctx.accounts.bridge_roles,
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+            )?;
+            apply_revoke_role(
+                bridge_roles,
+                &payer,
+                &system_program,
+                queued_action.queued_by,
+                params,
+            )?
+        }
+        AdminAction::SetMaxBurnBatchSize(params) => {
+            apply_max_burn_batch_size(bridge_config, params)?
+        }
+        AdminAction::SetEmergencyPauseThreshold(params) => {
+            apply_emergency_pause_threshold(bridge_config, params)?
+        }
+        AdminAction::DisableAuthorityValsetUpdates => apply_disable_authority_valset_updates(
+            bridge_config,
+            &ctx.accounts.validator_registry,
+            queued_action.queued_by,
+        )?,
+        AdminAction::RenounceFreezeAuthority => {
+            let (Some(token_mint), Some(token_program)) =
+                (&ctx.accounts.token_mint, &ctx.accounts.token_program)
+            else {
+                return err!(BridgeError::MissingActionAccounts);
+            };
+            apply_renounce_freeze_authority(
+                bridge_config,
+                token_mint,
+                token_program,
+                queued_action.queued_by,
+            )?
+        }
+        AdminAction::WithdrawGasReserve(params) => {
+            let Some(gas_reserve) = &ctx.accounts.gas_reserve else {
+                return err!(BridgeError::MissingActionAccounts);
+            };
+            apply_withdraw_gas_reserve(
+                gas_reserve,
+                &ctx.accounts.queued_by.to_account_info(),
+                params,
+            )?
+        }
+    }
+
+    emit!(AdminActionExecuted {
+        action_id: queued_action.action_id,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Queued validator set changes stay subject to the authority lockout, like
+/// the direct instructions
+fn require_authority_valset_updates(bridge_config: &BridgeConfig) -> Result<()> {
+    require!(
+        !bridge_config.authority_valset_updates_disabled,
+        BridgeError::AuthorityValsetUpdatesDisabled
+    );
+    Ok(())
+}
+
+/// The optional accounts a role change needs
+fn role_accounts<'a, 'info>(
+    bridge_roles: &'a mut Option<Account<'info, BridgeRoles>>,
+    payer: &Option<Signer<'info>>,
+    system_program: &Option<Program<'info, System>>,
+) -> Result<(
+    &'a mut Account<'info, BridgeRoles>,
+    AccountInfo<'info>,
+    AccountInfo<'info>,
+)> {
+    match (bridge_roles.as_mut(), payer, system_program) {
+        (Some(bridge_roles), Some(payer), Some(system_program)) => Ok((
+            bridge_roles,
+            payer.to_account_info(),
+            system_program.to_account_info(),
+        )),
+        _ => err!(BridgeError::MissingActionAccounts),
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Only written by the validator set actions
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [b"queued_action", &queued_action.action_id.to_le_bytes()[..]],
+        bump = queued_action.bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// CHECK: Rent refund destination, pinned to the original payer
+    #[account(mut, address = queued_action.queued_by)]
+    pub queued_by: UncheckedAccount<'info>,
+
+    /// Only used by `CommitValidatorSet`
+    #[account(
+        mut,
+        seeds = [b"validator_staging"],
+        bump = validator_staging.bump
+    )]
+    pub validator_staging: Option<Account<'info, ValidatorStaging>>,
+
+    /// Only written by `GrantRole` and `RevokeRole`
+    #[account(
+        mut,
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump
+    )]
+    pub bridge_roles: Option<Account<'info, BridgeRoles>>,
+
+    /// Funds or receives the rent difference when `bridge_roles` is resized
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Only used by `RenounceFreezeAuthority`
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Option<Account<'info, anchor_spl::token::Mint>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Only used by `WithdrawGasReserve`
+    #[account(
+        mut,
+        seeds = [b"gas_reserve"],
+        bump = gas_reserve.bump
+    )]
+    pub gas_reserve: Option<Account<'info, GasReserve>>,
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::{PAUSE_FINALIZATION, PAUSE_MINTS};
+use crate::errors::BridgeError;
+use crate::events::{MintClaimable, MintCompleted, MintParked};
+use crate::state::{
+    BridgeConfig, BridgeState, GasReserve, MintRecord, ProcessedMint, ValidatorRegistry,
+};
+use crate::utils::{is_denylisted, mint_bridged_tokens, pay_native_dropoff, solana_denylist_key};
+
+/// Permissionless crank: once the record has reached quorum under the
+/// current validator set, mints to the recipient and refunds the record's
+/// rent to whoever created it. In claim mode the record instead becomes
+/// claimable by the recipient via `claim_mint`.
+///
+/// `PAUSE_MINTS` only blocks the direct mint, so claim-mode records still
+/// become claimable while mints are halted.
+///
+/// A denylisted recipient parks the record instead: it keeps its sequence
+/// and can be finalized again, without re-attestation, once delisted.
+///
+/// The first finalization also creates the burn's `ProcessedMint` marker,
+/// paid by `payer` and never closed, so the burn cannot be attested again
+/// after the record is closed.
+pub fn finalize_mint(ctx: Context<FinalizeMint>, _params: FinalizeMintParams) -> Result<()> {
+    ctx.accounts.bridge_config.check_not_paused(PAUSE_FINALIZATION)?;
+
+    let mint_record = &ctx.accounts.mint_record;
+    let was_parked = mint_record.parked;
+
+    if !was_parked {
+        let registry = ctx.accounts.validator_registry.load()?;
+        require!(
+            mint_record.valset_nonce == registry.valset_nonce,
+            BridgeError::StaleAttestations
+        );
+        let required_stake = ctx.accounts.bridge_config.required_stake(registry.total_stake)?;
+        require!(
+            mint_record.attested_power >= required_stake,
+            BridgeError::ThresholdNotReached
+        );
+        drop(registry);
+
+        let bridge_state = &mut ctx.accounts.bridge_state;
+        bridge_state.check_sequence(mint_record.sequence)?;
+        bridge_state.mark_minted(mint_record.sequence);
+    }
+
+    let clock = Clock::get()?;
+
+    if !was_parked {
+        let processed_mint = &mut ctx.accounts.processed_mint;
+        require!(processed_mint.processed_at == 0, BridgeError::AlreadyMinted);
+        processed_mint.sequence = mint_record.sequence;
+        processed_mint.processed_at = clock.unix_timestamp;
+        processed_mint.bump = ctx.bumps.processed_mint;
+    }
+
+    let denylisted = is_denylisted(
+        &ctx.accounts.recipient_denylist_shard,
+        &solana_denylist_key(&mint_record.recipient),
+    )?;
+    if denylisted {
+        require!(!was_parked, BridgeError::AddressDenylisted);
+
+        let mint_record = &mut ctx.accounts.mint_record;
+        mint_record.parked = true;
+
+        emit!(MintParked {
+            burn_tx_hash: mint_record.burn_tx_hash,
+            recipient: mint_record.recipient,
+            amount: mint_record.amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    if ctx.accounts.bridge_config.claim_mode {
+        let expires_at = clock
+            .unix_timestamp
+            .checked_add(ctx.accounts.bridge_config.claim_expiry)
+            .ok_or(BridgeError::AmountOverflow)?;
+
+        let mint_record = &mut ctx.accounts.mint_record;
+        mint_record.claim_expires_at = expires_at;
+        mint_record.parked = false;
+
+        emit!(MintClaimable {
+            burn_tx_hash: mint_record.burn_tx_hash,
+            recipient: mint_record.recipient,
+            amount: mint_record.amount,
+            expires_at,
+            timestamp: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    ctx.accounts.bridge_config.check_not_paused(PAUSE_MINTS)?;
+
+    let recipient_token_account = ctx
+        .accounts
+        .recipient_token_account
+        .as_ref()
+        .ok_or(BridgeError::MissingRecipientTokenAccount)?;
+
+    mint_bridged_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        &recipient_token_account.to_account_info(),
+        &mut ctx.accounts.bridge_config,
+        mint_record.amount,
+    )?;
+
+    pay_native_dropoff(
+        ctx.accounts.gas_reserve.as_ref(),
+        &ctx.accounts.recipient,
+        mint_record,
+    )?;
+
+    emit!(MintCompleted {
+        burn_tx_hash: mint_record.burn_tx_hash,
+        recipient: mint_record.recipient,
+        amount: mint_record.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    ctx.accounts
+        .mint_record
+        .close(ctx.accounts.mint_record_payer.to_account_info())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FinalizeMintParams {
+    pub burn_tx_hash: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(params: FinalizeMintParams)]
+pub struct FinalizeMint<'info> {
+    /// Anyone may finalize; pays for the recipient ATA if it does not exist
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Must match the attested recipient; receives any native drop-off
+    #[account(mut, address = mint_record.recipient @ BridgeError::RecipientMismatch)]
+    pub recipient: AccountInfo<'info>,
+
+    /// CHECK: Payer that funded MintRecord (rent refund target)
+    #[account(mut, address = mint_record.payer)]
+    pub mint_record_payer: AccountInfo<'info>,
+
+    /// Omitted in claim mode, where the recipient supplies an account later
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_state"],
+        bump = bridge_state.bump
+    )]
+    pub bridge_state: Account<'info, BridgeState>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_record", &params.burn_tx_hash[..]],
+        bump = mint_record.bump
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProcessedMint::INIT_SPACE,
+        seeds = [b"processed_mint", &params.burn_tx_hash[..]],
+        bump
+    )]
+    pub processed_mint: Account<'info, ProcessedMint>,
+
+    #[account(
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+
+    /// Required only when the record carries a native drop-off
+    #[account(
+        mut,
+        seeds = [b"gas_reserve"],
+        bump = gas_reserve.bump
+    )]
+    pub gas_reserve: Option<Account<'info, GasReserve>>,
+
+    /// CHECK: Denylist shard for the recipient; validated in `is_denylisted`
+    pub recipient_denylist_shard: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
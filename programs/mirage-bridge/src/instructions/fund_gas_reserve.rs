@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::BridgeError;
+use crate::events::GasReserveFunded;
+use crate::state::GasReserve;
+
+/// Deposits SOL into the gas reserve, creating it on first use. Anyone may fund it.
+pub fn fund_gas_reserve(ctx: Context<FundGasReserve>, params: FundGasReserveParams) -> Result<()> {
+    require!(params.amount > 0, BridgeError::InvalidAmount);
+
+    ctx.accounts.gas_reserve.bump = ctx.bumps.gas_reserve;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.gas_reserve.to_account_info(),
+            },
+        ),
+        params.amount,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(GasReserveFunded {
+        funder: ctx.accounts.funder.key(),
+        amount: params.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundGasReserveParams {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct FundGasReserve<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + GasReserve::INIT_SPACE,
+        seeds = [b"gas_reserve"],
+        bump
+    )]
+    pub gas_reserve: Account<'info, GasReserve>,
+
+    pub system_program: Program<'info, System>,
+}
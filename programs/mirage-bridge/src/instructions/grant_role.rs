@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ROLE_MEMBERS;
+use crate::errors::BridgeError;
+use crate::events::RoleGranted;
+use crate::state::{BridgeConfig, BridgeRoles, Role, RoleMember};
+use crate::utils::resize_account;
+
+/// Grants `role` to `member`. Creates the roles account on first use for
+/// deployments initialized before roles existed.
+pub fn grant_role(ctx: Context<GrantRole>, params: RoleParams) -> Result<()> {
+    ctx.accounts.bridge_roles.bump = ctx.bumps.bridge_roles;
+    apply_grant_role(
+        &mut ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.admin.key(),
+        &params,
+    )
+}
+
+/// Shared with `execute_action`. `payer` funds the roles account growth.
+pub fn apply_grant_role<'info>(
+    bridge_roles: &mut Account<'info, BridgeRoles>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    admin: Pubkey,
+    params: &RoleParams,
+) -> Result<()> {
+    match bridge_roles
+        .members
+        .iter_mut()
+        .find(|m| m.member == params.member)
+    {
+        Some(entry) => {
+            require!(
+                entry.roles & params.role.mask() == 0,
+                BridgeError::RoleAlreadyGranted
+            );
+            entry.roles |= params.role.mask();
+        }
+        None => {
+            require!(
+                bridge_roles.members.len() < MAX_ROLE_MEMBERS,
+                BridgeError::TooManyRoleMembers
+            );
+            bridge_roles.members.push(RoleMember {
+                member: params.member,
+                roles: params.role.mask(),
+            });
+        }
+    }
+
+    let new_space = BridgeRoles::space(bridge_roles.members.len());
+    resize_account(
+        &bridge_roles.to_account_info(),
+        payer,
+        system_program,
+        new_space,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(RoleGranted {
+        member: params.member,
+        role: params.role,
+        admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoleParams {
+    pub member: Pubkey,
+    pub role: Role,
+}
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    /// Pays for roles account growth
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = BridgeRoles::space(0),
+        seeds = [b"bridge_roles"],
+        bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    pub system_program: Program<'info, System>,
+}
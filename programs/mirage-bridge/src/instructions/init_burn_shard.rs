@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BURN_SHARD_BITS, BURN_SHARD_COUNT};
+use crate::errors::BridgeError;
+use crate::state::{BridgeConfig, BurnShard};
+
+/// Creates a burn shard. Permissionless; anyone can pay to open a shard.
+///
+/// Shard nonces start above every ID issued by the legacy global counter,
+/// so sharded burn IDs never collide with earlier ones.
+pub fn init_burn_shard(ctx: Context<InitBurnShard>, params: InitBurnShardParams) -> Result<()> {
+    require!(
+        params.shard_id < BURN_SHARD_COUNT,
+        BridgeError::InvalidBurnShard
+    );
+
+    let legacy_burns = ctx.accounts.bridge_config.burn_nonce;
+
+    let burn_shard = &mut ctx.accounts.burn_shard;
+    burn_shard.shard_id = params.shard_id;
+    burn_shard.next_nonce = legacy_burns.div_ceil(1 << BURN_SHARD_BITS);
+    burn_shard.unsynced_burned = 0;
+    burn_shard.bump = ctx.bumps.burn_shard;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitBurnShardParams {
+    pub shard_id: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitBurnShardParams)]
+pub struct InitBurnShard<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BurnShard::INIT_SPACE,
+        seeds = [b"burn_shard", &params.shard_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub burn_shard: Account<'info, BurnShard>,
+
+    pub system_program: Program<'info, System>,
+}
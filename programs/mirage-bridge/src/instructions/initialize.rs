@@ -6,19 +6,15 @@ use mpl_token_metadata::{
     ID as METADATA_PROGRAM_ID,
 };
 
-use crate::constants::BASIS_POINTS_DENOMINATOR;
-use crate::errors::BridgeError;
-use crate::state::{BridgeConfig, BridgeState, ValidatorRegistry};
+use crate::constants::{DEFAULT_MAX_BURN_BATCH_SIZE, DEFAULT_VALIDATOR_CAPACITY};
+use crate::state::{
+    validate_attestation_threshold, validate_mirage_chain_id, AmountLimits, BridgeConfig,
+    BridgeRoles, BridgeState, ValidatorRegistry,
+};
 
 pub fn initialize(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
-    require!(
-        !params.mirage_chain_id.is_empty(),
-        BridgeError::InvalidChainId
-    );
-    require!(
-        params.attestation_threshold > 0 && params.attestation_threshold <= BASIS_POINTS_DENOMINATOR,
-        BridgeError::InvalidThreshold
-    );
+    validate_mirage_chain_id(&params.mirage_chain_id)?;
+    validate_attestation_threshold(params.attestation_threshold)?;
 
     let bridge_config = &mut ctx.accounts.bridge_config;
     bridge_config.authority = ctx.accounts.authority.key();
@@ -28,19 +24,44 @@ pub fn initialize(ctx: Context<Initialize>, params: InitializeParams) -> Result<
     bridge_config.total_minted = 0;
     bridge_config.total_burned = 0;
     bridge_config.burn_nonce = 0;
-    bridge_config.paused = false;
+    bridge_config.paused_flags = 0;
     bridge_config.bump = ctx.bumps.bridge_config;
-
-    let validator_registry = &mut ctx.accounts.validator_registry;
-    validator_registry.validators = Vec::new();
+    bridge_config.authority_valset_updates_disabled = false;
+    bridge_config.claim_mode = false;
+    bridge_config.claim_expiry = 0;
+    bridge_config.unclaimed_sweep_address = Pubkey::default();
+    bridge_config.max_native_dropoff_lamports = 0;
+    bridge_config.burn_limits = AmountLimits::default();
+    bridge_config.mint_limits = AmountLimits::default();
+    bridge_config.max_burn_batch_size = DEFAULT_MAX_BURN_BATCH_SIZE;
+    bridge_config.pending_authority = None;
+    bridge_config.authority_transfer_unlock_at = 0;
+    bridge_config.admin_action_delay = 0;
+    bridge_config.admin_action_nonce = 0;
+    bridge_config.admin_threshold = 0;
+    bridge_config.admin_signers = Vec::new();
+    bridge_config.emergency_pause_threshold = 0;
+    bridge_config.emergency_pause_nonce = 0;
+    bridge_config.version = BridgeConfig::VERSION;
+
+    // The authority holds every role implicitly; delegates are granted later
+    ctx.accounts.bridge_roles.bump = ctx.bumps.bridge_roles;
+
+    let mut validator_registry = ctx.accounts.validator_registry.load_init()?;
     validator_registry.total_stake = 0;
+    validator_registry.valset_nonce = 0;
+    validator_registry.capacity = DEFAULT_VALIDATOR_CAPACITY as u32;
+    validator_registry.len = 0;
     validator_registry.bump = ctx.bumps.validator_registry;
+    validator_registry.version = ValidatorRegistry::VERSION;
+    drop(validator_registry);
 
     let bridge_state = &mut ctx.accounts.bridge_state;
     bridge_state.bump = ctx.bumps.bridge_state;
     bridge_state.authority = ctx.accounts.authority.key();
     bridge_state.last_sequence = 0;
     bridge_state.replay_bitmap = [0; 8];
+    bridge_state.version = BridgeState::VERSION;
 
     // Create token metadata
     let metadata_data = DataV2 {
@@ -96,11 +117,20 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + ValidatorRegistry::INIT_SPACE,
+        space = BridgeRoles::space(0),
+        seeds = [b"bridge_roles"],
+        bump
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ValidatorRegistry::space(DEFAULT_VALIDATOR_CAPACITY),
         seeds = [b"validator_registry"],
         bump
     )]
-    pub validator_registry: Account<'info, ValidatorRegistry>,
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
 
     #[account(
         init,
@@ -117,7 +147,8 @@ pub struct Initialize<'info> {
         seeds = [b"mint"],
         bump,
         mint::decimals = 6,
-        mint::authority = bridge_config
+        mint::authority = bridge_config,
+        mint::freeze_authority = bridge_config
     )]
     pub token_mint: Account<'info, anchor_spl::token::Mint>,
 
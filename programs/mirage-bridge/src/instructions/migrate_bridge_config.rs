@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::events::AccountMigrated;
+use crate::state::{BridgeConfig, BridgeConfigV0};
+use crate::utils::{resize_account, store_account};
+
+/// Upgrades the config from the originally deployed layout in place.
+/// Permissionless: the payer only funds the extra rent, and every existing
+/// field keeps its value.
+pub fn migrate_bridge_config(ctx: Context<MigrateBridgeConfig>) -> Result<()> {
+    let account = ctx.accounts.bridge_config.to_account_info();
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let legacy = BridgeConfigV0::decode(&account.try_borrow_data()?)?;
+    resize_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + BridgeConfig::INIT_SPACE,
+    )?;
+    store_account(&account, &legacy.upgrade())?;
+
+    let clock = Clock::get()?;
+    emit!(AccountMigrated {
+        account: account.key(),
+        old_version: 0,
+        new_version: BridgeConfig::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateBridgeConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Decoded in the handler, since older layouts fail `Account` deserialization
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump
+    )]
+    pub bridge_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
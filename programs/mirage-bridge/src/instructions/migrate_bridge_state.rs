@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AccountMigrated;
+use crate::state::BridgeState;
+use crate::utils::{load_legacy_account, store_account};
+
+/// Upgrades the replay-protection state to the current layout in place
+pub fn migrate_bridge_state(ctx: Context<MigrateBridgeState>) -> Result<()> {
+    let account = ctx.accounts.bridge_state.to_account_info();
+    let mut bridge_state: BridgeState = load_legacy_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BridgeState::LEN,
+    )?;
+
+    let old_version = bridge_state.version;
+    require!(
+        old_version < BridgeState::VERSION,
+        BridgeError::AlreadyMigrated
+    );
+
+    // v0 -> v1 only appends `version` and the reserved bytes
+    bridge_state.version = BridgeState::VERSION;
+    store_account(&account, &bridge_state)?;
+
+    let clock = Clock::get()?;
+    emit!(AccountMigrated {
+        account: account.key(),
+        old_version,
+        new_version: BridgeState::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateBridgeState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Decoded in the handler, since older layouts fail `Account` deserialization
+    #[account(
+        mut,
+        seeds = [b"bridge_state"],
+        bump
+    )]
+    pub bridge_state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
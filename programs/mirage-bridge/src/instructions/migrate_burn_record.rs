@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::events::AccountMigrated;
+use crate::state::{BurnRecord, BurnRecordV0};
+use crate::utils::{resize_account, store_account};
+
+/// Upgrades a burn record from the originally deployed layout in place,
+/// decoding its bech32 recipient to the canonical form
+pub fn migrate_burn_record(
+    ctx: Context<MigrateBurnRecord>,
+    _params: MigrateBurnRecordParams,
+) -> Result<()> {
+    let account = ctx.accounts.burn_record.to_account_info();
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let legacy = BurnRecordV0::decode(&account.try_borrow_data()?)?;
+    resize_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + BurnRecord::INIT_SPACE,
+    )?;
+    store_account(&account, &legacy.upgrade())?;
+
+    let clock = Clock::get()?;
+    emit!(AccountMigrated {
+        account: account.key(),
+        old_version: 0,
+        new_version: BurnRecord::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateBurnRecordParams {
+    pub burn_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateBurnRecordParams)]
+pub struct MigrateBurnRecord<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Decoded in the handler, since older layouts fail `Account` deserialization
+    #[account(
+        mut,
+        seeds = [b"burn_record", &params.burn_id.to_le_bytes()[..]],
+        bump
+    )]
+    pub burn_record: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::events::LegacyMintRecordClosed;
+use crate::state::MintRecordV0;
+use crate::utils::close_program_account;
+
+/// Closes a mint record left at the originally deployed layout and refunds
+/// its rent to the relayer that funded it. Its attestations were signed over
+/// the old payload and it never recorded a sequence, so it can't be carried
+/// forward; orchestrators re-attest the transfer under the current payload,
+/// which opens a fresh record. A transfer the old program already minted is
+/// still refused by the `BridgeState` sequence window.
+pub fn migrate_mint_record(
+    ctx: Context<MigrateMintRecord>,
+    params: MigrateMintRecordParams,
+) -> Result<()> {
+    let account = ctx.accounts.mint_record.to_account_info();
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let legacy = MintRecordV0::decode(&account.try_borrow_data()?)?;
+    require_keys_eq!(
+        ctx.accounts.record_payer.key(),
+        legacy.payer,
+        ErrorCode::ConstraintAddress
+    );
+    close_program_account(&account, &ctx.accounts.record_payer.to_account_info())?;
+
+    let clock = Clock::get()?;
+    emit!(LegacyMintRecordClosed {
+        burn_tx_hash: params.burn_tx_hash,
+        recipient: legacy.recipient,
+        amount: legacy.amount,
+        attested_power: legacy.attested_power,
+        refunded_to: legacy.payer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateMintRecordParams {
+    pub burn_tx_hash: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateMintRecordParams)]
+pub struct MigrateMintRecord<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: Decoded in the handler, since the legacy layout fails `Account` deserialization
+    #[account(
+        mut,
+        seeds = [b"mint_record", &params.burn_tx_hash[..]],
+        bump
+    )]
+    pub mint_record: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the payer recorded in the legacy record (rent refund target)
+    #[account(mut)]
+    pub record_payer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,96 @@
+use std::mem::size_of;
+
+use anchor_lang::prelude::*;
+
+use crate::constants::{DEFAULT_VALIDATOR_CAPACITY, MAX_VALIDATORS, MAX_VALIDATOR_ADDR_LEN};
+use crate::errors::BridgeError;
+use crate::events::AccountMigrated;
+use crate::state::{
+    ValidatorEntry, ValidatorRegistry, ValidatorRegistryV0, VALIDATOR_REGISTRY_V0_DISCRIMINATOR,
+};
+use crate::utils::resize_account;
+
+/// Converts the Borsh registry deployed before the zero-copy layout into the
+/// current one in place. The set and its stakes are unchanged; entries are
+/// sorted by orchestrator key and `valset_nonce` starts at 0.
+pub fn migrate_validator_registry(ctx: Context<MigrateValidatorRegistry>) -> Result<()> {
+    let account = ctx.accounts.validator_registry.to_account_info();
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let legacy = {
+        let data = account.try_borrow_data()?;
+        require!(
+            !data.starts_with(ValidatorRegistry::DISCRIMINATOR),
+            BridgeError::AlreadyMigrated
+        );
+        require!(
+            data.starts_with(&VALIDATOR_REGISTRY_V0_DISCRIMINATOR),
+            BridgeError::InvalidLegacyLayout
+        );
+        ValidatorRegistryV0::deserialize(&mut &data[8..])
+            .map_err(|_| BridgeError::InvalidLegacyLayout)?
+    };
+    require!(
+        legacy.validators.len() <= MAX_VALIDATORS
+            && legacy
+                .validators
+                .iter()
+                .all(|v| v.mirage_validator.len() <= MAX_VALIDATOR_ADDR_LEN),
+        BridgeError::InvalidLegacyLayout
+    );
+
+    let capacity = legacy.validators.len().max(DEFAULT_VALIDATOR_CAPACITY);
+    resize_account(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ValidatorRegistry::space(capacity),
+    )?;
+
+    {
+        let mut data = account.try_borrow_mut_data()?;
+        data.fill(0);
+        data[..8].copy_from_slice(ValidatorRegistry::DISCRIMINATOR);
+
+        let (header, entries) = data.split_at_mut(ValidatorRegistry::HEADER_SPACE);
+        let registry = bytemuck::from_bytes_mut::<ValidatorRegistry>(&mut header[8..]);
+        registry.capacity = capacity as u32;
+        registry.bump = legacy.bump;
+        registry.version = ValidatorRegistry::VERSION;
+
+        let entries = bytemuck::cast_slice_mut::<u8, ValidatorEntry>(
+            &mut entries[..capacity * size_of::<ValidatorEntry>()],
+        );
+        registry.replace(entries, &legacy.validators)?;
+    }
+
+    let clock = Clock::get()?;
+    emit!(AccountMigrated {
+        account: account.key(),
+        old_version: 0,
+        new_version: ValidatorRegistry::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateValidatorRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Decoded in the handler, since the legacy layout has a different discriminator
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump
+    )]
+    pub validator_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
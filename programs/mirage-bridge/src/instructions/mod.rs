@@ -1,17 +1,105 @@
+pub mod accept_authority;
+pub mod add_to_denylist;
+pub mod add_validator;
+pub mod approve_action;
+pub mod attest;
 pub mod burn;
+pub mod burn_batch;
+pub mod burn_from_program;
+pub mod burn_with_permit;
+pub mod cancel_action;
+pub mod cancel_authority_transfer;
+pub mod claim_mint;
+pub mod close_valset_update;
+pub mod commit_validator_set;
+pub mod disable_authority_valset_updates;
+pub mod emergency_pause;
+pub mod execute_action;
+pub mod finalize_mint;
+pub mod freeze_account;
+pub mod grant_role;
+pub mod fund_gas_reserve;
+pub mod init_burn_shard;
 pub mod initialize;
-pub mod mint;
+pub mod migrate_bridge_config;
+pub mod migrate_bridge_state;
+pub mod migrate_burn_record;
+pub mod migrate_mint_record;
+pub mod migrate_validator_registry;
 pub mod pause;
-pub mod transfer_authority;
+pub mod propose_authority;
+pub mod queue_action;
+pub mod remove_from_denylist;
+pub mod remove_validator;
+pub mod renounce_freeze_authority;
+pub mod revoke_role;
+pub mod resize_validator_registry;
+pub mod rotate_validator_set;
+pub mod set_claim_config;
+pub mod set_emergency_pause_threshold;
+pub mod set_max_burn_batch_size;
+pub mod set_max_native_dropoff;
+pub mod set_transfer_limits;
+pub mod stage_validators;
+pub mod sweep_unclaimed_mint;
+pub mod sync_burn_totals;
+pub mod thaw_account;
 pub mod unpause;
+pub mod update_config;
 pub mod update_metadata;
+pub mod update_validator_stake;
 pub mod update_validators;
+pub mod withdraw_gas_reserve;
 
+pub use accept_authority::*;
+pub use add_to_denylist::*;
+pub use add_validator::*;
+pub use approve_action::*;
+pub use attest::*;
 pub use burn::*;
+pub use burn_batch::*;
+pub use burn_from_program::*;
+pub use burn_with_permit::*;
+pub use cancel_action::*;
+pub use cancel_authority_transfer::*;
+pub use claim_mint::*;
+pub use close_valset_update::*;
+pub use commit_validator_set::*;
+pub use disable_authority_valset_updates::*;
+pub use emergency_pause::*;
+pub use execute_action::*;
+pub use finalize_mint::*;
+pub use freeze_account::*;
+pub use grant_role::*;
+pub use fund_gas_reserve::*;
+pub use init_burn_shard::*;
 pub use initialize::*;
-pub use mint::*;
+pub use migrate_bridge_config::*;
+pub use migrate_bridge_state::*;
+pub use migrate_burn_record::*;
+pub use migrate_mint_record::*;
+pub use migrate_validator_registry::*;
 pub use pause::*;
-pub use transfer_authority::*;
+pub use propose_authority::*;
+pub use queue_action::*;
+pub use remove_from_denylist::*;
+pub use remove_validator::*;
+pub use renounce_freeze_authority::*;
+pub use revoke_role::*;
+pub use resize_validator_registry::*;
+pub use rotate_validator_set::*;
+pub use set_claim_config::*;
+pub use set_emergency_pause_threshold::*;
+pub use set_max_burn_batch_size::*;
+pub use set_max_native_dropoff::*;
+pub use set_transfer_limits::*;
+pub use stage_validators::*;
+pub use sweep_unclaimed_mint::*;
+pub use sync_burn_totals::*;
+pub use thaw_account::*;
 pub use unpause::*;
+pub use update_config::*;
 pub use update_metadata::*;
+pub use update_validator_stake::*;
 pub use update_validators::*;
+pub use withdraw_gas_reserve::*;
@@ -1,31 +1,60 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::PAUSE_ALL;
 use crate::errors::BridgeError;
 use crate::events::BridgePaused;
-use crate::state::BridgeConfig;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Sets the `PAUSE_*` bits in `params.flags`; other operations keep running
+pub fn pause(ctx: Context<Pause>, params: PauseParams) -> Result<()> {
+    params.validate()?;
 
-pub fn pause(ctx: Context<Pause>) -> Result<()> {
     let bridge_config = &mut ctx.accounts.bridge_config;
-    bridge_config.paused = true;
+    let changed_flags = params.flags & !bridge_config.paused_flags;
+    bridge_config.paused_flags |= params.flags;
 
     let clock = Clock::get()?;
     emit!(BridgePaused {
-        authority: ctx.accounts.authority.key(),
+        authority: ctx.accounts.pauser.key(),
+        changed_flags,
+        paused_flags: bridge_config.paused_flags,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
 
+/// Shared by `pause` and `unpause`
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PauseParams {
+    pub flags: u8, // Mask of `PAUSE_*` bits
+}
+
+impl PauseParams {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.flags != 0 && self.flags & !PAUSE_ALL == 0,
+            BridgeError::InvalidPauseFlags
+        );
+        Ok(())
+    }
+}
+
 #[derive(Accounts)]
 pub struct Pause<'info> {
-    pub authority: Signer<'info>,
+    pub pauser: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"bridge_config"],
-        bump = bridge_config.bump,
-        has_one = authority @ BridgeError::Unauthorized
+        bump = bridge_config.bump
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &pauser.key(), Role::Pauser) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
 }
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AuthorityTransferProposed;
+use crate::state::BridgeConfig;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposeAuthorityParams {
+    pub new_authority: Pubkey,
+    pub delay_seconds: i64, // 0 lets the new authority accept immediately
+}
+
+/// First step of an authority transfer. Nothing changes until
+/// `new_authority` signs `accept_authority`. Replaces any pending proposal.
+pub fn propose_authority(
+    ctx: Context<ProposeAuthority>,
+    params: ProposeAuthorityParams,
+) -> Result<()> {
+    apply_propose_authority(&mut ctx.accounts.bridge_config, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_propose_authority(
+    bridge_config: &mut BridgeConfig,
+    params: &ProposeAuthorityParams,
+) -> Result<()> {
+    require!(
+        params.new_authority != bridge_config.authority
+            && params.new_authority != Pubkey::default(),
+        BridgeError::InvalidAuthority
+    );
+    require!(
+        params.delay_seconds >= 0,
+        BridgeError::InvalidAuthorityTransferDelay
+    );
+
+    let clock = Clock::get()?;
+    let unlock_at = clock
+        .unix_timestamp
+        .checked_add(params.delay_seconds)
+        .ok_or(BridgeError::InvalidAuthorityTransferDelay)?;
+
+    bridge_config.pending_authority = Some(params.new_authority);
+    bridge_config.authority_transfer_unlock_at = unlock_at;
+
+    emit!(AuthorityTransferProposed {
+        authority: bridge_config.authority,
+        pending_authority: params.new_authority,
+        unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = authority @ BridgeError::Unauthorized,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+}
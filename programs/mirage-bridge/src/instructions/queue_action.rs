@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::AdminActionQueued;
+use crate::state::{
+    can_manage_admin_actions, AdminAction, BridgeConfig, BridgeRoles, QueuedAction,
+};
+
+/// Queues an admin action that anyone can execute once
+/// `bridge_config.admin_action_delay` has passed. While the multisig is on,
+/// this is the proposal: it counts as the proposer's approval, and the rest
+/// come from `approve_action`.
+pub fn queue_action(ctx: Context<QueueAction>, params: QueueActionParams) -> Result<()> {
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let action_id = bridge_config.admin_action_nonce;
+    bridge_config.admin_action_nonce =
+        action_id.checked_add(1).ok_or(BridgeError::NonceOverflow)?;
+
+    let clock = Clock::get()?;
+    let eta = clock
+        .unix_timestamp
+        .checked_add(bridge_config.admin_action_delay)
+        .ok_or(BridgeError::InvalidAdminActionDelay)?;
+
+    let queued_action = &mut ctx.accounts.queued_action;
+    queued_action.action_id = action_id;
+    queued_action.queued_by = ctx.accounts.admin.key();
+    queued_action.eta = eta;
+    queued_action.bump = ctx.bumps.queued_action;
+    queued_action.approvals = if bridge_config.admin_threshold > 0 {
+        vec![ctx.accounts.admin.key()]
+    } else {
+        Vec::new()
+    };
+    queued_action.action = params.action.clone();
+
+    emit!(AdminActionQueued {
+        action_id,
+        action: params.action,
+        queued_by: ctx.accounts.admin.key(),
+        eta,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QueueActionParams {
+    pub action: AdminAction,
+}
+
+#[derive(Accounts)]
+#[instruction(params: QueueActionParams)]
+pub struct QueueAction<'info> {
+    /// Pays for the queued action until it is executed or cancelled
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = can_manage_admin_actions(&bridge_config, &bridge_roles, &admin.key()) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = QueuedAction::space(&params.action),
+        seeds = [b"queued_action", &bridge_config.admin_action_nonce.to_le_bytes()[..]],
+        bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    pub system_program: Program<'info, System>,
+}
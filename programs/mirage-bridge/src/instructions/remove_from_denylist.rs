@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::DenylistUpdated;
+use crate::instructions::DenylistParams;
+use crate::state::{BridgeConfig, BridgeRoles, DenylistShard, Role};
+use crate::utils::{denylist_key, resize_account};
+
+/// Removes an address from the denylist and refunds the freed rent. Mints
+/// parked for a Solana address can then be finalized again.
+pub fn remove_from_denylist(ctx: Context<RemoveFromDenylist>, params: DenylistParams) -> Result<()> {
+    let key = denylist_key(&params.address)?;
+    require!(key[0] == params.shard, BridgeError::InvalidDenylistShard);
+
+    let shard = &mut ctx.accounts.denylist_shard;
+    let position = shard
+        .entries
+        .binary_search(&key)
+        .map_err(|_| BridgeError::NotDenylisted)?;
+    shard.entries.remove(position);
+
+    let new_space = DenylistShard::space(shard.entries.len());
+    resize_account(
+        &ctx.accounts.denylist_shard.to_account_info(),
+        &ctx.accounts.compliance_authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_space,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(DenylistUpdated {
+        address: params.address,
+        address_hash: key,
+        denylisted: false,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: DenylistParams)]
+pub struct RemoveFromDenylist<'info> {
+    /// Receives the freed rent
+    #[account(mut)]
+    pub compliance_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &compliance_authority.key(), Role::Compliance) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"denylist".as_ref(), &[params.shard]],
+        bump = denylist_shard.bump
+    )]
+    pub denylist_shard: Account<'info, DenylistShard>,
+
+    pub system_program: Program<'info, System>,
+}
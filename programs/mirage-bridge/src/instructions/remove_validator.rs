@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ValidatorRemoved;
+use crate::state::{BridgeConfig, BridgeRoles, Role, ValidatorRegistry};
+
+pub fn remove_validator(ctx: Context<RemoveValidator>, params: RemoveValidatorParams) -> Result<()> {
+    apply_remove_validator(&ctx.accounts.validator_registry, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_remove_validator(
+    validator_registry: &AccountLoader<ValidatorRegistry>,
+    params: &RemoveValidatorParams,
+) -> Result<()> {
+    let (mut registry, mut entries) = ValidatorRegistry::load_mut(validator_registry)?;
+
+    // Removing the last validator would leave mints permanently unattestable
+    require!(registry.len > 1, BridgeError::EmptyValidatorSet);
+
+    registry.remove(&mut entries, &params.orchestrator_pubkey)?;
+    registry.advance_valset_nonce()?;
+
+    let clock = Clock::get()?;
+    emit!(ValidatorRemoved {
+        orchestrator: params.orchestrator_pubkey,
+        total_stake: registry.total_stake,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RemoveValidatorParams {
+    pub orchestrator_pubkey: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct RemoveValidator<'info> {
+    pub validator_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.authority_valset_updates_disabled @ BridgeError::AuthorityValsetUpdatesDisabled,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &validator_manager.key(), Role::ValidatorManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, SetAuthority, Token};
+
+use crate::errors::BridgeError;
+use crate::events::FreezeAuthorityRenounced;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Permanently removes the mint's freeze authority. Irreversible: thaw any
+/// frozen accounts first, as they can no longer be thawed afterwards.
+pub fn renounce_freeze_authority(ctx: Context<RenounceFreezeAuthority>) -> Result<()> {
+    apply_renounce_freeze_authority(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.token_program,
+        ctx.accounts.admin.key(),
+    )
+}
+
+/// Shared with `execute_action`
+pub fn apply_renounce_freeze_authority<'info>(
+    bridge_config: &Account<'info, BridgeConfig>,
+    token_mint: &Account<'info, anchor_spl::token::Mint>,
+    token_program: &Program<'info, Token>,
+    admin: Pubkey,
+) -> Result<()> {
+    require!(
+        token_mint.freeze_authority == Some(bridge_config.key()).into(),
+        BridgeError::FreezeAuthorityRenounced
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"bridge_config", &[bridge_config.bump]]];
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            SetAuthority {
+                current_authority: bridge_config.to_account_info(),
+                account_or_mint: token_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::FreezeAccount,
+        None,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(FreezeAuthorityRenounced {
+        authority: admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenounceFreezeAuthority<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
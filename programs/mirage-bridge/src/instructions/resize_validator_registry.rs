@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_VALIDATORS;
+use crate::errors::BridgeError;
+use crate::events::ValidatorRegistryResized;
+use crate::state::{BridgeConfig, BridgeRoles, Role, ValidatorRegistry};
+use crate::utils::resize_account;
+
+/// Grows or shrinks the registry to hold `capacity` validators.
+/// The runtime limits growth to 10,240 bytes (~106 entries) per call.
+pub fn resize_validator_registry(
+    ctx: Context<ResizeValidatorRegistry>,
+    params: ResizeValidatorRegistryParams,
+) -> Result<()> {
+    let capacity = params.capacity as usize;
+    require!(
+        capacity > 0 && capacity <= MAX_VALIDATORS,
+        BridgeError::InvalidValidatorCapacity
+    );
+
+    let old_capacity = {
+        let (registry, _) = ValidatorRegistry::load(&ctx.accounts.validator_registry)?;
+        require!(
+            capacity >= registry.len as usize,
+            BridgeError::InvalidValidatorCapacity
+        );
+        registry.capacity
+    };
+
+    resize_account(
+        &ctx.accounts.validator_registry.to_account_info(),
+        &ctx.accounts.validator_manager.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ValidatorRegistry::space(capacity),
+    )?;
+
+    ctx.accounts.validator_registry.load_mut()?.capacity = params.capacity;
+
+    let clock = Clock::get()?;
+    emit!(ValidatorRegistryResized {
+        old_capacity,
+        new_capacity: params.capacity,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResizeValidatorRegistryParams {
+    pub capacity: u32,
+}
+
+#[derive(Accounts)]
+pub struct ResizeValidatorRegistry<'info> {
+    #[account(mut)]
+    pub validator_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &validator_manager.key(), Role::ValidatorManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
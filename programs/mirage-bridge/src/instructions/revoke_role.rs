@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::RoleRevoked;
+use crate::instructions::RoleParams;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+use crate::utils::resize_account;
+
+/// Revokes `role` from `member`, dropping the member and refunding its rent
+/// once it holds no roles. The bridge authority's implicit roles cannot be
+/// revoked.
+pub fn revoke_role(ctx: Context<RevokeRole>, params: RoleParams) -> Result<()> {
+    apply_revoke_role(
+        &mut ctx.accounts.bridge_roles,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.admin.key(),
+        &params,
+    )
+}
+
+/// Shared with `execute_action`. `payer` receives the freed rent.
+pub fn apply_revoke_role<'info>(
+    bridge_roles: &mut Account<'info, BridgeRoles>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    admin: Pubkey,
+    params: &RoleParams,
+) -> Result<()> {
+    let position = bridge_roles
+        .members
+        .iter()
+        .position(|m| m.member == params.member && m.roles & params.role.mask() != 0)
+        .ok_or(BridgeError::RoleNotGranted)?;
+    bridge_roles.members[position].roles &= !params.role.mask();
+    if bridge_roles.members[position].roles == 0 {
+        bridge_roles.members.remove(position);
+    }
+
+    let new_space = BridgeRoles::space(bridge_roles.members.len());
+    resize_account(
+        &bridge_roles.to_account_info(),
+        payer,
+        system_program,
+        new_space,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(RoleRevoked {
+        member: params.member,
+        role: params.role,
+        admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    /// Receives the freed rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    pub system_program: Program<'info, System>,
+}
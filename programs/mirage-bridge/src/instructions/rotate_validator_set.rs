@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+
+use crate::constants::MAX_VALIDATORS;
+use crate::errors::BridgeError;
+use crate::events::{ValidatorSetRotated, ValsetUpdateSigned};
+use crate::state::{
+    find_validator_index, hash_validator_set, validate_validator_info, validate_validator_set,
+    BridgeConfig, ValidatorInfo, ValidatorRegistry, ValsetUpdate,
+};
+use crate::utils::{
+    build_valset_update_payload, is_bit_set, load_ed25519_signers, resize_account, set_bit,
+};
+
+/// Rotates the validator set with the approval of the current set, in the
+/// style of Gravity Bridge valset updates.
+///
+/// Validators sign `(valset_nonce, valset_hash)` off-chain. Each call may
+/// upload another chunk of the new set and carry any number of Ed25519
+/// precompile signatures; both accumulate in the proposer's `ValsetUpdate`
+/// until the staged set matches `valset_hash` and the signers hold at least
+/// `attestation_threshold` of the current stake, at which point the registry
+/// is replaced and the record closed.
+pub fn rotate_validator_set(
+    ctx: Context<RotateValidatorSet>,
+    params: RotateValidatorSetParams,
+) -> Result<()> {
+    let bridge_config = &ctx.accounts.bridge_config;
+
+    let (valset_nonce, total_stake) = {
+        let registry = ctx.accounts.validator_registry.load()?;
+        (registry.valset_nonce, registry.total_stake)
+    };
+    require!(total_stake > 0, BridgeError::InvalidValidatorSet);
+    require!(
+        Some(params.valset_nonce) == valset_nonce.checked_add(1),
+        BridgeError::InvalidValsetNonce
+    );
+
+    let valset_update = &mut ctx.accounts.valset_update;
+
+    if valset_update.payer == Pubkey::default() {
+        valset_update.payer = ctx.accounts.payer.key();
+        valset_update.valset_nonce = params.valset_nonce;
+        valset_update.valset_hash = params.valset_hash;
+        valset_update.signer_bitmap = [0; 8];
+        valset_update.signed_power = 0;
+        valset_update.bump = ctx.bumps.valset_update;
+        valset_update.validators = Vec::new();
+    } else {
+        require!(
+            valset_update.valset_hash == params.valset_hash,
+            BridgeError::ValsetHashMismatch
+        );
+    }
+
+    // Stage the next chunk of the new set
+    require!(
+        valset_update.validators.len() + params.validators.len() <= MAX_VALIDATORS,
+        BridgeError::TooManyValidators
+    );
+    for validator in params.validators {
+        validate_validator_info(&validator)?;
+        require!(
+            !valset_update
+                .validators
+                .iter()
+                .any(|v| v.orchestrator_pubkey == validator.orchestrator_pubkey),
+            BridgeError::DuplicateValidator
+        );
+        valset_update.validators.push(validator);
+    }
+
+    // Count signatures from members of the current set
+    let expected_message = build_valset_update_payload(
+        ctx.program_id,
+        &bridge_config.mirage_chain_id,
+        params.valset_nonce,
+        &params.valset_hash,
+    );
+    let signers = load_ed25519_signers(&ctx.accounts.instructions_sysvar, &expected_message)?;
+
+    {
+        let (_, current) = ValidatorRegistry::load(&ctx.accounts.validator_registry)?;
+        for signer in signers {
+            let Some(index) = find_validator_index(&current, &signer) else {
+                continue;
+            };
+            if is_bit_set(&valset_update.signer_bitmap, index) {
+                continue;
+            }
+            set_bit(&mut valset_update.signer_bitmap, index);
+            valset_update.signed_power = valset_update
+                .signed_power
+                .checked_add(current[index].stake)
+                .ok_or(BridgeError::PowerOverflow)?;
+        }
+    }
+
+    let required_stake = bridge_config.required_stake(total_stake)?;
+
+    emit!(ValsetUpdateSigned {
+        valset_nonce: params.valset_nonce,
+        valset_hash: params.valset_hash,
+        signed_power: valset_update.signed_power,
+        threshold: required_stake,
+    });
+
+    if valset_update.signed_power < required_stake
+        || hash_validator_set(&valset_update.validators)? != valset_update.valset_hash
+    {
+        let new_space = ValsetUpdate::space(valset_update.validators.len());
+        return resize_account(
+            &valset_update.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_space,
+        );
+    }
+
+    let validators = std::mem::take(&mut valset_update.validators);
+    validate_validator_set(&validators)?;
+
+    {
+        let (mut registry, mut entries) = ValidatorRegistry::load_mut(&ctx.accounts.validator_registry)?;
+        registry.replace(&mut entries, &validators)?;
+        registry.valset_nonce = params.valset_nonce;
+
+        let clock = Clock::get()?;
+        emit!(ValidatorSetRotated {
+            valset_nonce: params.valset_nonce,
+            valset_hash: params.valset_hash,
+            validator_count: registry.len,
+            total_stake: registry.total_stake,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    ctx.accounts
+        .valset_update
+        .close(ctx.accounts.payer.to_account_info())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RotateValidatorSetParams {
+    pub valset_nonce: u64,
+    pub valset_hash: [u8; 32],
+    /// Next chunk of the new set to stage (may be empty)
+    pub validators: Vec<ValidatorInfo>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RotateValidatorSetParams)]
+pub struct RotateValidatorSet<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ValsetUpdate::space(0),
+        seeds = [b"valset_update", payer.key().as_ref(), &params.valset_nonce.to_le_bytes()[..]],
+        bump
+    )]
+    pub valset_update: Account<'info, ValsetUpdate>,
+
+    /// CHECK: Instructions sysvar for Ed25519 verification
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ClaimConfigUpdated;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Turns claim-based delivery on or off. Only affects mints finalized after
+/// the change; already claimable mints keep their expiry.
+pub fn set_claim_config(ctx: Context<SetClaimConfig>, params: SetClaimConfigParams) -> Result<()> {
+    apply_claim_config(&mut ctx.accounts.bridge_config, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_claim_config(
+    bridge_config: &mut BridgeConfig,
+    params: &SetClaimConfigParams,
+) -> Result<()> {
+    if params.claim_mode {
+        require!(
+            params.claim_expiry > 0 && params.unclaimed_sweep_address != Pubkey::default(),
+            BridgeError::InvalidClaimConfig
+        );
+    }
+
+    bridge_config.claim_mode = params.claim_mode;
+    bridge_config.claim_expiry = params.claim_expiry;
+    bridge_config.unclaimed_sweep_address = params.unclaimed_sweep_address;
+
+    let clock = Clock::get()?;
+    emit!(ClaimConfigUpdated {
+        claim_mode: params.claim_mode,
+        claim_expiry: params.claim_expiry,
+        unclaimed_sweep_address: params.unclaimed_sweep_address,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetClaimConfigParams {
+    pub claim_mode: bool,
+    pub claim_expiry: i64,
+    pub unclaimed_sweep_address: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct SetClaimConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
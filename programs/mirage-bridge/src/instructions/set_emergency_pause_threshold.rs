@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::EmergencyPauseThresholdUpdated;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Sets the stake, in basis points, that can pause via `emergency_pause`.
+/// 0 turns the validator path off; it may not exceed `attestation_threshold`.
+pub fn set_emergency_pause_threshold(
+    ctx: Context<SetEmergencyPauseThreshold>,
+    params: SetEmergencyPauseThresholdParams,
+) -> Result<()> {
+    apply_emergency_pause_threshold(&mut ctx.accounts.bridge_config, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_emergency_pause_threshold(
+    bridge_config: &mut BridgeConfig,
+    params: &SetEmergencyPauseThresholdParams,
+) -> Result<()> {
+    require!(
+        params.emergency_pause_threshold <= bridge_config.attestation_threshold,
+        BridgeError::InvalidEmergencyPauseThreshold
+    );
+
+    let old_threshold = bridge_config.emergency_pause_threshold;
+    bridge_config.emergency_pause_threshold = params.emergency_pause_threshold;
+
+    let clock = Clock::get()?;
+    emit!(EmergencyPauseThresholdUpdated {
+        old_emergency_pause_threshold: old_threshold,
+        new_emergency_pause_threshold: params.emergency_pause_threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetEmergencyPauseThresholdParams {
+    pub emergency_pause_threshold: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetEmergencyPauseThreshold<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BURN_BATCH_SIZE;
+use crate::errors::BridgeError;
+use crate::events::MaxBurnBatchSizeUpdated;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Sets how many entries a single `burn_batch` may carry
+pub fn set_max_burn_batch_size(
+    ctx: Context<SetMaxBurnBatchSize>,
+    params: SetMaxBurnBatchSizeParams,
+) -> Result<()> {
+    apply_max_burn_batch_size(&mut ctx.accounts.bridge_config, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_max_burn_batch_size(
+    bridge_config: &mut BridgeConfig,
+    params: &SetMaxBurnBatchSizeParams,
+) -> Result<()> {
+    require!(
+        params.max_burn_batch_size > 0 && params.max_burn_batch_size <= MAX_BURN_BATCH_SIZE,
+        BridgeError::InvalidMaxBurnBatchSize
+    );
+
+    let old_max = bridge_config.max_burn_batch_size;
+    bridge_config.max_burn_batch_size = params.max_burn_batch_size;
+
+    let clock = Clock::get()?;
+    emit!(MaxBurnBatchSizeUpdated {
+        old_max_burn_batch_size: old_max,
+        new_max_burn_batch_size: params.max_burn_batch_size,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetMaxBurnBatchSizeParams {
+    pub max_burn_batch_size: u8,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxBurnBatchSize<'info> {
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &fee_manager.key(), Role::FeeManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
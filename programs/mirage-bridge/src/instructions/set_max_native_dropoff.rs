@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::MaxNativeDropoffUpdated;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Sets the cap on attested native drop-offs (0 disables them). Checked when
+/// attestations are recorded, so records already attested keep their amount.
+pub fn set_max_native_dropoff(
+    ctx: Context<SetMaxNativeDropoff>,
+    params: SetMaxNativeDropoffParams,
+) -> Result<()> {
+    apply_max_native_dropoff(&mut ctx.accounts.bridge_config, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_max_native_dropoff(
+    bridge_config: &mut BridgeConfig,
+    params: &SetMaxNativeDropoffParams,
+) -> Result<()> {
+    let old_max = bridge_config.max_native_dropoff_lamports;
+    bridge_config.max_native_dropoff_lamports = params.max_native_dropoff_lamports;
+
+    let clock = Clock::get()?;
+    emit!(MaxNativeDropoffUpdated {
+        old_max_native_dropoff_lamports: old_max,
+        new_max_native_dropoff_lamports: params.max_native_dropoff_lamports,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetMaxNativeDropoffParams {
+    pub max_native_dropoff_lamports: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxNativeDropoff<'info> {
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &fee_manager.key(), Role::FeeManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
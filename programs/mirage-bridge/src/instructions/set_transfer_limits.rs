@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::TransferLimitsUpdated;
+use crate::state::{AmountLimits, BridgeConfig, BridgeRoles, Role};
+
+/// Sets per-transfer bounds for burns and mints. Mint limits are checked
+/// when attestations are recorded.
+pub fn set_transfer_limits(
+    ctx: Context<SetTransferLimits>,
+    params: SetTransferLimitsParams,
+) -> Result<()> {
+    apply_transfer_limits(&mut ctx.accounts.bridge_config, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_transfer_limits(
+    bridge_config: &mut BridgeConfig,
+    params: &SetTransferLimitsParams,
+) -> Result<()> {
+    require!(
+        params.burn_limits.is_valid() && params.mint_limits.is_valid(),
+        BridgeError::InvalidTransferLimits
+    );
+
+    bridge_config.burn_limits = params.burn_limits;
+    bridge_config.mint_limits = params.mint_limits;
+
+    let clock = Clock::get()?;
+    emit!(TransferLimitsUpdated {
+        burn_limits: params.burn_limits,
+        mint_limits: params.mint_limits,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetTransferLimitsParams {
+    pub burn_limits: AmountLimits,
+    pub mint_limits: AmountLimits,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferLimits<'info> {
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &fee_manager.key(), Role::FeeManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_VALIDATORS;
+use crate::errors::BridgeError;
+use crate::state::{
+    validate_validator_info, BridgeConfig, BridgeRoles, Role, ValidatorInfo, ValidatorStaging,
+};
+use crate::utils::resize_account;
+
+/// Appends a chunk of validators to the staging account. The full set is
+/// validated again and swapped into the registry by `commit_validator_set`.
+pub fn stage_validators(ctx: Context<StageValidators>, params: StageValidatorsParams) -> Result<()> {
+    let validator_staging = &mut ctx.accounts.validator_staging;
+    validator_staging.bump = ctx.bumps.validator_staging;
+
+    if params.reset {
+        validator_staging.validators.clear();
+    }
+
+    require!(
+        validator_staging.validators.len() + params.validators.len() <= MAX_VALIDATORS,
+        BridgeError::TooManyValidators
+    );
+
+    for validator in params.validators {
+        validate_validator_info(&validator)?;
+        require!(
+            !validator_staging
+                .validators
+                .iter()
+                .any(|v| v.orchestrator_pubkey == validator.orchestrator_pubkey),
+            BridgeError::DuplicateValidator
+        );
+        validator_staging.validators.push(validator);
+    }
+
+    let new_space = ValidatorStaging::space(validator_staging.validators.len());
+    resize_account(
+        &validator_staging.to_account_info(),
+        &ctx.accounts.validator_manager.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_space,
+    )
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StageValidatorsParams {
+    pub validators: Vec<ValidatorInfo>,
+    /// Discard previously staged entries before appending this chunk
+    pub reset: bool,
+}
+
+#[derive(Accounts)]
+pub struct StageValidators<'info> {
+    #[account(mut)]
+    pub validator_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.authority_valset_updates_disabled @ BridgeError::AuthorityValsetUpdatesDisabled
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &validator_manager.key(), Role::ValidatorManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        init_if_needed,
+        payer = validator_manager,
+        space = ValidatorStaging::space(0),
+        seeds = [b"validator_staging"],
+        bump
+    )]
+    pub validator_staging: Account<'info, ValidatorStaging>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::constants::PAUSE_MINTS;
+use crate::errors::BridgeError;
+use crate::events::UnclaimedMintSwept;
+use crate::state::{BridgeConfig, MintRecord};
+use crate::utils::mint_bridged_tokens;
+
+/// Permissionless: mints an expired, unclaimed transfer to the configured
+/// sweep address and closes the record.
+pub fn sweep_unclaimed_mint(
+    ctx: Context<SweepUnclaimedMint>,
+    _params: SweepUnclaimedMintParams,
+) -> Result<()> {
+    ctx.accounts.bridge_config.check_not_paused(PAUSE_MINTS)?;
+
+    let mint_record = &ctx.accounts.mint_record;
+    require!(mint_record.is_claimable(), BridgeError::MintNotClaimable);
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= mint_record.claim_expires_at,
+        BridgeError::ClaimNotExpired
+    );
+
+    mint_bridged_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.sweep_token_account.to_account_info(),
+        &mut ctx.accounts.bridge_config,
+        mint_record.amount,
+    )?;
+
+    emit!(UnclaimedMintSwept {
+        burn_tx_hash: mint_record.burn_tx_hash,
+        recipient: mint_record.recipient,
+        sweep_token_account: ctx.accounts.sweep_token_account.key(),
+        amount: mint_record.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SweepUnclaimedMintParams {
+    pub burn_tx_hash: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(params: SweepUnclaimedMintParams)]
+pub struct SweepUnclaimedMint<'info> {
+    /// CHECK: Payer that funded MintRecord (rent refund target)
+    #[account(mut, address = mint_record.payer)]
+    pub mint_record_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        constraint = sweep_token_account.owner == bridge_config.unclaimed_sweep_address
+            @ BridgeError::InvalidSweepAccount
+    )]
+    pub sweep_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_record", &params.burn_tx_hash[..]],
+        bump = mint_record.bump,
+        close = mint_record_payer
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::BurnTotalsSynced;
+use crate::state::{BridgeConfig, BurnShard};
+
+/// Permissionless crank that folds a shard's unsynced burns into
+/// `BridgeConfig::total_burned`. Burns themselves never write the config.
+pub fn sync_burn_totals(ctx: Context<SyncBurnTotals>) -> Result<()> {
+    let burn_shard = &mut ctx.accounts.burn_shard;
+    let amount = burn_shard.unsynced_burned;
+    burn_shard.unsynced_burned = 0;
+
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    bridge_config.total_burned = bridge_config
+        .total_burned
+        .checked_add(amount)
+        .ok_or(BridgeError::AmountOverflow)?;
+
+    let clock = Clock::get()?;
+    emit!(BurnTotalsSynced {
+        shard_id: burn_shard.shard_id,
+        amount,
+        total_burned: bridge_config.total_burned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SyncBurnTotals<'info> {
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_shard", &burn_shard.shard_id.to_le_bytes()[..]],
+        bump = burn_shard.bump
+    )]
+    pub burn_shard: Account<'info, BurnShard>,
+}
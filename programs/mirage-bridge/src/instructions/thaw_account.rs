@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, ThawAccount, Token, TokenAccount};
+
+use crate::errors::BridgeError;
+use crate::events::TokenAccountThawed;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Thaws a frozen token account. Thawing also needs the freeze authority,
+/// so accounts still frozen when it is renounced stay frozen.
+pub fn thaw_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
+    let bridge_config = &ctx.accounts.bridge_config;
+    require!(
+        ctx.accounts.token_mint.freeze_authority == Some(bridge_config.key()).into(),
+        BridgeError::FreezeAuthorityRenounced
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"bridge_config", &[bridge_config.bump]]];
+    token::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            authority: bridge_config.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let clock = Clock::get()?;
+    emit!(TokenAccountThawed {
+        token_account: ctx.accounts.token_account.key(),
+        owner: ctx.accounts.token_account.owner,
+        compliance_authority: ctx.accounts.compliance_authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ThawTokenAccount<'info> {
+    pub compliance_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &compliance_authority.key(), Role::Compliance) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        seeds = [b"mint"],
+        bump
+    )]
+    pub token_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(mut, token::mint = token_mint)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -2,15 +2,22 @@ use anchor_lang::prelude::*;
 
 use crate::errors::BridgeError;
 use crate::events::BridgeUnpaused;
-use crate::state::BridgeConfig;
+use crate::instructions::PauseParams;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// Clears the `PAUSE_*` bits in `params.flags`
+pub fn unpause(ctx: Context<Unpause>, params: PauseParams) -> Result<()> {
+    params.validate()?;
 
-pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
     let bridge_config = &mut ctx.accounts.bridge_config;
-    bridge_config.paused = false;
+    let changed_flags = params.flags & bridge_config.paused_flags;
+    bridge_config.paused_flags &= !params.flags;
 
     let clock = Clock::get()?;
     emit!(BridgeUnpaused {
-        authority: ctx.accounts.authority.key(),
+        authority: ctx.accounts.admin.key(),
+        changed_flags,
+        paused_flags: bridge_config.paused_flags,
         timestamp: clock.unix_timestamp,
     });
 
@@ -19,13 +26,19 @@ pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct Unpause<'info> {
-    pub authority: Signer<'info>,
+    pub admin: Signer<'info>,
 
     #[account(
         mut,
         seeds = [b"bridge_config"],
-        bump = bridge_config.bump,
-        has_one = authority @ BridgeError::Unauthorized
+        bump = bridge_config.bump
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
 }
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ConfigUpdated;
+use crate::state::{
+    validate_attestation_threshold, validate_mirage_chain_id, AmountLimits, BridgeConfig,
+    BridgeRoles, Role,
+};
+
+/// Updates core settings fixed at `initialize`. Omitted fields keep their
+/// current value; set ones are validated exactly as `initialize` does.
+///
+/// In-flight `MintRecord`s are not reset:
+/// - `attestation_threshold` is read when a record is finalized, so records
+///   still collecting signatures need the new threshold, whether higher or
+///   lower. Records already finalized or claimable are unaffected.
+/// - `mint_limits` are checked on every `attest`, so a record outside the
+///   new bounds stops collecting signatures, but can still be finalized if
+///   it has already reached quorum.
+/// - `mirage_chain_id` is bound into mint attestations, validator set
+///   updates and emergency pause messages, so signatures made under the old
+///   ID stop verifying. Attestations already recorded still count.
+/// - `claim_expiry` applies to mints that become claimable afterwards;
+///   already claimable mints keep their expiry.
+pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    apply_update_config(&mut ctx.accounts.bridge_config, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_update_config(
+    bridge_config: &mut BridgeConfig,
+    params: &UpdateConfigParams,
+) -> Result<()> {
+    let attestation_threshold = params
+        .attestation_threshold
+        .unwrap_or(bridge_config.attestation_threshold);
+    let mirage_chain_id = params
+        .mirage_chain_id
+        .clone()
+        .unwrap_or_else(|| bridge_config.mirage_chain_id.clone());
+    let burn_limits = params.burn_limits.unwrap_or(bridge_config.burn_limits);
+    let mint_limits = params.mint_limits.unwrap_or(bridge_config.mint_limits);
+    let claim_expiry = params.claim_expiry.unwrap_or(bridge_config.claim_expiry);
+
+    validate_attestation_threshold(attestation_threshold)?;
+    validate_mirage_chain_id(&mirage_chain_id)?;
+    require!(
+        burn_limits.is_valid() && mint_limits.is_valid(),
+        BridgeError::InvalidTransferLimits
+    );
+    require!(
+        claim_expiry >= 0 && (!bridge_config.claim_mode || claim_expiry > 0),
+        BridgeError::InvalidClaimConfig
+    );
+
+    let clock = Clock::get()?;
+    emit!(ConfigUpdated {
+        old_attestation_threshold: bridge_config.attestation_threshold,
+        new_attestation_threshold: attestation_threshold,
+        old_mirage_chain_id: bridge_config.mirage_chain_id.clone(),
+        new_mirage_chain_id: mirage_chain_id.clone(),
+        old_burn_limits: bridge_config.burn_limits,
+        new_burn_limits: burn_limits,
+        old_mint_limits: bridge_config.mint_limits,
+        new_mint_limits: mint_limits,
+        old_claim_expiry: bridge_config.claim_expiry,
+        new_claim_expiry: claim_expiry,
+        timestamp: clock.unix_timestamp,
+    });
+
+    bridge_config.attestation_threshold = attestation_threshold;
+    bridge_config.mirage_chain_id = mirage_chain_id;
+    bridge_config.burn_limits = burn_limits;
+    bridge_config.mint_limits = mint_limits;
+    bridge_config.claim_expiry = claim_expiry;
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateConfigParams {
+    pub attestation_threshold: Option<u64>, // Basis points
+    pub mirage_chain_id: Option<String>,
+    pub burn_limits: Option<AmountLimits>,
+    pub mint_limits: Option<AmountLimits>,
+    pub claim_expiry: Option<i64>, // Seconds a mint stays claimable
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &admin.key(), Role::Admin) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+}
@@ -7,7 +7,7 @@ use mpl_token_metadata::{
 
 use crate::errors::BridgeError;
 use crate::events::MetadataUpdated;
-use crate::state::BridgeConfig;
+use crate::state::{BridgeConfig, BridgeRoles, Role};
 
 pub fn update_metadata(ctx: Context<UpdateMetadata>, params: UpdateMetadataParams) -> Result<()> {
     let metadata_data = DataV2 {
@@ -39,7 +39,7 @@ pub fn update_metadata(ctx: Context<UpdateMetadata>, params: UpdateMetadataParam
             .metadata(&ctx.accounts.metadata)
             .mint(&ctx.accounts.mint.to_account_info())
             .mint_authority(&ctx.accounts.bridge_config.to_account_info())
-            .payer(&ctx.accounts.authority)
+            .payer(&ctx.accounts.metadata_manager)
             .update_authority(&ctx.accounts.bridge_config.to_account_info(), true)
             .system_program(&ctx.accounts.system_program)
             .data(metadata_data)
@@ -49,7 +49,7 @@ pub fn update_metadata(ctx: Context<UpdateMetadata>, params: UpdateMetadataParam
 
     let clock = Clock::get()?;
     emit!(MetadataUpdated {
-        authority: ctx.accounts.authority.key(),
+        authority: ctx.accounts.metadata_manager.key(),
         mint: ctx.accounts.mint.key(),
         metadata: ctx.accounts.metadata.key(),
         created: !metadata_exists,
@@ -69,16 +69,22 @@ pub struct UpdateMetadataParams {
 #[derive(Accounts)]
 pub struct UpdateMetadata<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub metadata_manager: Signer<'info>,
 
     #[account(
         seeds = [b"bridge_config"],
         bump = bridge_config.bump,
-        has_one = authority @ BridgeError::Unauthorized,
         has_one = mint @ BridgeError::InvalidMint
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
 
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &metadata_manager.key(), Role::MetadataManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
     #[account(
         seeds = [b"mint"],
         bump
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::ValidatorStakeUpdated;
+use crate::state::{find_validator_index, BridgeConfig, BridgeRoles, Role, ValidatorRegistry};
+
+pub fn update_validator_stake(
+    ctx: Context<UpdateValidatorStake>,
+    params: UpdateValidatorStakeParams,
+) -> Result<()> {
+    apply_update_validator_stake(&ctx.accounts.validator_registry, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_update_validator_stake(
+    validator_registry: &AccountLoader<ValidatorRegistry>,
+    params: &UpdateValidatorStakeParams,
+) -> Result<()> {
+    require!(params.stake > 0, BridgeError::ZeroStake);
+
+    let (mut registry, mut entries) = ValidatorRegistry::load_mut(validator_registry)?;
+
+    let index = find_validator_index(&entries[..registry.len as usize], &params.orchestrator_pubkey)
+        .ok_or(BridgeError::ValidatorNotFound)?;
+
+    let old_stake = entries[index].stake;
+    entries[index].stake = params.stake;
+    registry.recompute_total_stake(&entries)?;
+    registry.advance_valset_nonce()?;
+
+    let clock = Clock::get()?;
+    emit!(ValidatorStakeUpdated {
+        orchestrator: params.orchestrator_pubkey,
+        old_stake,
+        new_stake: params.stake,
+        total_stake: registry.total_stake,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateValidatorStakeParams {
+    pub orchestrator_pubkey: Pubkey,
+    pub stake: u64,
+}
+
+#[derive(Accounts)]
+pub struct UpdateValidatorStake<'info> {
+    pub validator_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.authority_valset_updates_disabled @ BridgeError::AuthorityValsetUpdatesDisabled,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &validator_manager.key(), Role::ValidatorManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry"],
+        bump = validator_registry.load()?.bump
+    )]
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
+}
@@ -1,50 +1,65 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::MAX_VALIDATORS;
 use crate::errors::BridgeError;
-use crate::state::{BridgeConfig, ValidatorInfo, ValidatorRegistry};
+use crate::events::ValidatorSetUpdated;
+use crate::state::{
+    validate_validator_set, BridgeConfig, BridgeRoles, Role, ValidatorInfo, ValidatorRegistry,
+};
 
 pub fn update_validators(ctx: Context<UpdateValidators>, params: UpdateValidatorsParams) -> Result<()> {
-    require!(!params.validators.is_empty(), BridgeError::EmptyValidatorSet);
-    require!(
-        params.validators.len() <= MAX_VALIDATORS,
-        BridgeError::TooManyValidators
-    );
-
-    let total_stake: u64 = params
-        .validators
-        .iter()
-        .map(|v| v.stake)
-        .try_fold(0u64, |acc, stake| acc.checked_add(stake))
-        .ok_or(BridgeError::PowerOverflow)?;
-
-    let validator_registry = &mut ctx.accounts.validator_registry;
-    validator_registry.validators = params.validators;
-    validator_registry.total_stake = total_stake;
+    apply_update_validators(&ctx.accounts.validator_registry, &params)
+}
+
+/// Shared with `execute_action`
+pub fn apply_update_validators(
+    validator_registry: &AccountLoader<ValidatorRegistry>,
+    params: &UpdateValidatorsParams,
+) -> Result<()> {
+    validate_validator_set(&params.validators)?;
+
+    let (mut registry, mut entries) = ValidatorRegistry::load_mut(validator_registry)?;
+    registry.replace(&mut entries, &params.validators)?;
+    registry.advance_valset_nonce()?;
+
+    let clock = Clock::get()?;
+    emit!(ValidatorSetUpdated {
+        validator_count: registry.len,
+        total_stake: registry.total_stake,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdateValidatorsParams {
     pub validators: Vec<ValidatorInfo>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateValidators<'info> {
-    pub authority: Signer<'info>,
+    pub validator_manager: Signer<'info>,
 
     #[account(
         seeds = [b"bridge_config"],
         bump = bridge_config.bump,
-        has_one = authority @ BridgeError::Unauthorized
+        constraint = !bridge_config.authority_valset_updates_disabled @ BridgeError::AuthorityValsetUpdatesDisabled,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
     )]
     pub bridge_config: Account<'info, BridgeConfig>,
 
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &validator_manager.key(), Role::ValidatorManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
     #[account(
         mut,
         seeds = [b"validator_registry"],
-        bump = validator_registry.bump
+        bump = validator_registry.load()?.bump
     )]
-    pub validator_registry: Account<'info, ValidatorRegistry>,
+    pub validator_registry: AccountLoader<'info, ValidatorRegistry>,
 }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::GasReserveWithdrawn;
+use crate::state::{BridgeConfig, BridgeRoles, GasReserve, Role};
+use crate::utils::{spare_lamports, transfer_lamports};
+
+/// Withdraws SOL from the gas reserve to the fee manager, leaving it rent exempt
+pub fn withdraw_gas_reserve(
+    ctx: Context<WithdrawGasReserve>,
+    params: WithdrawGasReserveParams,
+) -> Result<()> {
+    apply_withdraw_gas_reserve(
+        &ctx.accounts.gas_reserve,
+        &ctx.accounts.fee_manager.to_account_info(),
+        &params,
+    )
+}
+
+/// Shared with `execute_action`, which pays out to whoever queued the action
+pub fn apply_withdraw_gas_reserve<'info>(
+    gas_reserve: &Account<'info, GasReserve>,
+    destination: &AccountInfo<'info>,
+    params: &WithdrawGasReserveParams,
+) -> Result<()> {
+    require!(params.amount > 0, BridgeError::InvalidAmount);
+
+    let gas_reserve = gas_reserve.to_account_info();
+    require!(
+        spare_lamports(&gas_reserve)? >= params.amount,
+        BridgeError::InsufficientGasReserve
+    );
+    transfer_lamports(&gas_reserve, destination, params.amount)?;
+
+    let clock = Clock::get()?;
+    emit!(GasReserveWithdrawn {
+        authority: destination.key(),
+        amount: params.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawGasReserveParams {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawGasReserve<'info> {
+    #[account(mut)]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = bridge_config.admin_action_delay == 0 @ BridgeError::TimelockRequired,
+        constraint = bridge_config.admin_threshold == 0 @ BridgeError::MultisigRequired
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [b"bridge_roles"],
+        bump = bridge_roles.bump,
+        constraint = bridge_roles.has_role(&bridge_config.authority, &fee_manager.key(), Role::FeeManager) @ BridgeError::Unauthorized
+    )]
+    pub bridge_roles: Account<'info, BridgeRoles>,
+
+    #[account(
+        mut,
+        seeds = [b"gas_reserve"],
+        bump = gas_reserve.bump
+    )]
+    pub gas_reserve: Account<'info, GasReserve>,
+}
@@ -1,17 +1,62 @@
 #![allow(unexpected_cfgs)]
 #![allow(clippy::result_large_err)]
 use anchor_lang::prelude::*;
+use instructions::accept_authority::*;
+use instructions::add_to_denylist::*;
+use instructions::add_validator::*;
+use instructions::approve_action::*;
+use instructions::attest::*;
 use instructions::burn::*;
+use instructions::burn_batch::*;
+use instructions::burn_from_program::*;
+use instructions::burn_with_permit::*;
+use instructions::cancel_action::*;
+use instructions::cancel_authority_transfer::*;
+use instructions::claim_mint::*;
+use instructions::close_valset_update::*;
+use instructions::commit_validator_set::*;
+use instructions::disable_authority_valset_updates::*;
+use instructions::emergency_pause::*;
+use instructions::execute_action::*;
+use instructions::finalize_mint::*;
+use instructions::freeze_account::*;
+use instructions::grant_role::*;
+use instructions::fund_gas_reserve::*;
+use instructions::init_burn_shard::*;
 use instructions::initialize::*;
-use instructions::mint::*;
+use instructions::migrate_bridge_config::*;
+use instructions::migrate_bridge_state::*;
+use instructions::migrate_burn_record::*;
+use instructions::migrate_mint_record::*;
+use instructions::migrate_validator_registry::*;
 use instructions::pause::*;
-use instructions::transfer_authority::*;
+use instructions::propose_authority::*;
+use instructions::queue_action::*;
+use instructions::remove_from_denylist::*;
+use instructions::remove_validator::*;
+use instructions::renounce_freeze_authority::*;
+use instructions::revoke_role::*;
+use instructions::resize_validator_registry::*;
+use instructions::rotate_validator_set::*;
+use instructions::set_claim_config::*;
+use instructions::set_emergency_pause_threshold::*;
+use instructions::set_max_burn_batch_size::*;
+use instructions::set_max_native_dropoff::*;
+use instructions::set_transfer_limits::*;
+use instructions::stage_validators::*;
+use instructions::sweep_unclaimed_mint::*;
+use instructions::sync_burn_totals::*;
+use instructions::thaw_account::*;
 use instructions::unpause::*;
+use instructions::update_config::*;
 use instructions::update_metadata::*;
+use instructions::update_validator_stake::*;
 use instructions::update_validators::*;
+use instructions::withdraw_gas_reserve::*;
 
 declare_id!("4taEm2D4skz4sPCMJEnLhF9XSDoULtgnn85M1bxbWA2c");
 
+pub mod bridge_cpi;
 pub mod constants;
 pub mod errors;
 pub mod events;
@@ -31,8 +76,49 @@ pub mod mirage_bridge {
         instructions::burn::burn(ctx, params)
     }
 
-    pub fn mint(ctx: Context<MintTokens>, params: MintParams) -> Result<()> {
-        instructions::mint::mint(ctx, params)
+    pub fn burn_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BurnBatch<'info>>,
+        params: BurnBatchParams,
+    ) -> Result<()> {
+        instructions::burn_batch::burn_batch(ctx, params)
+    }
+
+    pub fn burn_with_permit(ctx: Context<BurnWithPermit>, params: BurnWithPermitParams) -> Result<()> {
+        instructions::burn_with_permit::burn_with_permit(ctx, params)
+    }
+
+    pub fn burn_from_program(
+        ctx: Context<BurnFromProgram>,
+        params: BurnFromProgramParams,
+    ) -> Result<()> {
+        instructions::burn_from_program::burn_from_program(ctx, params)
+    }
+
+    pub fn init_burn_shard(ctx: Context<InitBurnShard>, params: InitBurnShardParams) -> Result<()> {
+        instructions::init_burn_shard::init_burn_shard(ctx, params)
+    }
+
+    pub fn sync_burn_totals(ctx: Context<SyncBurnTotals>) -> Result<()> {
+        instructions::sync_burn_totals::sync_burn_totals(ctx)
+    }
+
+    pub fn attest(ctx: Context<Attest>, params: AttestParams) -> Result<()> {
+        instructions::attest::attest(ctx, params)
+    }
+
+    pub fn finalize_mint(ctx: Context<FinalizeMint>, params: FinalizeMintParams) -> Result<()> {
+        instructions::finalize_mint::finalize_mint(ctx, params)
+    }
+
+    pub fn claim_mint(ctx: Context<ClaimMint>, params: ClaimMintParams) -> Result<()> {
+        instructions::claim_mint::claim_mint(ctx, params)
+    }
+
+    pub fn sweep_unclaimed_mint(
+        ctx: Context<SweepUnclaimedMint>,
+        params: SweepUnclaimedMintParams,
+    ) -> Result<()> {
+        instructions::sweep_unclaimed_mint::sweep_unclaimed_mint(ctx, params)
     }
 
     pub fn update_validators(
@@ -42,19 +128,184 @@ pub mod mirage_bridge {
         instructions::update_validators::update_validators(ctx, params)
     }
 
-    pub fn pause(ctx: Context<Pause>) -> Result<()> {
-        instructions::pause::pause(ctx)
+    pub fn add_validator(ctx: Context<AddValidator>, params: AddValidatorParams) -> Result<()> {
+        instructions::add_validator::add_validator(ctx, params)
+    }
+
+    pub fn remove_validator(
+        ctx: Context<RemoveValidator>,
+        params: RemoveValidatorParams,
+    ) -> Result<()> {
+        instructions::remove_validator::remove_validator(ctx, params)
+    }
+
+    pub fn update_validator_stake(
+        ctx: Context<UpdateValidatorStake>,
+        params: UpdateValidatorStakeParams,
+    ) -> Result<()> {
+        instructions::update_validator_stake::update_validator_stake(ctx, params)
+    }
+
+    pub fn stage_validators(
+        ctx: Context<StageValidators>,
+        params: StageValidatorsParams,
+    ) -> Result<()> {
+        instructions::stage_validators::stage_validators(ctx, params)
+    }
+
+    pub fn commit_validator_set(ctx: Context<CommitValidatorSet>) -> Result<()> {
+        instructions::commit_validator_set::commit_validator_set(ctx)
+    }
+
+    pub fn resize_validator_registry(
+        ctx: Context<ResizeValidatorRegistry>,
+        params: ResizeValidatorRegistryParams,
+    ) -> Result<()> {
+        instructions::resize_validator_registry::resize_validator_registry(ctx, params)
+    }
+
+    pub fn rotate_validator_set(
+        ctx: Context<RotateValidatorSet>,
+        params: RotateValidatorSetParams,
+    ) -> Result<()> {
+        instructions::rotate_validator_set::rotate_validator_set(ctx, params)
     }
 
-    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
-        instructions::unpause::unpause(ctx)
+    pub fn close_valset_update(ctx: Context<CloseValsetUpdate>) -> Result<()> {
+        instructions::close_valset_update::close_valset_update(ctx)
     }
 
-    pub fn transfer_authority(
-        ctx: Context<TransferAuthority>,
-        params: TransferAuthorityParams,
+    pub fn disable_authority_valset_updates(
+        ctx: Context<DisableAuthorityValsetUpdates>,
     ) -> Result<()> {
-        instructions::transfer_authority::transfer_authority(ctx, params)
+        instructions::disable_authority_valset_updates::disable_authority_valset_updates(ctx)
+    }
+
+    pub fn pause(ctx: Context<Pause>, params: PauseParams) -> Result<()> {
+        instructions::pause::pause(ctx, params)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, params: PauseParams) -> Result<()> {
+        instructions::unpause::unpause(ctx, params)
+    }
+
+    pub fn emergency_pause(ctx: Context<EmergencyPause>, params: PauseParams) -> Result<()> {
+        instructions::emergency_pause::emergency_pause(ctx, params)
+    }
+
+    pub fn set_emergency_pause_threshold(
+        ctx: Context<SetEmergencyPauseThreshold>,
+        params: SetEmergencyPauseThresholdParams,
+    ) -> Result<()> {
+        instructions::set_emergency_pause_threshold::set_emergency_pause_threshold(ctx, params)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        instructions::update_config::update_config(ctx, params)
+    }
+
+    pub fn set_claim_config(
+        ctx: Context<SetClaimConfig>,
+        params: SetClaimConfigParams,
+    ) -> Result<()> {
+        instructions::set_claim_config::set_claim_config(ctx, params)
+    }
+
+    pub fn set_max_native_dropoff(
+        ctx: Context<SetMaxNativeDropoff>,
+        params: SetMaxNativeDropoffParams,
+    ) -> Result<()> {
+        instructions::set_max_native_dropoff::set_max_native_dropoff(ctx, params)
+    }
+
+    pub fn set_transfer_limits(
+        ctx: Context<SetTransferLimits>,
+        params: SetTransferLimitsParams,
+    ) -> Result<()> {
+        instructions::set_transfer_limits::set_transfer_limits(ctx, params)
+    }
+
+    pub fn set_max_burn_batch_size(
+        ctx: Context<SetMaxBurnBatchSize>,
+        params: SetMaxBurnBatchSizeParams,
+    ) -> Result<()> {
+        instructions::set_max_burn_batch_size::set_max_burn_batch_size(ctx, params)
+    }
+
+    pub fn fund_gas_reserve(
+        ctx: Context<FundGasReserve>,
+        params: FundGasReserveParams,
+    ) -> Result<()> {
+        instructions::fund_gas_reserve::fund_gas_reserve(ctx, params)
+    }
+
+    pub fn withdraw_gas_reserve(
+        ctx: Context<WithdrawGasReserve>,
+        params: WithdrawGasReserveParams,
+    ) -> Result<()> {
+        instructions::withdraw_gas_reserve::withdraw_gas_reserve(ctx, params)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, params: RoleParams) -> Result<()> {
+        instructions::grant_role::grant_role(ctx, params)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, params: RoleParams) -> Result<()> {
+        instructions::revoke_role::revoke_role(ctx, params)
+    }
+
+    pub fn add_to_denylist(ctx: Context<AddToDenylist>, params: DenylistParams) -> Result<()> {
+        instructions::add_to_denylist::add_to_denylist(ctx, params)
+    }
+
+    pub fn remove_from_denylist(
+        ctx: Context<RemoveFromDenylist>,
+        params: DenylistParams,
+    ) -> Result<()> {
+        instructions::remove_from_denylist::remove_from_denylist(ctx, params)
+    }
+
+    pub fn freeze_account(ctx: Context<FreezeTokenAccount>) -> Result<()> {
+        instructions::freeze_account::freeze_account(ctx)
+    }
+
+    pub fn thaw_account(ctx: Context<ThawTokenAccount>) -> Result<()> {
+        instructions::thaw_account::thaw_account(ctx)
+    }
+
+    pub fn renounce_freeze_authority(ctx: Context<RenounceFreezeAuthority>) -> Result<()> {
+        instructions::renounce_freeze_authority::renounce_freeze_authority(ctx)
+    }
+
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        params: ProposeAuthorityParams,
+    ) -> Result<()> {
+        instructions::propose_authority::propose_authority(ctx, params)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::accept_authority(ctx)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer::cancel_authority_transfer(ctx)
+    }
+
+    pub fn queue_action(ctx: Context<QueueAction>, params: QueueActionParams) -> Result<()> {
+        instructions::queue_action::queue_action(ctx, params)
+    }
+
+    pub fn approve_action(ctx: Context<ApproveAction>) -> Result<()> {
+        instructions::approve_action::approve_action(ctx)
+    }
+
+    pub fn execute_action(ctx: Context<ExecuteAction>) -> Result<()> {
+        instructions::execute_action::execute_action(ctx)
+    }
+
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        instructions::cancel_action::cancel_action(ctx)
     }
 
     pub fn update_metadata(
@@ -63,4 +314,30 @@ pub mod mirage_bridge {
     ) -> Result<()> {
         instructions::update_metadata::update_metadata(ctx, params)
     }
+
+    pub fn migrate_bridge_config(ctx: Context<MigrateBridgeConfig>) -> Result<()> {
+        instructions::migrate_bridge_config::migrate_bridge_config(ctx)
+    }
+
+    pub fn migrate_bridge_state(ctx: Context<MigrateBridgeState>) -> Result<()> {
+        instructions::migrate_bridge_state::migrate_bridge_state(ctx)
+    }
+
+    pub fn migrate_validator_registry(ctx: Context<MigrateValidatorRegistry>) -> Result<()> {
+        instructions::migrate_validator_registry::migrate_validator_registry(ctx)
+    }
+
+    pub fn migrate_mint_record(
+        ctx: Context<MigrateMintRecord>,
+        params: MigrateMintRecordParams,
+    ) -> Result<()> {
+        instructions::migrate_mint_record::migrate_mint_record(ctx, params)
+    }
+
+    pub fn migrate_burn_record(
+        ctx: Context<MigrateBurnRecord>,
+        params: MigrateBurnRecordParams,
+    ) -> Result<()> {
+        instructions::migrate_burn_record::migrate_burn_record(ctx, params)
+    }
 }
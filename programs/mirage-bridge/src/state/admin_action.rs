@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ADMIN_SIGNERS;
+use crate::instructions::{
+    AddValidatorParams, ProposeAuthorityParams, RemoveValidatorParams, RoleParams,
+    SetClaimConfigParams, SetEmergencyPauseThresholdParams, SetMaxBurnBatchSizeParams,
+    SetMaxNativeDropoffParams, SetTransferLimitsParams, UpdateConfigParams,
+    UpdateValidatorStakeParams, UpdateValidatorsParams, WithdrawGasReserveParams,
+};
+use crate::state::{BridgeConfig, BridgeRoles, Role};
+
+/// A sensitive admin change. While `admin_action_delay` or `admin_threshold`
+/// is non-zero these only take effect through `queue_action` and
+/// `execute_action`.
+///
+/// Admin instructions without a variant stay single-key:
+/// - `stage_validators` only writes the staging buffer; the set changes
+///   through `CommitValidatorSet`, which pins the staged set's hash.
+/// - `resize_validator_registry` only changes the registry's capacity.
+/// - `pause`, `unpause`, the denylist, freezes and metadata must react
+///   faster than any delay allows, or do not touch bridge funds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum AdminAction {
+    SetAttestationThreshold { attestation_threshold: u64 },
+    UpdateValidators(UpdateValidatorsParams),
+    SetTransferLimits(SetTransferLimitsParams),
+    SetMaxNativeDropoff(SetMaxNativeDropoffParams),
+    ProposeAuthority(ProposeAuthorityParams),
+    SetAdminActionDelay { admin_action_delay: i64 },
+    SetAdminMultisig { signers: Vec<Pubkey>, threshold: u8 },
+    UpdateConfig(UpdateConfigParams),
+    SetClaimConfig(SetClaimConfigParams),
+    GrantRole(RoleParams),
+    RevokeRole(RoleParams),
+    SetMaxBurnBatchSize(SetMaxBurnBatchSizeParams),
+    SetEmergencyPauseThreshold(SetEmergencyPauseThresholdParams),
+    DisableAuthorityValsetUpdates,
+    RenounceFreezeAuthority,
+    CommitValidatorSet { staged_set_hash: [u8; 32] }, // See `ValidatorStaging::set_hash`
+    AddValidator(AddValidatorParams),
+    RemoveValidator(RemoveValidatorParams),
+    UpdateValidatorStake(UpdateValidatorStakeParams),
+    WithdrawGasReserve(WithdrawGasReserveParams), // Paid out to `queued_by`
+}
+
+#[account]
+pub struct QueuedAction {
+    pub action_id: u64,
+    pub queued_by: Pubkey, // Gets the rent back when the action is executed or cancelled
+    pub eta: i64,          // Earliest execution time
+    pub bump: u8,
+    pub approvals: Vec<Pubkey>, // Admin signers that approved; unused while multisig is off
+    pub action: AdminAction,
+}
+
+impl QueuedAction {
+    pub fn space(action: &AdminAction) -> usize {
+        8 + 8
+            + 32
+            + 8
+            + 1
+            + 4
+            + MAX_ADMIN_SIGNERS * 32
+            + action.try_to_vec().map_or(0, |data| data.len())
+    }
+}
+
+/// Who may queue and cancel admin actions: admin signers while the multisig
+/// is on, `Role::Admin` holders otherwise
+pub fn can_manage_admin_actions(
+    bridge_config: &BridgeConfig,
+    bridge_roles: &BridgeRoles,
+    key: &Pubkey,
+) -> bool {
+    if bridge_config.admin_threshold > 0 {
+        bridge_config.is_admin_signer(key)
+    } else {
+        bridge_roles.has_role(&bridge_config.authority, key, Role::Admin)
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::MAX_CHAIN_ID_LEN;
+use crate::constants::{BASIS_POINTS_DENOMINATOR, MAX_ADMIN_SIGNERS, MAX_CHAIN_ID_LEN};
+use crate::errors::BridgeError;
 
 #[account]
 #[derive(InitSpace)]
@@ -11,8 +12,131 @@ pub struct BridgeConfig {
     pub mirage_chain_id: String,
     pub attestation_threshold: u64,
     pub total_minted: u64,
-    pub total_burned: u64,
-    pub burn_nonce: u64,
-    pub paused: bool,
+    pub total_burned: u64, // Lags burns until shards are folded in by `sync_burn_totals`
+    pub burn_nonce: u64,   // Legacy global counter; frozen now that burns use `BurnShard`s
+    pub paused_flags: u8, // `PAUSE_*` bits; replaces the old `paused` bool in place
     pub bump: u8,
+    // Once set, the validator set can only change via `rotate_validator_set`
+    pub authority_valset_updates_disabled: bool,
+    // Claim mode: quorum makes a mint claimable instead of delivering it
+    pub claim_mode: bool,
+    pub claim_expiry: i64,                 // Seconds a mint stays claimable
+    pub unclaimed_sweep_address: Pubkey,   // Owner of the account expired claims are swept to
+    pub max_native_dropoff_lamports: u64,  // Cap on attested SOL drop-offs; 0 disables them
+    pub burn_limits: AmountLimits,         // Solana -> Mirage
+    pub mint_limits: AmountLimits,         // Mirage -> Solana
+    pub max_burn_batch_size: u8,           // Entries allowed in one `burn_batch`
+    pub pending_authority: Option<Pubkey>, // Proposed by `propose_authority`
+    pub authority_transfer_unlock_at: i64, // When `pending_authority` may accept
+    pub admin_action_delay: i64,           // Timelock on queued admin actions; 0 = off
+    pub admin_action_nonce: u64,           // ID of the next queued admin action
+    pub admin_threshold: u8,               // Approvals a queued action needs; 0 = multisig off
+    #[max_len(MAX_ADMIN_SIGNERS)]
+    pub admin_signers: Vec<Pubkey>,
+    // Stake (basis points) whose signatures can pause via `emergency_pause`;
+    // 0 = off, and capped at `attestation_threshold` when used
+    pub emergency_pause_threshold: u64,
+    pub emergency_pause_nonce: u64, // Bound into pause messages; bumped on each emergency pause
+    pub version: u8,                // Layout version; see `migrate_bridge_config`
+    pub _reserved: [u8; 128],       // Zeroed room for future fields
+}
+
+/// Per-transfer bounds for one direction. A `max_amount` of 0 means no cap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct AmountLimits {
+    pub min_amount: u64,
+    pub max_amount: u64,
+}
+
+impl AmountLimits {
+    pub fn is_valid(&self) -> bool {
+        self.max_amount == 0 || self.min_amount <= self.max_amount
+    }
+
+    pub fn check(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_amount, BridgeError::AmountBelowMinimum);
+        require!(
+            self.max_amount == 0 || amount <= self.max_amount,
+            BridgeError::AmountAboveMaximum
+        );
+        Ok(())
+    }
+}
+
+/// Shared by `initialize` and `update_config`
+pub fn validate_mirage_chain_id(mirage_chain_id: &str) -> Result<()> {
+    require!(
+        !mirage_chain_id.is_empty() && mirage_chain_id.len() <= MAX_CHAIN_ID_LEN,
+        BridgeError::InvalidChainId
+    );
+    Ok(())
+}
+
+/// Threshold in basis points; shared by `initialize` and config updates
+pub fn validate_attestation_threshold(attestation_threshold: u64) -> Result<()> {
+    require!(
+        attestation_threshold > 0 && attestation_threshold <= BASIS_POINTS_DENOMINATOR,
+        BridgeError::InvalidThreshold
+    );
+    Ok(())
+}
+
+/// Either multisig off (no signers, threshold 0) or an M-of-N set of distinct keys
+pub fn validate_admin_multisig(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        signers.len() <= MAX_ADMIN_SIGNERS
+            && (threshold as usize) <= signers.len()
+            && (threshold > 0 || signers.is_empty()),
+        BridgeError::InvalidAdminMultisig
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..i].contains(signer),
+            BridgeError::InvalidAdminMultisig
+        );
+    }
+    Ok(())
+}
+
+impl BridgeConfig {
+    pub const VERSION: u8 = 1;
+
+    /// Fails if any of `flags` is paused
+    pub fn check_not_paused(&self, flags: u8) -> Result<()> {
+        require!(self.paused_flags & flags == 0, BridgeError::BridgePaused);
+        Ok(())
+    }
+
+    pub fn is_admin_signer(&self, key: &Pubkey) -> bool {
+        self.admin_signers.contains(key)
+    }
+
+    /// Approvals from keys that are still admin signers
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|a| self.is_admin_signer(a)).count()
+    }
+
+    /// Stake needed to reach `attestation_threshold` of `total_stake`
+    pub fn required_stake(&self, total_stake: u64) -> Result<u64> {
+        stake_for_threshold(total_stake, self.attestation_threshold)
+    }
+
+    /// Stake needed to reach `emergency_pause_threshold` of `total_stake`
+    pub fn required_emergency_pause_stake(&self, total_stake: u64) -> Result<u64> {
+        stake_for_threshold(
+            total_stake,
+            self.emergency_pause_threshold.min(self.attestation_threshold),
+        )
+    }
+}
+
+/// `threshold` basis points of `total_stake`
+fn stake_for_threshold(total_stake: u64, threshold: u64) -> Result<u64> {
+    // Use u128 to avoid overflow with large stake values
+    let required = (total_stake as u128)
+        .checked_mul(threshold as u128)
+        .ok_or(BridgeError::PowerOverflow)?
+        .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+        .ok_or(BridgeError::PowerOverflow)?;
+    Ok(required as u64)
 }
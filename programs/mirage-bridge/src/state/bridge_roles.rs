@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Delegated admin roles. `bridge_config.authority` holds every role
+/// implicitly, so it can never be locked out by a revoke.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Admin,            // Grants and revokes roles, unpauses, sets claim config
+    Pauser,           // Pauses the bridge
+    ValidatorManager, // Edits the validator set
+    MetadataManager,  // Creates and updates token metadata
+    FeeManager,       // Sets limits and drop-off caps, withdraws the gas reserve
+    Compliance,       // Edits the denylist, freezes and thaws accounts
+}
+
+impl Role {
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoleMember {
+    pub member: Pubkey,
+    pub roles: u8, // Bitmask of `Role::mask`
+}
+
+impl RoleMember {
+    pub const SIZE: usize = 32 + 1;
+}
+
+#[account]
+pub struct BridgeRoles {
+    pub bump: u8,
+    pub members: Vec<RoleMember>,
+}
+
+impl BridgeRoles {
+    pub fn space(members: usize) -> usize {
+        8 + 1 + 4 + members * RoleMember::SIZE
+    }
+
+    pub fn has_role(&self, authority: &Pubkey, member: &Pubkey, role: Role) -> bool {
+        member == authority
+            || self
+                .members
+                .iter()
+                .any(|m| m.member == *member && m.roles & role.mask() != 0)
+    }
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::BridgeError;
+use crate::utils::{is_bit_set, set_bit, shift_bitmap};
+
 #[account]
 pub struct BridgeState {
     pub bump: u8,
@@ -9,9 +12,41 @@ pub struct BridgeState {
     // 0 = not processed, 1 = processed
     // Bit 0 corresponds to (last_sequence), Bit 1 to (last_sequence - 1), etc.
     pub replay_bitmap: [u128; 8], 
+    pub version: u8,             // Layout version; see `migrate_bridge_state`
+    pub _reserved: [u8; 64],     // Zeroed room for future fields
 }
 
 impl BridgeState {
-    // 8 (discriminator) + 1 (bump) + 32 (authority) + 8 (last_sequence) + 128 (replay_bitmap) = 177
-    pub const LEN: usize = 8 + 1 + 32 + 8 + 128;
+    pub const VERSION: u8 = 1;
+
+    // 8 (discriminator) + 1 (bump) + 32 (authority) + 8 (last_sequence) + 128 (replay_bitmap)
+    // + 1 (version) + 64 (reserved) = 242
+    pub const LEN: usize = 8 + 1 + 32 + 8 + 128 + 1 + 64;
+
+    /// Rejects sequences that were already minted or fell out of the window.
+    /// Any sequence above `last_sequence` is a valid new tip.
+    pub fn check_sequence(&self, sequence: u64) -> Result<()> {
+        if sequence <= self.last_sequence {
+            let diff = (self.last_sequence - sequence) as usize;
+            if diff >= 1024 {
+                return err!(BridgeError::TransactionTooOld);
+            }
+            if is_bit_set(&self.replay_bitmap, diff) {
+                return err!(BridgeError::AlreadyMinted);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn mark_minted(&mut self, sequence: u64) {
+        if sequence > self.last_sequence {
+            let diff = (sequence - self.last_sequence) as usize;
+            shift_bitmap(&mut self.replay_bitmap, diff);
+            self.last_sequence = sequence;
+            set_bit(&mut self.replay_bitmap, 0);
+        } else {
+            let diff = (self.last_sequence - sequence) as usize;
+            set_bit(&mut self.replay_bitmap, diff);
+        }
+    }
 }
@@ -1,15 +1,21 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::MAX_RECIPIENT_LEN;
+use crate::state::MirageRecipient;
 
 #[account]
 #[derive(InitSpace)]
 pub struct BurnRecord {
     pub burn_id: u64,
     pub solana_sender: Pubkey,
-    #[max_len(MAX_RECIPIENT_LEN)]
-    pub mirage_recipient: String,
+    pub mirage_recipient: MirageRecipient, // Canonical (decoded) form
     pub amount: u64,
     pub timestamp: i64,
     pub bump: u8,
+    pub caller_program: Option<Pubkey>, // Program that burned via `burn_from_program`
+    pub version: u8,                    // Layout version; see `migrate_burn_record`
+    pub _reserved: [u8; 16],            // Zeroed room for future fields
+}
+
+impl BurnRecord {
+    pub const VERSION: u8 = 1;
 }
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BURN_SHARD_BITS;
+use crate::errors::BridgeError;
+
+/// One of `BURN_SHARD_COUNT` independent burn counters. Burns pick any
+/// shard, so concurrent burns only contend when they pick the same one.
+#[account]
+#[derive(InitSpace)]
+pub struct BurnShard {
+    pub shard_id: u16,
+    pub next_nonce: u64,
+    pub unsynced_burned: u64, // Burned since the last `sync_burn_totals`
+    pub bump: u8,
+}
+
+impl BurnShard {
+    /// Globally unique: the low bits carry the shard ID
+    pub fn next_burn_id(&self) -> u64 {
+        (self.next_nonce << BURN_SHARD_BITS) | self.shard_id as u64
+    }
+
+    /// Consumes the next burn ID and adds `amount` to the unsynced total
+    pub fn issue_burn_id(&mut self, amount: u64) -> Result<u64> {
+        require!(
+            self.next_nonce < (1 << (64 - BURN_SHARD_BITS)) - 1,
+            BridgeError::NonceOverflow
+        );
+        let burn_id = self.next_burn_id();
+        self.next_nonce += 1;
+        self.unsynced_burned = self
+            .unsynced_burned
+            .checked_add(amount)
+            .ok_or(BridgeError::AmountOverflow)?;
+        Ok(burn_id)
+    }
+}
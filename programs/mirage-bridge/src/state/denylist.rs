@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::MirageRecipient;
+
+/// One shard of the compliance denylist. Entries are address hashes
+/// (see `denylist_key`) kept sorted, and the shard is chosen by the first
+/// byte of the hash, so lookups only ever touch a single small account.
+#[account]
+pub struct DenylistShard {
+    pub shard: u8,
+    pub bump: u8,
+    pub entries: Vec<[u8; 32]>,
+}
+
+impl DenylistShard {
+    /// Offset of the entry count: discriminator + shard + bump
+    pub const ENTRIES_OFFSET: usize = 8 + 1 + 1;
+
+    pub fn space(entries: usize) -> usize {
+        Self::ENTRIES_OFFSET + 4 + entries * 32
+    }
+}
+
+/// An address the compliance role can deny
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum DenylistAddress {
+    /// Solana wallet, checked as the burner and as the mint recipient
+    Solana(Pubkey),
+    /// Mirage account, checked as the burn destination
+    Mirage(MirageRecipient),
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+/// Program-owned SOL reserve that pays native drop-offs to mint recipients.
+/// Every lamport above rent exemption is available.
+#[account]
+#[derive(InitSpace)]
+pub struct GasReserve {
+    pub bump: u8,
+}
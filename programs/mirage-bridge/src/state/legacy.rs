@@ -0,0 +1,376 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DEFAULT_MAX_BURN_BATCH_SIZE, PAUSE_ALL};
+use crate::errors::BridgeError;
+use crate::state::{
+    AmountLimits, BridgeConfig, BurnRecord, MintRecord, MirageRecipient, ValidatorInfo,
+};
+use crate::utils::canonicalize_mirage_recipient;
+
+// Bounds the original accounts were allocated with. The current constants
+// have since grown, so the deployed sizes are pinned here.
+const V0_MAX_CHAIN_ID_LEN: usize = 32;
+const V0_MAX_RECIPIENT_LEN: usize = 64;
+const V0_MAX_VALIDATORS: usize = 100;
+
+/// Discriminator of the Borsh `ValidatorRegistry`, before the registry moved
+/// to a zero-copy layout with its own discriminator
+pub const VALIDATOR_REGISTRY_V0_DISCRIMINATOR: [u8; 8] = [168, 113, 195, 186, 62, 121, 163, 230];
+
+/// Validator registry as originally deployed: one Borsh vector, unsorted
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ValidatorRegistryV0 {
+    pub validators: Vec<ValidatorInfo>,
+    pub total_stake: u64,
+    pub bump: u8,
+}
+
+/// Bridge config as originally deployed
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct BridgeConfigV0 {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    #[max_len(V0_MAX_CHAIN_ID_LEN)]
+    pub mirage_chain_id: String,
+    pub attestation_threshold: u64,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub burn_nonce: u64,
+    pub paused: bool,
+    pub bump: u8,
+}
+
+impl BridgeConfigV0 {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_v0(data, BridgeConfig::DISCRIMINATOR, Self::SPACE)
+    }
+
+    /// Existing fields keep their values; everything added since takes the
+    /// same default `initialize` gives it
+    pub fn upgrade(self) -> BridgeConfig {
+        BridgeConfig {
+            authority: self.authority,
+            mint: self.mint,
+            mirage_chain_id: self.mirage_chain_id,
+            attestation_threshold: self.attestation_threshold,
+            total_minted: self.total_minted,
+            total_burned: self.total_burned,
+            burn_nonce: self.burn_nonce,
+            paused_flags: if self.paused { PAUSE_ALL } else { 0 },
+            bump: self.bump,
+            authority_valset_updates_disabled: false,
+            claim_mode: false,
+            claim_expiry: 0,
+            unclaimed_sweep_address: Pubkey::default(),
+            max_native_dropoff_lamports: 0,
+            burn_limits: AmountLimits::default(),
+            mint_limits: AmountLimits::default(),
+            max_burn_batch_size: DEFAULT_MAX_BURN_BATCH_SIZE,
+            pending_authority: None,
+            authority_transfer_unlock_at: 0,
+            admin_action_delay: 0,
+            admin_action_nonce: 0,
+            admin_threshold: 0,
+            admin_signers: Vec::new(),
+            emergency_pause_threshold: 0,
+            emergency_pause_nonce: 0,
+            version: BridgeConfig::VERSION,
+            _reserved: [0; 128],
+        }
+    }
+}
+
+/// Burn record as originally deployed, with the recipient kept as the
+/// bech32 string the user passed in
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct BurnRecordV0 {
+    pub burn_id: u64,
+    pub solana_sender: Pubkey,
+    #[max_len(V0_MAX_RECIPIENT_LEN)]
+    pub mirage_recipient: String,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl BurnRecordV0 {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_v0(data, BurnRecord::DISCRIMINATOR, Self::SPACE)
+    }
+
+    /// The recipient is decoded to its canonical form. The original burn only
+    /// checked the address prefix, so one that doesn't decode is kept as the
+    /// string that was recorded rather than leaving the record unmigratable.
+    pub fn upgrade(self) -> BurnRecord {
+        let recipient = MirageRecipient::Bech32(self.mirage_recipient);
+        BurnRecord {
+            burn_id: self.burn_id,
+            solana_sender: self.solana_sender,
+            mirage_recipient: canonicalize_mirage_recipient(&recipient).unwrap_or(recipient),
+            amount: self.amount,
+            timestamp: self.timestamp,
+            bump: self.bump,
+            caller_program: None,
+            version: BurnRecord::VERSION,
+            _reserved: [0; 16],
+        }
+    }
+}
+
+/// Mint record as originally deployed: attestations are a list of
+/// orchestrator keys, signed over a payload without the program ID, chain ID
+/// or sequence, so none of them carry over
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct MintRecordV0 {
+    pub payer: Pubkey,
+    pub burn_tx_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub amount: u64,
+    #[max_len(V0_MAX_VALIDATORS)]
+    pub attestations: Vec<Pubkey>,
+    pub attested_power: u64,
+    pub bump: u8,
+}
+
+impl MintRecordV0 {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_v0(data, MintRecord::DISCRIMINATOR, Self::SPACE)
+    }
+}
+
+/// Each account was only ever deployed at its original size or the current
+/// one, so any other size has already been migrated. The discriminator did
+/// not change between the two.
+fn decode_v0<T: AnchorDeserialize>(data: &[u8], discriminator: &[u8], space: usize) -> Result<T> {
+    require!(data.len() == space, BridgeError::AlreadyMigrated);
+    require!(
+        data.starts_with(discriminator),
+        BridgeError::InvalidLegacyLayout
+    );
+    T::deserialize(&mut &data[8..]).map_err(|_| error!(BridgeError::InvalidLegacyLayout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::encode_mirage_address;
+
+    /// The accounts exactly as the originally deployed program declared them,
+    /// with its constants written out: 32-byte chain ID, 64-byte recipient,
+    /// 100 validators
+    mod deployed {
+        use anchor_lang::prelude::*;
+
+        #[account]
+        #[derive(InitSpace)]
+        pub struct BridgeConfig {
+            pub authority: Pubkey,
+            pub mint: Pubkey,
+            #[max_len(32)]
+            pub mirage_chain_id: String,
+            pub attestation_threshold: u64,
+            pub total_minted: u64,
+            pub total_burned: u64,
+            pub burn_nonce: u64,
+            pub paused: bool,
+            pub bump: u8,
+        }
+
+        #[account]
+        #[derive(InitSpace)]
+        pub struct BurnRecord {
+            pub burn_id: u64,
+            pub solana_sender: Pubkey,
+            #[max_len(64)]
+            pub mirage_recipient: String,
+            pub amount: u64,
+            pub timestamp: i64,
+            pub bump: u8,
+        }
+
+        #[account]
+        #[derive(InitSpace)]
+        pub struct MintRecord {
+            pub payer: Pubkey,
+            pub burn_tx_hash: [u8; 32],
+            pub recipient: Pubkey,
+            pub amount: u64,
+            #[max_len(100)]
+            pub attestations: Vec<Pubkey>,
+            pub attested_power: u64,
+            pub bump: u8,
+        }
+    }
+
+    /// Serializes into an account allocated the way `init` did at the time
+    fn account_data<T: AccountSerialize>(value: &T, space: usize) -> Vec<u8> {
+        let mut data = vec![0u8; space];
+        value.try_serialize(&mut &mut data[..]).unwrap();
+        data
+    }
+
+    fn is_error<T>(result: Result<T>, expected: BridgeError) -> bool {
+        matches!(result, Err(e) if e == expected.into())
+    }
+
+    fn deployed_config(paused: bool) -> deployed::BridgeConfig {
+        deployed::BridgeConfig {
+            authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            mirage_chain_id: "mirage-1".to_string(),
+            attestation_threshold: 6667,
+            total_minted: 5_000,
+            total_burned: 2_000,
+            burn_nonce: 42,
+            paused,
+            bump: 254,
+        }
+    }
+
+    #[test]
+    fn legacy_sizes_match_deployed_allocations() {
+        assert_eq!(BridgeConfigV0::SPACE, 142);
+        assert_eq!(BurnRecordV0::SPACE, 133);
+        assert_eq!(MintRecordV0::SPACE, 3_325);
+        assert_eq!(8 + deployed::BridgeConfig::INIT_SPACE, 142);
+        assert_eq!(8 + deployed::BurnRecord::INIT_SPACE, 133);
+        assert_eq!(8 + deployed::MintRecord::INIT_SPACE, 3_325);
+    }
+
+    #[test]
+    fn upgrades_deployed_bridge_config() {
+        let deployed = deployed_config(true);
+        let data = account_data(&deployed, 8 + deployed::BridgeConfig::INIT_SPACE);
+
+        let config = BridgeConfigV0::decode(&data).unwrap().upgrade();
+        assert_eq!(config.authority, deployed.authority);
+        assert_eq!(config.mint, deployed.mint);
+        assert_eq!(config.mirage_chain_id, deployed.mirage_chain_id);
+        assert_eq!(config.attestation_threshold, deployed.attestation_threshold);
+        assert_eq!(config.total_minted, deployed.total_minted);
+        assert_eq!(config.total_burned, deployed.total_burned);
+        assert_eq!(config.burn_nonce, deployed.burn_nonce);
+        assert_eq!(config.paused_flags, PAUSE_ALL);
+        assert_eq!(config.bump, deployed.bump);
+        assert_eq!(config.max_burn_batch_size, DEFAULT_MAX_BURN_BATCH_SIZE);
+        assert_eq!(config.version, BridgeConfig::VERSION);
+
+        // Written back at the current size, it loads as a current config
+        let upgraded = account_data(&config, 8 + BridgeConfig::INIT_SPACE);
+        let reloaded = BridgeConfig::try_deserialize(&mut &upgraded[..]).unwrap();
+        assert_eq!(reloaded.authority, deployed.authority);
+        assert_eq!(reloaded.version, BridgeConfig::VERSION);
+
+        let unpaused = deployed_config(false);
+        let data = account_data(&unpaused, 8 + deployed::BridgeConfig::INIT_SPACE);
+        assert_eq!(
+            BridgeConfigV0::decode(&data)
+                .unwrap()
+                .upgrade()
+                .paused_flags,
+            0
+        );
+    }
+
+    #[test]
+    fn upgrades_deployed_burn_record() {
+        let account = [7u8; 20];
+        let deployed = deployed::BurnRecord {
+            burn_id: 9,
+            solana_sender: Pubkey::new_unique(),
+            mirage_recipient: encode_mirage_address(&account).unwrap(),
+            amount: 1_000_000,
+            timestamp: 1_700_000_000,
+            bump: 253,
+        };
+        let data = account_data(&deployed, 8 + deployed::BurnRecord::INIT_SPACE);
+
+        let record = BurnRecordV0::decode(&data).unwrap().upgrade();
+        assert_eq!(record.burn_id, deployed.burn_id);
+        assert_eq!(record.solana_sender, deployed.solana_sender);
+        assert_eq!(record.mirage_recipient, MirageRecipient::Hex(account));
+        assert_eq!(record.amount, deployed.amount);
+        assert_eq!(record.timestamp, deployed.timestamp);
+        assert_eq!(record.bump, deployed.bump);
+        assert_eq!(record.caller_program, None);
+        assert_eq!(record.version, BurnRecord::VERSION);
+
+        let upgraded = account_data(&record, 8 + BurnRecord::INIT_SPACE);
+        let reloaded = BurnRecord::try_deserialize(&mut &upgraded[..]).unwrap();
+        assert_eq!(reloaded.mirage_recipient, MirageRecipient::Hex(account));
+    }
+
+    #[test]
+    fn keeps_undecodable_burn_recipient_as_recorded() {
+        let deployed = deployed::BurnRecord {
+            burn_id: 10,
+            solana_sender: Pubkey::new_unique(),
+            mirage_recipient: "mirage1notbech32".to_string(),
+            amount: 1,
+            timestamp: 0,
+            bump: 255,
+        };
+        let data = account_data(&deployed, 8 + deployed::BurnRecord::INIT_SPACE);
+
+        let record = BurnRecordV0::decode(&data).unwrap().upgrade();
+        assert_eq!(
+            record.mirage_recipient,
+            MirageRecipient::Bech32("mirage1notbech32".to_string())
+        );
+    }
+
+    #[test]
+    fn decodes_deployed_mint_record() {
+        let deployed = deployed::MintRecord {
+            payer: Pubkey::new_unique(),
+            burn_tx_hash: [3u8; 32],
+            recipient: Pubkey::new_unique(),
+            amount: 500,
+            attestations: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            attested_power: 40,
+            bump: 252,
+        };
+        let data = account_data(&deployed, 8 + deployed::MintRecord::INIT_SPACE);
+
+        let record = MintRecordV0::decode(&data).unwrap();
+        assert_eq!(record.payer, deployed.payer);
+        assert_eq!(record.burn_tx_hash, deployed.burn_tx_hash);
+        assert_eq!(record.recipient, deployed.recipient);
+        assert_eq!(record.amount, deployed.amount);
+        assert_eq!(record.attestations, deployed.attestations);
+        assert_eq!(record.attested_power, deployed.attested_power);
+        assert_eq!(record.bump, deployed.bump);
+    }
+
+    #[test]
+    fn rejects_current_and_foreign_layouts() {
+        let config = BridgeConfigV0::decode(&data_from_config())
+            .unwrap()
+            .upgrade();
+        let current = account_data(&config, 8 + BridgeConfig::INIT_SPACE);
+        assert!(is_error(
+            BridgeConfigV0::decode(&current),
+            BridgeError::AlreadyMigrated
+        ));
+
+        let mut foreign = data_from_config();
+        foreign[..8].copy_from_slice(BurnRecord::DISCRIMINATOR);
+        assert!(is_error(
+            BridgeConfigV0::decode(&foreign),
+            BridgeError::InvalidLegacyLayout
+        ));
+    }
+
+    fn data_from_config() -> Vec<u8> {
+        account_data(
+            &deployed_config(false),
+            8 + deployed::BridgeConfig::INIT_SPACE,
+        )
+    }
+}
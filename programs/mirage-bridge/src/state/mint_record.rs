@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::MAX_VALIDATORS;
+use crate::utils::{is_bit_set, set_bit};
+
+pub const ATTESTATION_BITMAP_WORDS: usize = MAX_VALIDATORS.div_ceil(128);
 
 #[account]
 #[derive(InitSpace)]
@@ -9,14 +12,45 @@ pub struct MintRecord {
     pub burn_tx_hash: [u8; 32],
     pub recipient: Pubkey,
     pub amount: u64,
-    #[max_len(MAX_VALIDATORS)]
-    pub attestations: Vec<Pubkey>,
+    pub sequence: u64,           // Signed replay-protection sequence, checked again at finalization
+    pub native_dropoff_lamports: u64, // SOL sent to the recipient from the gas reserve on delivery
+    pub valset_nonce: u64,       // Registry epoch the bitmap positions refer to
+    pub attestation_bitmap: [u128; ATTESTATION_BITMAP_WORDS], // Bit i = registry entry i attested
     pub attested_power: u64,
+    pub claim_expires_at: i64,   // Set when finalized in claim mode; 0 otherwise
+    pub parked: bool,            // Reached quorum but the recipient is denylisted
     pub bump: u8,
+    pub version: u8,             // Layout version; no reserved bytes, since records are short-lived
 }
 
 impl MintRecord {
-    pub fn has_attested(&self, orchestrator: &Pubkey) -> bool {
-        self.attestations.contains(orchestrator)
+    pub const VERSION: u8 = 1;
+
+    /// Amount is required to be non-zero, so a zero amount means the record
+    /// was just created
+    pub fn is_initialized(&self) -> bool {
+        self.amount > 0
+    }
+
+    pub fn is_claimable(&self) -> bool {
+        self.claim_expires_at != 0
+    }
+
+    pub fn has_attested(&self, index: usize) -> bool {
+        is_bit_set(&self.attestation_bitmap, index)
+    }
+
+    pub fn record_attestation(&mut self, index: usize) {
+        set_bit(&mut self.attestation_bitmap, index);
+    }
+
+    /// Registry positions shift whenever the set changes, so attestations
+    /// collected under an earlier epoch are discarded. Stake-only updates
+    /// advance the epoch too, since `attested_power` was summed at the old
+    /// stakes.
+    pub fn reset_attestations(&mut self, valset_nonce: u64) {
+        self.valset_nonce = valset_nonce;
+        self.attestation_bitmap = [0; ATTESTATION_BITMAP_WORDS];
+        self.attested_power = 0;
     }
 }
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_RECIPIENT_LEN;
+
+/// Destination account on the Mirage chain.
+///
+/// Clients may submit any of the three forms; the program stores and emits
+/// the canonical byte form (`Hex` for 20-byte accounts, `Module` for 32-byte
+/// accounts) so the same account always appears the same way on-chain.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum MirageRecipient {
+    /// Bech32 address (`mirage1...`)
+    Bech32(#[max_len(MAX_RECIPIENT_LEN)] String),
+    /// 20-byte account address, i.e. the `0x...` EVM form
+    Hex([u8; 20]),
+    /// 32-byte module account address
+    Module([u8; 32]),
+}
+
+impl MirageRecipient {
+    /// Raw account bytes; empty for the un-decoded `Bech32` form
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            MirageRecipient::Bech32(_) => &[],
+            MirageRecipient::Hex(bytes) => bytes,
+            MirageRecipient::Module(bytes) => bytes,
+        }
+    }
+}
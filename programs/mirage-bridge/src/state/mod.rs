@@ -1,11 +1,35 @@
+pub mod admin_action;
 pub mod bridge_config;
+pub mod bridge_roles;
 pub mod burn_record;
+pub mod burn_shard;
+pub mod denylist;
+pub mod gas_reserve;
+pub mod legacy;
 pub mod bridge_state;
 pub mod mint_record;
+pub mod mirage_recipient;
+pub mod pause_signatures;
+pub mod permit_nonce;
+pub mod processed_mint;
 pub mod validator_registry;
+pub mod validator_staging;
+pub mod valset_update;
 
+pub use admin_action::*;
 pub use bridge_config::*;
+pub use bridge_roles::*;
 pub use burn_record::*;
+pub use burn_shard::*;
+pub use denylist::*;
+pub use gas_reserve::*;
+pub use legacy::*;
 pub use bridge_state::*;
 pub use mint_record::*;
+pub use mirage_recipient::*;
+pub use pause_signatures::*;
+pub use permit_nonce::*;
+pub use processed_mint::*;
 pub use validator_registry::*;
+pub use validator_staging::*;
+pub use valset_update::*;
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Validator signatures collected towards an `emergency_pause` of `flags`.
+/// Starts over whenever the pause nonce or the validator set moves on.
+#[account]
+#[derive(InitSpace)]
+pub struct PauseSignatures {
+    pub payer: Pubkey, // Created the account; gets rent back once the pause lands
+    pub flags: u8,
+    pub emergency_pause_nonce: u64,
+    pub valset_nonce: u64,
+    // Bit i is set once the validator at index i of the registry has signed
+    pub signer_bitmap: [u128; 8],
+    pub signed_power: u64,
+    pub bump: u8,
+}
+
+impl PauseSignatures {
+    pub fn reset(&mut self, emergency_pause_nonce: u64, valset_nonce: u64) {
+        self.emergency_pause_nonce = emergency_pause_nonce;
+        self.valset_nonce = valset_nonce;
+        self.signer_bitmap = [0; 8];
+        self.signed_power = 0;
+    }
+}
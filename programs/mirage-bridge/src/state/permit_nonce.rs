@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+/// Next burn-intent nonce a user can sign. Intents must be used in order,
+/// so each signed intent can be executed at most once.
+#[account]
+#[derive(InitSpace)]
+pub struct PermitNonce {
+    pub next_nonce: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Permanent marker that a burn has been minted. `MintRecord`s are closed
+/// once a transfer completes and the sequence bitmap only covers a sliding
+/// window, so this is what stops the same burn from being attested again.
+#[account]
+#[derive(InitSpace)]
+pub struct ProcessedMint {
+    pub sequence: u64,
+    pub processed_at: i64,
+    pub bump: u8,
+}
@@ -1,16 +1,43 @@
+use std::cell::{Ref, RefMut};
+use std::mem::size_of;
+
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
 
 use crate::constants::{MAX_VALIDATORS, MAX_VALIDATOR_ADDR_LEN};
+use crate::errors::BridgeError;
+
+/// First 8 bytes of sha256("account:ZeroCopyValidatorRegistry"). Differs
+/// from the Borsh registry deployed before the zero-copy layout, so that
+/// account is never reinterpreted as a header and entries; it has to be
+/// converted by `migrate_validator_registry` first.
+pub const VALIDATOR_REGISTRY_DISCRIMINATOR: [u8; 8] = [120, 48, 105, 208, 177, 228, 45, 142];
 
-#[account]
-#[derive(InitSpace)]
+/// Registry header. The account data continues with `capacity` fixed-size
+/// `ValidatorEntry` slots, the first `len` of which are in use and kept
+/// sorted by `orchestrator_pubkey` so lookups can binary search.
+#[account(zero_copy, discriminator = &VALIDATOR_REGISTRY_DISCRIMINATOR)]
 pub struct ValidatorRegistry {
-    #[max_len(MAX_VALIDATORS)]
-    pub validators: Vec<ValidatorInfo>,
     pub total_stake: u64,
+    pub valset_nonce: u64, // Incremented on every change to the set
+    pub capacity: u32,
+    pub len: u32,
     pub bump: u8,
+    pub version: u8, // Layout version; see `migrate_validator_registry`
+    pub _padding: [u8; 6],
+    pub _reserved: [u8; 32], // Zeroed room for future header fields
+}
+
+#[zero_copy]
+pub struct ValidatorEntry {
+    pub orchestrator_pubkey: Pubkey,
+    pub stake: u64,
+    pub mirage_validator: [u8; MAX_VALIDATOR_ADDR_LEN],
+    pub mirage_validator_len: u8,
+    pub _padding: [u8; 3],
 }
 
+/// Instruction-facing form of a registry entry
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
 pub struct ValidatorInfo {
     pub orchestrator_pubkey: Pubkey,
@@ -19,11 +46,204 @@ pub struct ValidatorInfo {
     pub stake: u64,
 }
 
+impl ValidatorEntry {
+    pub fn from_info(info: &ValidatorInfo) -> Self {
+        let mut mirage_validator = [0u8; MAX_VALIDATOR_ADDR_LEN];
+        let address = info.mirage_validator.as_bytes();
+        mirage_validator[..address.len()].copy_from_slice(address);
+
+        Self {
+            orchestrator_pubkey: info.orchestrator_pubkey,
+            stake: info.stake,
+            mirage_validator,
+            mirage_validator_len: address.len() as u8,
+            _padding: [0; 3],
+        }
+    }
+
+    pub fn to_info(&self) -> ValidatorInfo {
+        let address = &self.mirage_validator[..self.mirage_validator_len as usize];
+        ValidatorInfo {
+            orchestrator_pubkey: self.orchestrator_pubkey,
+            mirage_validator: String::from_utf8_lossy(address).into_owned(),
+            stake: self.stake,
+        }
+    }
+}
+
 impl ValidatorRegistry {
-    pub fn get_validator_stake(&self, orchestrator: &Pubkey) -> Option<u64> {
-        self.validators
+    pub const VERSION: u8 = 1;
+    pub const HEADER_SPACE: usize = 8 + size_of::<ValidatorRegistry>();
+
+    /// Account size for a registry holding up to `capacity` validators
+    pub fn space(capacity: usize) -> usize {
+        Self::HEADER_SPACE + capacity * size_of::<ValidatorEntry>()
+    }
+
+    /// Borrows the header and the in-use entries
+    pub fn load<'a>(
+        loader: &'a AccountLoader<'_, ValidatorRegistry>,
+    ) -> Result<(Ref<'a, ValidatorRegistry>, Ref<'a, [ValidatorEntry]>)> {
+        // Checks the discriminator and layout before the raw slice is reinterpreted
+        require!(
+            loader.load()?.version == Self::VERSION,
+            BridgeError::AccountNotMigrated
+        );
+
+        let info: &AccountInfo = loader.as_ref();
+        let data = info.try_borrow_data()?;
+        let (header, entries) = Ref::map_split(data, |data| data.split_at(Self::HEADER_SPACE));
+        let header = Ref::map(header, |h| bytemuck::from_bytes::<ValidatorRegistry>(&h[8..]));
+        let len = header.len as usize;
+        let entries = Ref::map(entries, |e| {
+            bytemuck::cast_slice::<u8, ValidatorEntry>(&e[..len * size_of::<ValidatorEntry>()])
+        });
+        Ok((header, entries))
+    }
+
+    /// Mutably borrows the header and every entry slot (`capacity` of them)
+    pub fn load_mut<'a>(
+        loader: &'a AccountLoader<'_, ValidatorRegistry>,
+    ) -> Result<(RefMut<'a, ValidatorRegistry>, RefMut<'a, [ValidatorEntry]>)> {
+        require!(
+            loader.load_mut()?.version == Self::VERSION,
+            BridgeError::AccountNotMigrated
+        );
+
+        let info: &AccountInfo = loader.as_ref();
+        let data = info.try_borrow_mut_data()?;
+        let (header, entries) =
+            RefMut::map_split(data, |data| data.split_at_mut(Self::HEADER_SPACE));
+        let header = RefMut::map(header, |h| {
+            bytemuck::from_bytes_mut::<ValidatorRegistry>(&mut h[8..])
+        });
+        let capacity = header.capacity as usize;
+        let entries = RefMut::map(entries, |e| {
+            bytemuck::cast_slice_mut::<u8, ValidatorEntry>(
+                &mut e[..capacity * size_of::<ValidatorEntry>()],
+            )
+        });
+        Ok((header, entries))
+    }
+
+    /// Marks the set as changed, invalidating signatures over the previous set
+    pub fn advance_valset_nonce(&mut self) -> Result<()> {
+        self.valset_nonce = self
+            .valset_nonce
+            .checked_add(1)
+            .ok_or(BridgeError::NonceOverflow)?;
+        Ok(())
+    }
+
+    /// Recomputes `total_stake` from the in-use entries
+    pub fn recompute_total_stake(&mut self, entries: &[ValidatorEntry]) -> Result<()> {
+        self.total_stake = entries[..self.len as usize]
             .iter()
-            .find(|v| v.orchestrator_pubkey == *orchestrator)
             .map(|v| v.stake)
+            .try_fold(0u64, |acc, stake| acc.checked_add(stake))
+            .ok_or(BridgeError::PowerOverflow)?;
+        Ok(())
     }
+
+    /// Inserts a validator, keeping entries sorted by orchestrator key
+    pub fn insert(&mut self, entries: &mut [ValidatorEntry], validator: &ValidatorInfo) -> Result<()> {
+        validate_validator_info(validator)?;
+
+        let len = self.len as usize;
+        let index = match find_validator_index(&entries[..len], &validator.orchestrator_pubkey) {
+            Some(_) => return err!(BridgeError::DuplicateValidator),
+            None => entries[..len]
+                .partition_point(|v| v.orchestrator_pubkey < validator.orchestrator_pubkey),
+        };
+        require!(len < entries.len(), BridgeError::RegistryFull);
+
+        entries.copy_within(index..len, index + 1);
+        entries[index] = ValidatorEntry::from_info(validator);
+        self.len += 1;
+        self.recompute_total_stake(entries)
+    }
+
+    /// Removes a validator, keeping entries sorted
+    pub fn remove(&mut self, entries: &mut [ValidatorEntry], orchestrator: &Pubkey) -> Result<()> {
+        let len = self.len as usize;
+        let index = find_validator_index(&entries[..len], orchestrator)
+            .ok_or(BridgeError::ValidatorNotFound)?;
+
+        entries.copy_within(index + 1..len, index);
+        entries[len - 1] = bytemuck::Zeroable::zeroed();
+        self.len -= 1;
+        self.recompute_total_stake(entries)
+    }
+
+    /// Replaces the whole set; `validators` must already have passed
+    /// `validate_validator_set`
+    pub fn replace(&mut self, entries: &mut [ValidatorEntry], validators: &[ValidatorInfo]) -> Result<()> {
+        require!(validators.len() <= entries.len(), BridgeError::RegistryFull);
+
+        let mut sorted: Vec<ValidatorEntry> = validators.iter().map(ValidatorEntry::from_info).collect();
+        sorted.sort_unstable_by(|a, b| a.orchestrator_pubkey.cmp(&b.orchestrator_pubkey));
+
+        entries[..sorted.len()].copy_from_slice(&sorted);
+        for entry in entries.iter_mut().take(self.len as usize).skip(sorted.len()) {
+            *entry = bytemuck::Zeroable::zeroed();
+        }
+        self.len = sorted.len() as u32;
+        self.recompute_total_stake(entries)
+    }
+}
+
+/// Binary search over sorted in-use entries
+pub fn find_validator_index(entries: &[ValidatorEntry], orchestrator: &Pubkey) -> Option<usize> {
+    entries
+        .binary_search_by(|v| v.orchestrator_pubkey.cmp(orchestrator))
+        .ok()
+}
+
+pub fn get_validator_stake(entries: &[ValidatorEntry], orchestrator: &Pubkey) -> Option<u64> {
+    find_validator_index(entries, orchestrator).map(|i| entries[i].stake)
+}
+
+/// Checks a single registry entry (non-zero stake, address fits the account)
+pub fn validate_validator_info(validator: &ValidatorInfo) -> Result<()> {
+    require!(validator.stake > 0, BridgeError::ZeroStake);
+    require!(
+        !validator.mirage_validator.is_empty()
+            && validator.mirage_validator.len() <= MAX_VALIDATOR_ADDR_LEN,
+        BridgeError::InvalidMirageValidator
+    );
+    Ok(())
+}
+
+/// Validates a complete validator set and returns its total stake
+pub fn validate_validator_set(validators: &[ValidatorInfo]) -> Result<u64> {
+    require!(!validators.is_empty(), BridgeError::EmptyValidatorSet);
+    require!(
+        validators.len() <= MAX_VALIDATORS,
+        BridgeError::TooManyValidators
+    );
+
+    for (i, validator) in validators.iter().enumerate() {
+        validate_validator_info(validator)?;
+        require!(
+            !validators[..i]
+                .iter()
+                .any(|v| v.orchestrator_pubkey == validator.orchestrator_pubkey),
+            BridgeError::DuplicateValidator
+        );
+    }
+
+    validators
+        .iter()
+        .map(|v| v.stake)
+        .try_fold(0u64, |acc, stake| acc.checked_add(stake))
+        .ok_or(error!(BridgeError::PowerOverflow))
+}
+
+/// SHA-256 over the Borsh encoding of the set, as signed by validators
+pub fn hash_validator_set(validators: &[ValidatorInfo]) -> Result<[u8; 32]> {
+    let mut data = Vec::new();
+    validators
+        .serialize(&mut data)
+        .map_err(|_| BridgeError::InvalidValidatorSet)?;
+    Ok(hash(&data).to_bytes())
 }
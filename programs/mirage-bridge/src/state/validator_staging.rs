@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+
+use crate::state::ValidatorInfo;
+
+/// Validator set being uploaded in chunks by `stage_validators`,
+/// swapped into the registry by `commit_validator_set`.
+/// The account is resized to fit as chunks are appended.
+#[account]
+pub struct ValidatorStaging {
+    pub validators: Vec<ValidatorInfo>,
+    pub bump: u8,
+}
+
+impl ValidatorStaging {
+    /// Account size when holding `count` validators
+    pub fn space(count: usize) -> usize {
+        8 + 4 + count * ValidatorInfo::INIT_SPACE + 1
+    }
+
+    /// sha256 of the Borsh-encoded staged set. A queued `CommitValidatorSet`
+    /// carries this, so the buffer can't be swapped after it is approved.
+    pub fn set_hash(&self) -> [u8; 32] {
+        hash(&self.validators.try_to_vec().unwrap_or_default()).to_bytes()
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ValidatorInfo;
+
+/// Pending validator set rotation, accumulating signatures from the current
+/// set until `signed_power` reaches the attestation threshold.
+/// The account is resized to fit as chunks of the new set are uploaded.
+#[account]
+pub struct ValsetUpdate {
+    pub payer: Pubkey,           // Proposer; uploads the new set and gets rent back
+    pub valset_nonce: u64,       // Nonce the registry takes once rotated
+    pub valset_hash: [u8; 32],   // hash_validator_set() of the new set
+    // Bit i is set once the validator at index i of the current registry has signed
+    pub signer_bitmap: [u128; 8],
+    pub signed_power: u64,
+    pub bump: u8,
+    pub validators: Vec<ValidatorInfo>,
+}
+
+impl ValsetUpdate {
+    /// Account size when holding `count` staged validators
+    pub fn space(count: usize) -> usize {
+        8 + 32 + 8 + 32 + 128 + 8 + 1 + 4 + count * ValidatorInfo::INIT_SPACE
+    }
+}
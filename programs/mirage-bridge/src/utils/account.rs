@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::BridgeError;
+
+/// Resizes a program-owned account, topping up rent from `payer` when it
+/// grows and refunding the excess to `payer` when it shrinks. New bytes are
+/// zeroed. Growth is limited by the runtime to 10,240 bytes per instruction.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_space: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(new_space);
+    let current_lamports = account.lamports();
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    } else if current_lamports > required_lamports {
+        let excess = current_lamports - required_lamports;
+        **account.try_borrow_mut_lamports()? = required_lamports;
+        let payer_lamports = payer.lamports();
+        **payer.try_borrow_mut_lamports()? = payer_lamports
+            .checked_add(excess)
+            .ok_or(BridgeError::AmountOverflow)?;
+    }
+
+    account.resize(new_space)?;
+    Ok(())
+}
+
+/// Grows an `#[account]` written under an older layout to `new_space`, with
+/// `payer` funding the extra rent, and decodes it. Fields appended since that
+/// layout read as zero.
+pub fn load_legacy_account<'info, T: AccountDeserialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_space: usize,
+) -> Result<T> {
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+    if account.data_len() < new_space {
+        resize_account(account, payer, system_program, new_space)?;
+    }
+    let data = account.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Writes a migrated `#[account]` back over the account's data, clearing
+/// whatever the old layout left past the end of the new one
+pub fn store_account<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    value.try_serialize(&mut &mut data[..])
+}
+
+/// Closes a program-owned account that can't be loaded as an `Account`,
+/// sending its lamports to `destination`
+pub fn close_program_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    transfer_lamports(account, destination, account.lamports())?;
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+    Ok(())
+}
+
+/// Lamports a program-owned account holds above its rent-exempt minimum
+pub fn spare_lamports(account: &AccountInfo) -> Result<u64> {
+    let minimum = Rent::get()?.minimum_balance(account.data_len());
+    Ok(account.lamports().saturating_sub(minimum))
+}
+
+/// Moves lamports out of a program-owned account without a system CPI
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> Result<()> {
+    let from_lamports = from.lamports();
+    **from.try_borrow_mut_lamports()? = from_lamports
+        .checked_sub(lamports)
+        .ok_or(BridgeError::InsufficientGasReserve)?;
+    let to_lamports = to.lamports();
+    **to.try_borrow_mut_lamports()? = to_lamports
+        .checked_add(lamports)
+        .ok_or(BridgeError::AmountOverflow)?;
+    Ok(())
+}
+
+/// Creates a program-owned PDA with `space` bytes, funded by `payer`.
+/// Handles accounts that were pre-funded with lamports by someone else.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            required_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
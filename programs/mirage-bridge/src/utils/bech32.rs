@@ -1,14 +1,28 @@
 use crate::errors::BridgeError;
 use anchor_lang::prelude::*;
-use bech32::Hrp;
+use bech32::{Bech32, Hrp};
+
+pub const MIRAGE_HRP: &str = "mirage";
 
 pub fn validate_mirage_address(address: &str) -> Result<()> {
-    let hrp = Hrp::parse("mirage").map_err(|_| BridgeError::InvalidMirageRecipient)?;
+    decode_mirage_address(address).map(|_| ())
+}
+
+/// Decodes a `mirage1...` address into its raw account bytes
+pub fn decode_mirage_address(address: &str) -> Result<Vec<u8>> {
+    let hrp = Hrp::parse(MIRAGE_HRP).map_err(|_| BridgeError::InvalidMirageRecipient)?;
 
-    let (parsed_hrp, _data) =
+    let (parsed_hrp, data) =
         bech32::decode(address).map_err(|_| BridgeError::InvalidMirageRecipient)?;
 
     require!(parsed_hrp == hrp, BridgeError::InvalidMirageRecipient);
 
-    Ok(())
+    Ok(data)
+}
+
+/// Encodes raw account bytes as a `mirage1...` address
+pub fn encode_mirage_address(data: &[u8]) -> Result<String> {
+    let hrp = Hrp::parse(MIRAGE_HRP).map_err(|_| BridgeError::InvalidMirageRecipient)?;
+
+    bech32::encode::<Bech32>(hrp, data).map_err(|_| error!(BridgeError::InvalidMirageRecipient))
 }
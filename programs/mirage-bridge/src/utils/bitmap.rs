@@ -1,18 +1,18 @@
 /// Checks if a bit is set in a u128 array bitmap
-pub fn is_bit_set(bitmap: &[u128; 8], index: usize) -> bool {
+pub fn is_bit_set(bitmap: &[u128], index: usize) -> bool {
     let array_idx = index / 128;
     let bit_idx = index % 128;
-    if array_idx >= 8 {
+    if array_idx >= bitmap.len() {
         return false;
     }
     (bitmap[array_idx] & (1u128 << bit_idx)) != 0
 }
 
 /// Sets a bit in a u128 array bitmap
-pub fn set_bit(bitmap: &mut [u128; 8], index: usize) {
+pub fn set_bit(bitmap: &mut [u128], index: usize) {
     let array_idx = index / 128;
     let bit_idx = index % 128;
-    if array_idx < 8 {
+    if array_idx < bitmap.len() {
         bitmap[array_idx] |= 1u128 << bit_idx;
     }
 }
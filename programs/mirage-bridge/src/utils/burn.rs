@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::PAUSE_BURNS;
+use crate::errors::BridgeError;
+use crate::events::BurnInitiated;
+use crate::state::{BridgeConfig, BurnRecord, BurnShard, MirageRecipient};
+use crate::utils::{canonicalize_mirage_recipient, is_denylisted, mirage_denylist_key, solana_denylist_key};
+
+/// Requires `signer` to be the token account's owner or an approved delegate
+/// for at least `amount`
+pub fn check_burn_authority(token_account: &TokenAccount, signer: &Pubkey, amount: u64) -> Result<()> {
+    require!(
+        *signer == token_account.owner
+            || (token_account.delegate == Some(*signer).into()
+                && token_account.delegated_amount >= amount),
+        BridgeError::InvalidBurnAuthority
+    );
+    Ok(())
+}
+
+/// Rejects a denylisted signer acting for the token owner, such as a
+/// delegate or a permit relayer. `validate_burn` only checks the owner.
+pub fn check_burn_signer(signer: &Pubkey, signer_denylist_shard: &AccountInfo) -> Result<()> {
+    require!(
+        !is_denylisted(signer_denylist_shard, &solana_denylist_key(signer))?,
+        BridgeError::AddressDenylisted
+    );
+    Ok(())
+}
+
+/// Checks shared by every burn path. Returns the canonical recipient.
+pub fn validate_burn(
+    bridge_config: &BridgeConfig,
+    solana_sender: &Pubkey,
+    mirage_recipient: &MirageRecipient,
+    amount: u64,
+    sender_denylist_shard: &AccountInfo,
+    recipient_denylist_shard: &AccountInfo,
+) -> Result<MirageRecipient> {
+    bridge_config.check_not_paused(PAUSE_BURNS)?;
+    require!(amount > 0, BridgeError::InvalidAmount);
+    bridge_config.burn_limits.check(amount)?;
+
+    let mirage_recipient = canonicalize_mirage_recipient(mirage_recipient)?;
+
+    require!(
+        !is_denylisted(sender_denylist_shard, &solana_denylist_key(solana_sender))?,
+        BridgeError::AddressDenylisted
+    );
+    require!(
+        !is_denylisted(
+            recipient_denylist_shard,
+            &mirage_denylist_key(mirage_recipient.as_bytes())
+        )?,
+        BridgeError::AddressDenylisted
+    );
+
+    Ok(mirage_recipient)
+}
+
+/// Issues the next burn ID from `burn_shard`, fills in the burn record and
+/// emits `BurnInitiated`. Call after the tokens have been burned.
+/// `caller_program` is set for burns made by another program via CPI.
+pub fn record_burn(
+    burn_shard: &mut BurnShard,
+    burn_record: &mut BurnRecord,
+    bump: u8,
+    solana_sender: Pubkey,
+    mirage_recipient: MirageRecipient,
+    amount: u64,
+    caller_program: Option<Pubkey>,
+) -> Result<()> {
+    let burn_id = burn_shard.issue_burn_id(amount)?;
+
+    let clock = Clock::get()?;
+    burn_record.burn_id = burn_id;
+    burn_record.solana_sender = solana_sender;
+    burn_record.mirage_recipient = mirage_recipient.clone();
+    burn_record.amount = amount;
+    burn_record.timestamp = clock.unix_timestamp;
+    burn_record.bump = bump;
+    burn_record.caller_program = caller_program;
+    burn_record.version = BurnRecord::VERSION;
+
+    emit!(BurnInitiated {
+        burn_id,
+        solana_sender,
+        mirage_recipient,
+        amount,
+        timestamp: clock.unix_timestamp,
+        caller_program,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use solana_sha256_hasher::hashv;
+
+use crate::errors::BridgeError;
+use crate::state::{DenylistAddress, DenylistShard};
+use crate::utils::canonicalize_mirage_recipient;
+
+/// Hash of a Solana address as stored in the denylist
+pub fn solana_denylist_key(address: &Pubkey) -> [u8; 32] {
+    hashv(&[b"solana", address.as_ref()]).to_bytes()
+}
+
+/// Hash of a canonical Mirage recipient as stored in the denylist
+pub fn mirage_denylist_key(canonical_bytes: &[u8]) -> [u8; 32] {
+    hashv(&[b"mirage", canonical_bytes]).to_bytes()
+}
+
+/// Denylist key for any address form. Mirage recipients are canonicalized
+/// first so every encoding of an account maps to the same entry.
+pub fn denylist_key(address: &DenylistAddress) -> Result<[u8; 32]> {
+    match address {
+        DenylistAddress::Solana(pubkey) => Ok(solana_denylist_key(pubkey)),
+        DenylistAddress::Mirage(recipient) => {
+            let canonical = canonicalize_mirage_recipient(recipient)?;
+            Ok(mirage_denylist_key(canonical.as_bytes()))
+        }
+    }
+}
+
+/// Looks `key` up in the shard that must hold it. The shard is checked to be
+/// the right PDA; one that has never been created is an empty shard.
+pub fn is_denylisted(shard: &AccountInfo, key: &[u8; 32]) -> Result<bool> {
+    let (expected, _) = Pubkey::find_program_address(&[b"denylist", &[key[0]]], &crate::ID);
+    require_keys_eq!(shard.key(), expected, BridgeError::InvalidDenylistShard);
+
+    if shard.owner != &crate::ID || shard.data_is_empty() {
+        return Ok(false);
+    }
+
+    // Binary search the raw entries rather than deserializing the whole shard
+    let data = shard.try_borrow_data()?;
+    require!(
+        data.len() >= DenylistShard::ENTRIES_OFFSET + 4
+            && data[..8] == *DenylistShard::DISCRIMINATOR,
+        BridgeError::InvalidDenylistShard
+    );
+    let offset = DenylistShard::ENTRIES_OFFSET;
+    let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    let entries = data
+        .get(offset + 4..offset + 4 + len * 32)
+        .ok_or(BridgeError::InvalidDenylistShard)?;
+
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = (low + high) / 2;
+        match entries[mid * 32..(mid + 1) * 32].cmp(&key[..]) {
+            std::cmp::Ordering::Equal => return Ok(true),
+            std::cmp::Ordering::Less => low = mid + 1,
+            std::cmp::Ordering::Greater => high = mid,
+        }
+    }
+
+    Ok(false)
+}
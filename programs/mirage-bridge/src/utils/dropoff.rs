@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BridgeError;
+use crate::events::NativeDropoff;
+use crate::state::{GasReserve, MintRecord};
+use crate::utils::{spare_lamports, transfer_lamports};
+
+/// Pays the record's native drop-off from the gas reserve. Fails while the
+/// reserve cannot cover it, so the record stays open until the reserve is
+/// funded and delivery is retried. The drop-off is skipped only if it would
+/// leave a fresh recipient below rent exemption.
+pub fn pay_native_dropoff<'info>(
+    gas_reserve: Option<&Account<'info, GasReserve>>,
+    recipient: &AccountInfo<'info>,
+    mint_record: &MintRecord,
+) -> Result<()> {
+    let lamports = mint_record.native_dropoff_lamports;
+    if lamports == 0 {
+        return Ok(());
+    }
+
+    let gas_reserve = gas_reserve
+        .ok_or(BridgeError::MissingGasReserve)?
+        .to_account_info();
+    require!(
+        spare_lamports(&gas_reserve)? >= lamports,
+        BridgeError::InsufficientGasReserve
+    );
+
+    let recipient_minimum = Rent::get()?.minimum_balance(recipient.data_len());
+    let paid = recipient.lamports().saturating_add(lamports) >= recipient_minimum;
+
+    if paid {
+        transfer_lamports(&gas_reserve, recipient, lamports)?;
+    }
+
+    let clock = Clock::get()?;
+    emit!(NativeDropoff {
+        burn_tx_hash: mint_record.burn_tx_hash,
+        recipient: mint_record.recipient,
+        lamports,
+        paid,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
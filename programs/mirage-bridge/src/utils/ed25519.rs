@@ -1,87 +1,177 @@
 use crate::errors::BridgeError;
+use crate::instructions::AttestParams;
+use crate::state::MirageRecipient;
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::sysvar::instructions::{
-    load_current_index_checked, load_instruction_at_checked,
-};
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
 
 pub const ED25519_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
     0x03, 0x7d, 0x46, 0xd6, 0x7c, 0x93, 0xfb, 0xbe, 0x12, 0xf9, 0x42, 0x8f, 0x83, 0x8d, 0x40, 0xff,
     0x05, 0x70, 0x74, 0x49, 0x27, 0xf4, 0x8a, 0x64, 0xfc, 0xca, 0x70, 0x44, 0x80, 0x00, 0x00, 0x00,
 ]);
 
+/// Domain tag prefixed to mint attestation payloads
+pub const MINT_ATTESTATION_TAG: &[u8] = b"mirage-bridge:mint-attestation";
+
+/// Payload orchestrators sign to approve an inbound transfer. Binding the
+/// program and chain ID keeps signatures from being replayed against another
+/// deployment, and binding the sequence keeps relayers from choosing it.
 pub fn build_attestation_payload(
-    burn_tx_hash: &[u8; 32],
-    mirage_sender: &str,
-    amount: u64,
+    program_id: &Pubkey,
+    mirage_chain_id: &str,
     recipient: &Pubkey,
-    destination_chain: &str,
+    params: &AttestParams,
 ) -> Vec<u8> {
     let mut payload = Vec::new();
-    payload.extend_from_slice(burn_tx_hash);
-    payload.extend_from_slice(&(mirage_sender.len() as u32).to_le_bytes());
-    payload.extend_from_slice(mirage_sender.as_bytes());
-    payload.extend_from_slice(&amount.to_le_bytes());
+    payload.extend_from_slice(MINT_ATTESTATION_TAG);
+    payload.extend_from_slice(&program_id.to_bytes());
+    payload.extend_from_slice(&(mirage_chain_id.len() as u32).to_le_bytes());
+    payload.extend_from_slice(mirage_chain_id.as_bytes());
+    payload.extend_from_slice(&params.burn_tx_hash);
+    payload.extend_from_slice(&(params.mirage_sender.len() as u32).to_le_bytes());
+    payload.extend_from_slice(params.mirage_sender.as_bytes());
+    payload.extend_from_slice(&params.amount.to_le_bytes());
     payload.extend_from_slice(&recipient.to_bytes());
-    // Bind destination chain to prevent cross-chain replay attacks
-    payload.extend_from_slice(&(destination_chain.len() as u32).to_le_bytes());
-    payload.extend_from_slice(destination_chain.as_bytes());
+    payload.extend_from_slice(&params.sequence.to_le_bytes());
+    payload.extend_from_slice(&params.native_dropoff_lamports.to_le_bytes());
+    payload
+}
+
+/// Domain tag prefixed to validator set update payloads so they can never
+/// be confused with mint attestations
+pub const VALSET_UPDATE_TAG: &[u8] = b"mirage-bridge:valset-update";
+
+pub fn build_valset_update_payload(
+    program_id: &Pubkey,
+    mirage_chain_id: &str,
+    valset_nonce: u64,
+    valset_hash: &[u8; 32],
+) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(VALSET_UPDATE_TAG);
+    payload.extend_from_slice(&program_id.to_bytes());
+    payload.extend_from_slice(&(mirage_chain_id.len() as u32).to_le_bytes());
+    payload.extend_from_slice(mirage_chain_id.as_bytes());
+    payload.extend_from_slice(&valset_nonce.to_le_bytes());
+    payload.extend_from_slice(valset_hash);
+    payload
+}
+
+/// Domain tag prefixed to user-signed burn intents
+pub const BURN_INTENT_TAG: &[u8] = b"mirage-bridge:burn-intent";
+
+/// Payload a user signs to authorize `burn_with_permit`. The recipient must
+/// be in canonical form. The chain ID keeps an intent signed for one Mirage
+/// network from being used on a deployment bridging to another.
+pub fn build_burn_intent_payload(
+    program_id: &Pubkey,
+    mirage_chain_id: &str,
+    user: &Pubkey,
+    mirage_recipient: &MirageRecipient,
+    amount: u64,
+    nonce: u64,
+    expiry: i64,
+) -> Vec<u8> {
+    let recipient = mirage_recipient.as_bytes();
+    let mut payload = Vec::new();
+    payload.extend_from_slice(BURN_INTENT_TAG);
+    payload.extend_from_slice(&program_id.to_bytes());
+    payload.extend_from_slice(&(mirage_chain_id.len() as u32).to_le_bytes());
+    payload.extend_from_slice(mirage_chain_id.as_bytes());
+    payload.extend_from_slice(&user.to_bytes());
+    payload.extend_from_slice(&(recipient.len() as u32).to_le_bytes());
+    payload.extend_from_slice(recipient);
+    payload.extend_from_slice(&amount.to_le_bytes());
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload.extend_from_slice(&expiry.to_le_bytes());
     payload
 }
 
-pub fn verify_ed25519_signature(
+/// Domain tag prefixed to validator-signed emergency pause messages
+pub const EMERGENCY_PAUSE_TAG: &[u8] = b"mirage-bridge:emergency-pause";
+
+/// Payload validators sign to pause `flags` via `emergency_pause`. Bound to
+/// `emergency_pause_nonce`, so a used message cannot re-pause after an unpause.
+pub fn build_emergency_pause_payload(
+    program_id: &Pubkey,
+    mirage_chain_id: &str,
+    emergency_pause_nonce: u64,
+    flags: u8,
+) -> Vec<u8> {
+    let mut payload = Vec::new();
+    payload.extend_from_slice(EMERGENCY_PAUSE_TAG);
+    payload.extend_from_slice(&program_id.to_bytes());
+    payload.extend_from_slice(&(mirage_chain_id.len() as u32).to_le_bytes());
+    payload.extend_from_slice(mirage_chain_id.as_bytes());
+    payload.extend_from_slice(&emergency_pause_nonce.to_le_bytes());
+    payload.push(flags);
+    payload
+}
+
+// Size of one Ed25519SignatureOffsets entry in the precompile instruction data
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Returns the public keys of every Ed25519 precompile signature in the
+/// current transaction whose message equals `expected_message`.
+///
+/// The precompile has already verified each signature before the program
+/// runs, so only the pubkey and message need to be read back. Entries that
+/// reference data in other instructions are ignored.
+pub fn load_ed25519_signers(
     instructions_sysvar: &AccountInfo,
-    orchestrator: &Pubkey,
     expected_message: &[u8],
-) -> Result<()> {
-    let current_idx = load_current_index_checked(instructions_sysvar)
-        .map_err(|_| BridgeError::InvalidSignatureInstruction)?;
-
-    require!(current_idx > 0, BridgeError::InvalidSignatureInstruction);
-
-    let ed25519_ix = load_instruction_at_checked(current_idx as usize - 1, instructions_sysvar)
-        .map_err(|_| BridgeError::InvalidSignatureInstruction)?;
-
-    require!(
-        ed25519_ix.program_id == ED25519_PROGRAM_ID,
-        BridgeError::InvalidSignatureInstruction
-    );
-
-    let data = &ed25519_ix.data;
-    require!(data.len() >= 2, BridgeError::InvalidSignatureInstruction);
-
-    let num_signatures = data[0];
-    require!(
-        num_signatures == 1,
-        BridgeError::InvalidSignatureInstruction
-    );
-
-    require!(data.len() >= 16, BridgeError::InvalidSignatureInstruction);
-
-    let pubkey_offset = u16::from_le_bytes([data[6], data[7]]) as usize;
-    let message_offset = u16::from_le_bytes([data[10], data[11]]) as usize;
-    let message_size = u16::from_le_bytes([data[12], data[13]]) as usize;
-
-    require!(
-        data.len() >= pubkey_offset + 32,
-        BridgeError::InvalidSignatureInstruction
-    );
-    require!(
-        data.len() >= message_offset + message_size,
-        BridgeError::InvalidSignatureInstruction
-    );
-
-    let pubkey_bytes = &data[pubkey_offset..pubkey_offset + 32];
-    let message_bytes = &data[message_offset..message_offset + message_size];
-
-    require!(
-        pubkey_bytes == orchestrator.to_bytes().as_ref(),
-        BridgeError::SignaturePubkeyMismatch
-    );
-
-    require!(
-        message_bytes == expected_message,
-        BridgeError::SignatureMessageMismatch
-    );
-
-    Ok(())
+) -> Result<Vec<Pubkey>> {
+    let mut signers = Vec::new();
+    let mut index = 0;
+
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        index += 1;
+        if ix.program_id != ED25519_PROGRAM_ID {
+            continue;
+        }
+
+        let data = &ix.data;
+        require!(!data.is_empty(), BridgeError::InvalidSignatureInstruction);
+        let num_signatures = data[0] as usize;
+
+        for i in 0..num_signatures {
+            let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_LEN;
+            require!(
+                data.len() >= start + SIGNATURE_OFFSETS_LEN,
+                BridgeError::InvalidSignatureInstruction
+            );
+            let read_u16 = |at: usize| u16::from_le_bytes([data[start + at], data[start + at + 1]]);
+
+            let signature_ix_index = read_u16(2);
+            let pubkey_offset = read_u16(4) as usize;
+            let pubkey_ix_index = read_u16(6);
+            let message_offset = read_u16(8) as usize;
+            let message_size = read_u16(10) as usize;
+            let message_ix_index = read_u16(12);
+
+            if signature_ix_index != u16::MAX
+                || pubkey_ix_index != u16::MAX
+                || message_ix_index != u16::MAX
+            {
+                continue;
+            }
+
+            require!(
+                data.len() >= pubkey_offset + 32 && data.len() >= message_offset + message_size,
+                BridgeError::InvalidSignatureInstruction
+            );
+
+            if &data[message_offset..message_offset + message_size] != expected_message {
+                continue;
+            }
+
+            let pubkey = Pubkey::try_from(&data[pubkey_offset..pubkey_offset + 32])
+                .map_err(|_| BridgeError::InvalidSignatureInstruction)?;
+            if !signers.contains(&pubkey) {
+                signers.push(pubkey);
+            }
+        }
+    }
+
+    Ok(signers)
 }
@@ -0,0 +1,153 @@
+use crate::errors::BridgeError;
+use crate::state::MirageRecipient;
+use crate::utils::{decode_mirage_address, encode_mirage_address};
+use anchor_lang::prelude::*;
+
+/// Converts any accepted recipient form into its canonical byte form.
+/// Bech32 addresses are decoded to `Hex` (20 bytes) or `Module` (32 bytes).
+pub fn canonicalize_mirage_recipient(recipient: &MirageRecipient) -> Result<MirageRecipient> {
+    let canonical = match recipient {
+        MirageRecipient::Bech32(address) => mirage_recipient_from_bytes(&decode_mirage_address(address)?)?,
+        MirageRecipient::Hex(bytes) => MirageRecipient::Hex(*bytes),
+        MirageRecipient::Module(bytes) => MirageRecipient::Module(*bytes),
+    };
+
+    // The zero address is never a valid destination
+    require!(
+        canonical.as_bytes().iter().any(|b| *b != 0),
+        BridgeError::InvalidMirageRecipient
+    );
+
+    Ok(canonical)
+}
+
+/// Parses user input in either `mirage1...` or `0x...` form
+pub fn parse_mirage_recipient(input: &str) -> Result<MirageRecipient> {
+    let recipient = match input.strip_prefix("0x").or_else(|| input.strip_prefix("0X")) {
+        Some(hex) => mirage_recipient_from_bytes(&decode_hex(hex)?)?,
+        None => MirageRecipient::Bech32(input.to_string()),
+    };
+
+    canonicalize_mirage_recipient(&recipient)
+}
+
+/// Formats a recipient as a `mirage1...` address
+pub fn mirage_recipient_to_bech32(recipient: &MirageRecipient) -> Result<String> {
+    let canonical = canonicalize_mirage_recipient(recipient)?;
+    encode_mirage_address(canonical.as_bytes())
+}
+
+/// Formats a recipient in its lowercase `0x...` form
+pub fn mirage_recipient_to_hex(recipient: &MirageRecipient) -> Result<String> {
+    let canonical = canonicalize_mirage_recipient(recipient)?;
+    let mut hex = String::with_capacity(2 + canonical.as_bytes().len() * 2);
+    hex.push_str("0x");
+    for byte in canonical.as_bytes() {
+        hex.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        hex.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    Ok(hex)
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn mirage_recipient_from_bytes(bytes: &[u8]) -> Result<MirageRecipient> {
+    if let Ok(account) = <[u8; 20]>::try_from(bytes) {
+        return Ok(MirageRecipient::Hex(account));
+    }
+    if let Ok(module) = <[u8; 32]>::try_from(bytes) {
+        return Ok(MirageRecipient::Module(module));
+    }
+    err!(BridgeError::InvalidMirageRecipient)
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    require!(hex.len() % 2 == 0, BridgeError::InvalidMirageRecipient);
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = hex_value(pair[0])?;
+            let low = hex_value(pair[1])?;
+            Ok((high << 4) | low)
+        })
+        .collect()
+}
+
+fn hex_value(digit: u8) -> Result<u8> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => err!(BridgeError::InvalidMirageRecipient),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bech32::{Bech32, Hrp};
+
+    fn is_invalid<T>(result: Result<T>) -> bool {
+        matches!(result, Err(e) if e == BridgeError::InvalidMirageRecipient.into())
+    }
+
+    #[test]
+    fn parses_valid_bech32() {
+        let account = [7u8; 20];
+        let address = encode_mirage_address(&account).unwrap();
+        assert!(address.starts_with("mirage1"));
+
+        let parsed = parse_mirage_recipient(&address).unwrap();
+        assert_eq!(parsed, MirageRecipient::Hex(account));
+        assert_eq!(mirage_recipient_to_bech32(&parsed).unwrap(), address);
+    }
+
+    #[test]
+    fn parses_module_bech32() {
+        let module = [9u8; 32];
+        let address = encode_mirage_address(&module).unwrap();
+        assert_eq!(
+            parse_mirage_recipient(&address).unwrap(),
+            MirageRecipient::Module(module)
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_hrp() {
+        let address = bech32::encode::<Bech32>(Hrp::parse("cosmos").unwrap(), &[7u8; 20]).unwrap();
+        assert!(is_invalid(parse_mirage_recipient(&address)));
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let mut address = encode_mirage_address(&[7u8; 20]).unwrap();
+        let last = address.pop().unwrap();
+        address.push(if last == 'q' { 'p' } else { 'q' });
+        assert!(is_invalid(parse_mirage_recipient(&address)));
+    }
+
+    #[test]
+    fn round_trips_hex() {
+        let input = "0xAbCdEf0123456789abcdef0123456789ABCDEF01";
+        let parsed = parse_mirage_recipient(input).unwrap();
+        assert_eq!(mirage_recipient_to_hex(&parsed).unwrap(), input.to_lowercase());
+
+        let address = mirage_recipient_to_bech32(&parsed).unwrap();
+        assert_eq!(parse_mirage_recipient(&address).unwrap(), parsed);
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        assert!(is_invalid(parse_mirage_recipient("0xabc")));
+        assert!(is_invalid(parse_mirage_recipient("0xzz")));
+        // Neither 20 nor 32 bytes
+        assert!(is_invalid(parse_mirage_recipient("0xabcd")));
+    }
+
+    #[test]
+    fn rejects_zero_address() {
+        let zero = format!("0x{}", "00".repeat(20));
+        assert!(is_invalid(parse_mirage_recipient(&zero)));
+    }
+}
@@ -1,7 +1,19 @@
+pub mod account;
 pub mod bech32;
 pub mod bitmap;
+pub mod burn;
+pub mod denylist;
+pub mod dropoff;
 pub mod ed25519;
+pub mod mirage_recipient;
+pub mod token;
 
+pub use account::*;
 pub use bech32::*;
 pub use bitmap::*;
+pub use burn::*;
+pub use denylist::*;
+pub use dropoff::*;
 pub use ed25519::*;
+pub use mirage_recipient::*;
+pub use token::*;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo};
+
+use crate::errors::BridgeError;
+use crate::state::BridgeConfig;
+
+/// Mints bridged tokens to `to`, signed by the bridge config PDA, and adds
+/// them to `total_minted`.
+pub fn mint_bridged_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    bridge_config: &mut Account<'info, BridgeConfig>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[b"bridge_config", &[bridge_config.bump]]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: token_mint.clone(),
+                to: to.clone(),
+                authority: bridge_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    bridge_config.total_minted = bridge_config
+        .total_minted
+        .checked_add(amount)
+        .ok_or(BridgeError::AmountOverflow)?;
+
+    Ok(())
+}
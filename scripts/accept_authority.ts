@@ -0,0 +1,55 @@
+import { setupFromEnv } from "./common/config";
+import { getBridgeConfigPDA, getBridgeStatePDA } from "./common/pda";
+import { confirmTx } from "./common/utils";
+
+// Usage (with the proposed authority's wallet):
+//   bun run scripts/accept_authority.ts
+
+async function main() {
+  console.log("=== Accept Bridge Authority ===\n");
+
+  const { connection, wallet, program } = setupFromEnv();
+
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [bridgeState] = getBridgeStatePDA();
+
+  const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+  console.log(`Current authority: ${config.authority.toBase58()}`);
+  console.log(`Pending authority: ${config.pendingAuthority?.toBase58() ?? "none"}`);
+  console.log(`Signer:            ${wallet.publicKey.toBase58()}`);
+  console.log("");
+
+  if (!config.pendingAuthority?.equals(wallet.publicKey)) {
+    console.error("❌ Your wallet is not the pending authority!");
+    process.exit(1);
+  }
+
+  const unlockAt = config.authorityTransferUnlockAt.toNumber();
+  if (Date.now() / 1000 < unlockAt) {
+    console.error(`❌ Transfer is locked until ${new Date(unlockAt * 1000).toISOString()}`);
+    process.exit(1);
+  }
+
+  const tx = await program.methods
+    .acceptAuthority()
+    .accounts({
+      newAuthority: wallet.publicKey,
+      bridgeConfig,
+      bridgeState,
+    })
+    .signers([wallet])
+    .rpc();
+
+  await confirmTx(connection, tx);
+
+  console.log(`✅ Authority accepted!`);
+  console.log(`  Transaction: ${tx}`);
+
+  const updatedConfig = await program.account.bridgeConfig.fetch(bridgeConfig);
+  console.log(`\nVerified new authority: ${updatedConfig.authority.toBase58()}`);
+}
+
+main().catch((err) => {
+  console.error("Error:", err);
+  process.exit(1);
+});
@@ -16,6 +16,13 @@ export function getValidatorRegistryPDA(): [PublicKey, number] {
   );
 }
 
+export function getValidatorStagingPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("validator_staging")],
+    PROGRAM_ID
+  );
+}
+
 export function getMintPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("mint")],
@@ -37,9 +44,22 @@ export function getMintRecordPDA(burnTxHash: Buffer): [PublicKey, number] {
   );
 }
 
-export function getBurnRecordPDA(burnNonce: BN): [PublicKey, number] {
+export function getBurnShardPDA(shardId: number): [PublicKey, number] {
+  const id = Buffer.alloc(2);
+  id.writeUInt16LE(shardId);
+  return PublicKey.findProgramAddressSync([Buffer.from("burn_shard"), id], PROGRAM_ID);
+}
+
+export function getBurnRecordPDA(burnId: BN): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("burn_record"), burnId.toArrayLike(Buffer, "le", 8)],
+    PROGRAM_ID
+  );
+}
+
+export function getGasReservePDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("burn_record"), burnNonce.toArrayLike(Buffer, "le", 8)],
+    [Buffer.from("gas_reserve")],
     PROGRAM_ID
   );
 }
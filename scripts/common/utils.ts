@@ -34,28 +34,33 @@ export function shortPubkey(pubkey: PublicKey | string): string {
 }
 
 export function buildAttestationPayload(
+  programId: PublicKey,
+  mirageChainId: string,
   burnTxHash: Buffer,
   mirageSender: string,
   amount: BN,
   recipient: PublicKey,
-  destinationChain: string = "solana"
+  sequence: BN,
+  nativeDropoffLamports: BN = new BN(0)
 ): Buffer {
+  const chainLen = Buffer.alloc(4);
+  chainLen.writeUInt32LE(mirageChainId.length, 0);
+
   const senderLen = Buffer.alloc(4);
   senderLen.writeUInt32LE(mirageSender.length, 0);
-  
-  const amountBuf = amount.toArrayLike(Buffer, "le", 8);
 
-  const chainLen = Buffer.alloc(4);
-  chainLen.writeUInt32LE(destinationChain.length, 0);
-  
   return Buffer.concat([
+    Buffer.from("mirage-bridge:mint-attestation"),
+    programId.toBuffer(),
+    chainLen,
+    Buffer.from(mirageChainId),
     burnTxHash,
     senderLen,
     Buffer.from(mirageSender),
-    amountBuf,
+    amount.toArrayLike(Buffer, "le", 8),
     recipient.toBuffer(),
-    chainLen,
-    Buffer.from(destinationChain),
+    sequence.toArrayLike(Buffer, "le", 8),
+    nativeDropoffLamports.toArrayLike(Buffer, "le", 8),
   ]);
 }
 
@@ -69,3 +74,64 @@ export function hexToBuffer(hex: string): Buffer {
 export function bufferToHex(buffer: Buffer): string {
   return buffer.toString("hex");
 }
+
+// Zero-copy registry layout: 8-byte discriminator, 64-byte header, then
+// `capacity` 96-byte entries of which the first `len` are in use
+const REGISTRY_HEADER_END = 72;
+const REGISTRY_ENTRY_SIZE = 96;
+
+export async function fetchValidatorRegistry(connection: Connection, validatorRegistry: PublicKey) {
+  const account = await connection.getAccountInfo(validatorRegistry);
+  if (!account) {
+    throw new Error(`Validator registry not found: ${validatorRegistry.toBase58()}`);
+  }
+
+  const data = account.data;
+  const len = data.readUInt32LE(28);
+  const validators = [];
+  for (let i = 0; i < len; i++) {
+    const offset = REGISTRY_HEADER_END + i * REGISTRY_ENTRY_SIZE;
+    const addressLen = data.readUInt8(offset + 92);
+    validators.push({
+      orchestratorPubkey: new PublicKey(data.subarray(offset, offset + 32)),
+      stake: new BN(data.subarray(offset + 32, offset + 40), "le"),
+      mirageValidator: data.subarray(offset + 40, offset + 40 + addressLen).toString("utf8"),
+    });
+  }
+
+  return {
+    totalStake: new BN(data.subarray(8, 16), "le"),
+    valsetNonce: new BN(data.subarray(16, 24), "le"),
+    capacity: data.readUInt32LE(24),
+    version: data.readUInt8(33),
+    validators,
+  };
+}
+
+// `BridgeConfig.paused_flags` bits
+export const PAUSE_FLAGS: Record<string, number> = {
+  burns: 1 << 0,
+  mints: 1 << 1,
+  attestations: 1 << 2,
+  finalization: 1 << 3,
+};
+export const PAUSE_ALL = Object.values(PAUSE_FLAGS).reduce((a, b) => a | b, 0);
+
+/** Parses flag names like `mints attestations`; no names means every flag */
+export function parsePauseFlags(names: string[]): number {
+  if (names.length === 0) return PAUSE_ALL;
+  return names.reduce((flags, name) => {
+    const bit = PAUSE_FLAGS[name];
+    if (bit === undefined) {
+      throw new Error(`Unknown pause flag: ${name} (expected ${Object.keys(PAUSE_FLAGS).join(", ")})`);
+    }
+    return flags | bit;
+  }, 0);
+}
+
+export function describePauseFlags(flags: number): string {
+  const names = Object.entries(PAUSE_FLAGS)
+    .filter(([, bit]) => flags & bit)
+    .map(([name]) => name);
+  return names.length > 0 ? names.join(", ") : "none";
+}
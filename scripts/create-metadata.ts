@@ -58,7 +58,7 @@ async function main() {
             tokenUri,
         })
         .accounts({
-            authority: wallet.publicKey,
+            metadataManager: wallet.publicKey,
             bridgeConfig,
             mint,
             metadata,
@@ -0,0 +1,46 @@
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import BN from "bn.js";
+import { setupFromEnv } from "./common/config";
+import { getGasReservePDA, logPDAs } from "./common/pda";
+import { confirmTx } from "./common/utils";
+
+async function main() {
+  console.log("=== Fund Gas Reserve ===\n");
+
+  const { connection, wallet, program } = setupFromEnv();
+  logPDAs();
+  console.log("---");
+
+  const sol = parseFloat(process.env.AMOUNT_SOL || "");
+  if (isNaN(sol) || sol <= 0) {
+    console.log("❌ Set AMOUNT_SOL to the amount of SOL to deposit");
+    process.exit(1);
+  }
+  const amount = new BN(Math.floor(sol * LAMPORTS_PER_SOL));
+
+  const [gasReserve] = getGasReservePDA();
+  console.log(`Depositing ${sol} SOL into ${gasReserve.toBase58()}...`);
+  console.log("");
+
+  const tx = await program.methods
+    .fundGasReserve({ amount })
+    .accounts({
+      funder: wallet.publicKey,
+      gasReserve,
+    })
+    .signers([wallet])
+    .rpc();
+
+  await confirmTx(connection, tx);
+
+  console.log(`✅ Gas reserve funded!`);
+  console.log(`  Transaction: ${tx}`);
+
+  const balance = await connection.getBalance(gasReserve);
+  console.log(`\nGas Reserve Balance: ${balance / LAMPORTS_PER_SOL} SOL`);
+}
+
+main().catch((err) => {
+  console.error("Error:", err);
+  process.exit(1);
+});
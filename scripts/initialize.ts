@@ -3,7 +3,7 @@ import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import BN from "bn.js";
 import { setupFromEnv } from "./common/config";
 import { getBridgeConfigPDA, getBridgeStatePDA, getValidatorRegistryPDA, getMintPDA, getMetadataPDA, METADATA_PROGRAM_ID, logPDAs } from "./common/pda";
-import { confirmTx } from "./common/utils";
+import { confirmTx, describePauseFlags } from "./common/utils";
 
 async function main() {
   console.log("=== Initialize Bridge ===\n");
@@ -26,7 +26,7 @@ async function main() {
     console.log(`  Mint: ${config.mint.toBase58()}`);
     console.log(`  Chain ID: ${config.mirageChainId}`);
     console.log(`  Threshold: ${config.attestationThreshold.toNumber()} basis points`);
-    console.log(`  Paused: ${config.paused}`);
+    console.log(`  Paused: ${describePauseFlags(config.pausedFlags)}`);
     process.exit(1);
   }
 
@@ -79,7 +79,7 @@ async function main() {
   console.log(`  Mint: ${config.mint.toBase58()}`);
   console.log(`  Chain ID: ${config.mirageChainId}`);
   console.log(`  Threshold: ${config.attestationThreshold.toNumber()} basis points`);
-  console.log(`  Paused: ${config.paused}`);
+  console.log(`  Paused: ${describePauseFlags(config.pausedFlags)}`);
 }
 
 main().catch((err) => {
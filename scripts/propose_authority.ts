@@ -1,22 +1,25 @@
 import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
 import { setupFromEnv } from "./common/config";
-import { getBridgeConfigPDA, getBridgeStatePDA } from "./common/pda";
+import { getBridgeConfigPDA } from "./common/pda";
 import { confirmTx } from "./common/utils";
 
-// Usage: 
-//   bun run scripts/transfer_authority.ts <new_authority_pubkey>
+// Usage:
+//   bun run scripts/propose_authority.ts <new_authority_pubkey> [delay_seconds]
+//
+// The new authority then runs scripts/accept_authority.ts with its own wallet.
 //
 // Example:
-//   bun run scripts/transfer_authority.ts BcedynFvVZPchs5ASyh3ypLdnH26GXLUpagg2fHG4b2C
+//   bun run scripts/propose_authority.ts BcedynFvVZPchs5ASyh3ypLdnH26GXLUpagg2fHG4b2C 86400
 
 async function main() {
-  console.log("=== Transfer Bridge Authority ===\n");
+  console.log("=== Propose Bridge Authority ===\n");
 
   const newAuthorityArg = process.argv[2];
   if (!newAuthorityArg) {
-    console.log("Usage: bun run scripts/transfer_authority.ts <new_authority_pubkey>");
+    console.log("Usage: bun run scripts/propose_authority.ts <new_authority_pubkey> [delay_seconds]");
     console.log("\nExample:");
-    console.log("  bun run scripts/transfer_authority.ts BcedynFvVZPchs5ASyh3ypLdnH26GXLUpagg2fHG4b2C");
+    console.log("  bun run scripts/propose_authority.ts BcedynFvVZPchs5ASyh3ypLdnH26GXLUpagg2fHG4b2C 86400");
     process.exit(1);
   }
 
@@ -28,10 +31,15 @@ async function main() {
     process.exit(1);
   }
 
+  const delaySeconds = Number(process.argv[3] ?? 0);
+  if (!Number.isInteger(delaySeconds) || delaySeconds < 0) {
+    console.error("❌ Invalid delay:", process.argv[3]);
+    process.exit(1);
+  }
+
   const { connection, wallet, program } = setupFromEnv();
 
   const [bridgeConfig] = getBridgeConfigPDA();
-  const [bridgeState] = getBridgeStatePDA();
 
   // Fetch current config
   const config = await program.account.bridgeConfig.fetch(bridgeConfig);
@@ -39,6 +47,7 @@ async function main() {
 
   console.log(`Current authority: ${currentAuthority}`);
   console.log(`New authority:     ${newAuthority.toBase58()}`);
+  console.log(`Delay:             ${delaySeconds}s`);
   console.log(`Signer:            ${wallet.publicKey.toBase58()}`);
   console.log("");
 
@@ -54,28 +63,29 @@ async function main() {
     process.exit(1);
   }
 
-  console.log("Transferring authority...\n");
+  console.log("Proposing authority...\n");
 
   const tx = await program.methods
-    .transferAuthority({
+    .proposeAuthority({
       newAuthority,
+      delaySeconds: new BN(delaySeconds),
     })
     .accounts({
       authority: wallet.publicKey,
       bridgeConfig,
-      bridgeState,
     })
     .signers([wallet])
     .rpc();
 
   await confirmTx(connection, tx);
 
-  console.log(`✅ Authority transferred!`);
+  console.log(`✅ Authority proposed!`);
   console.log(`  Transaction: ${tx}`);
 
   // Verify
   const updatedConfig = await program.account.bridgeConfig.fetch(bridgeConfig);
-  console.log(`\nVerified new authority: ${updatedConfig.authority.toBase58()}`);
+  console.log(`\nPending authority: ${updatedConfig.pendingAuthority?.toBase58()}`);
+  console.log(`Can accept from:   ${new Date(updatedConfig.authorityTransferUnlockAt.toNumber() * 1000).toISOString()}`);
 }
 
 main().catch((err) => {
@@ -1,6 +1,6 @@
 import { setupFromEnv } from "./common/config";
 import { getBridgeConfigPDA, getBridgeStatePDA, getValidatorRegistryPDA, getMintPDA, logPDAs } from "./common/pda";
-import { formatAmount, shortPubkey } from "./common/utils";
+import { describePauseFlags, fetchValidatorRegistry, formatAmount, shortPubkey } from "./common/utils";
 
 async function main() {
   console.log("=== Bridge Status ===\n");
@@ -21,7 +21,7 @@ async function main() {
   }
 
   const config = await program.account.bridgeConfig.fetch(bridgeConfig);
-  const registry = await program.account.validatorRegistry.fetch(validatorRegistry);
+  const registry = await fetchValidatorRegistry(connection, validatorRegistry);
   const state = await program.account.bridgeState.fetch(bridgeState);
 
   console.log(`\nBridge Config:`);
@@ -29,18 +29,18 @@ async function main() {
   console.log(`  Mint: ${config.mint.toBase58()}`);
   console.log(`  Chain ID: ${config.mirageChainId}`);
   console.log(`  Attestation Threshold: ${config.attestationThreshold.toNumber()} basis points (${config.attestationThreshold.toNumber() / 100}%)`);
-  console.log(`  Paused: ${config.paused}`);
+  console.log(`  Paused: ${describePauseFlags(config.pausedFlags)}`);
 
   console.log(`\nStatistics:`);
   console.log(`  Total Minted: ${formatAmount(config.totalMinted)} MIRAGE`);
-  console.log(`  Total Burned: ${formatAmount(config.totalBurned)} MIRAGE`);
-  console.log(`  Burn Nonce: ${config.burnNonce.toNumber()}`);
+  console.log(`  Total Burned: ${formatAmount(config.totalBurned)} MIRAGE (synced from burn shards)`);
+  console.log(`  Legacy Burn Nonce: ${config.burnNonce.toNumber()}`);
 
   console.log(`\nReplay Protection:`);
   console.log(`  Last Sequence: ${state.lastSequence.toNumber()}`);
 
   console.log(`\nValidator Registry:`);
-  console.log(`  Total Validators: ${registry.validators.length}`);
+  console.log(`  Total Validators: ${registry.validators.length} / ${registry.capacity}`);
   console.log(`  Total Stake: ${registry.totalStake.toString()}`);
   
   if (registry.validators.length > 0) {
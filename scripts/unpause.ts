@@ -1,6 +1,6 @@
 import { setupFromEnv } from "./common/config";
 import { getBridgeConfigPDA, logPDAs } from "./common/pda";
-import { confirmTx } from "./common/utils";
+import { confirmTx, describePauseFlags, parsePauseFlags } from "./common/utils";
 
 async function main() {
   console.log("=== Unpause Bridge ===\n");
@@ -10,6 +10,7 @@ async function main() {
   console.log("---");
 
   const [bridgeConfig] = getBridgeConfigPDA();
+  const flags = parsePauseFlags(process.argv.slice(2));
 
   const config = await program.account.bridgeConfig.fetch(bridgeConfig);
   
@@ -20,18 +21,18 @@ async function main() {
     process.exit(1);
   }
 
-  if (!config.paused) {
-    console.log("❌ Bridge is not paused!");
+  if ((config.pausedFlags & flags) === 0) {
+    console.log(`❌ Not paused: ${describePauseFlags(flags)}`);
     process.exit(1);
   }
 
-  console.log(`Unpausing bridge...`);
+  console.log(`Unpausing: ${describePauseFlags(flags)}...`);
   console.log("");
 
   const tx = await program.methods
-    .unpause()
+    .unpause({ flags })
     .accounts({
-      authority: wallet.publicKey,
+      admin: wallet.publicKey,
       bridgeConfig,
     })
     .signers([wallet])
@@ -44,7 +45,7 @@ async function main() {
 
   const configAfter = await program.account.bridgeConfig.fetch(bridgeConfig);
   console.log(`\nBridge Status:`);
-  console.log(`  Paused: ${configAfter.paused}`);
+  console.log(`  Paused: ${describePauseFlags(configAfter.pausedFlags)}`);
 }
 
 main().catch((err) => {
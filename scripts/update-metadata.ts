@@ -54,7 +54,7 @@ async function main() {
             tokenUri,
         })
         .accounts({
-            authority: wallet.publicKey,
+            metadataManager: wallet.publicKey,
             bridgeConfig,
             mint,
             metadata,
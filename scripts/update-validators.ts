@@ -3,8 +3,11 @@ import BN from "bn.js";
 import { readdirSync, readFileSync } from "fs";
 import { join } from "path";
 import { setupFromEnv } from "./common/config";
-import { getBridgeConfigPDA, getValidatorRegistryPDA, logPDAs } from "./common/pda";
-import { confirmTx, shortPubkey } from "./common/utils";
+import { getBridgeConfigPDA, getValidatorRegistryPDA, getValidatorStagingPDA, logPDAs } from "./common/pda";
+import { confirmTx, fetchValidatorRegistry, shortPubkey } from "./common/utils";
+
+// Validators per transaction; larger sets are staged in chunks and committed
+const STAGE_CHUNK_SIZE = 8;
 
 interface ValidatorConfig {
   orchestratorPubkey: string;
@@ -92,22 +95,72 @@ async function main() {
   console.log(`  Total stake: ${totalStake.toString()}`);
   console.log("");
 
-  const tx = await program.methods
-    .updateValidators({ validators })
-    .accounts({
-      authority: wallet.publicKey,
-      bridgeConfig,
-      validatorRegistry,
-    })
-    .signers([wallet])
-    .rpc();
+  const current = await fetchValidatorRegistry(connection, validatorRegistry);
+  if (validators.length > current.capacity) {
+    const tx = await program.methods
+      .resizeValidatorRegistry({ capacity: validators.length })
+      .accounts({
+        validatorManager: wallet.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .signers([wallet])
+      .rpc();
+
+    await confirmTx(connection, tx);
+    console.log(`  Resized registry ${current.capacity} -> ${validators.length}: ${tx}`);
+  }
 
-  await confirmTx(connection, tx);
+  if (validators.length <= STAGE_CHUNK_SIZE) {
+    const tx = await program.methods
+      .updateValidators({ validators })
+      .accounts({
+        validatorManager: wallet.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .signers([wallet])
+      .rpc();
+
+    await confirmTx(connection, tx);
+    console.log(`  Transaction: ${tx}`);
+  } else {
+    const [validatorStaging] = getValidatorStagingPDA();
+
+    for (let i = 0; i < validators.length; i += STAGE_CHUNK_SIZE) {
+      const chunk = validators.slice(i, i + STAGE_CHUNK_SIZE);
+      const tx = await program.methods
+        .stageValidators({ validators: chunk, reset: i === 0 })
+        .accounts({
+          validatorManager: wallet.publicKey,
+          bridgeConfig,
+          validatorStaging,
+        })
+        .signers([wallet])
+        .rpc();
+
+      await confirmTx(connection, tx);
+      console.log(`  Staged ${i + chunk.length}/${validators.length}: ${tx}`);
+    }
+
+    const tx = await program.methods
+      .commitValidatorSet()
+      .accounts({
+        validatorManager: wallet.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+        validatorStaging,
+      })
+      .signers([wallet])
+      .rpc();
+
+    await confirmTx(connection, tx);
+    console.log(`  Commit: ${tx}`);
+  }
 
   console.log(`✅ Validators updated!`);
-  console.log(`  Transaction: ${tx}`);
 
-  const registry = await program.account.validatorRegistry.fetch(validatorRegistry);
+  const registry = await fetchValidatorRegistry(connection, validatorRegistry);
   console.log(`\nValidator Registry:`);
   console.log(`  Count: ${registry.validators.length}`);
   console.log(`  Total Stake: ${registry.totalStake.toString()}`);
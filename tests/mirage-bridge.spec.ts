@@ -14,4 +14,21 @@ describe("Mirage Bridge Program Tests", () => {
   require("./specs/unpause.spec");
   require("./specs/burn.spec");
   require("./specs/mint.spec");
+  require("./specs/validator_registry_ops.spec");
+  require("./specs/rotate_validator_set.spec");
+  require("./specs/claim_mint.spec");
+  require("./specs/gas_dropoff.spec");
+  require("./specs/denylist.spec");
+  require("./specs/freeze.spec");
+  require("./specs/transfer_limits.spec");
+  require("./specs/burn_permit.spec");
+  require("./specs/burn_from_program.spec");
+  require("./specs/burn_batch.spec");
+  require("./specs/roles.spec");
+  require("./specs/authority_transfer.spec");
+  require("./specs/admin_timelock.spec");
+  require("./specs/admin_multisig.spec");
+  require("./specs/emergency_pause.spec");
+  require("./specs/update_config.spec");
+  require("./specs/account_migration.spec");
 });
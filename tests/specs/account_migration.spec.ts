@@ -0,0 +1,269 @@
+import { describe, expect, it } from "bun:test";
+import { ComputeBudgetProgram, PublicKey } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import BN from "bn.js";
+import { bech32 } from "bech32";
+import {
+  getBridgeConfigPDA,
+  getBridgeStatePDA,
+  getBurnRecordPDA,
+  getMintRecordPDA,
+  getValidatorRegistryPDA,
+  fetchValidatorRegistry,
+  createFundedKeypair,
+  generateBurnTxHash,
+  PAUSE_ALL,
+  REGISTRY_HEADER_END,
+} from "../utils/helpers";
+import { send } from "../utils/mint";
+import { FailedTransactionMetadata } from "litesvm";
+import { sha256 } from "@noble/hashes/sha2.js";
+
+// Bytes appended to the bridge state in v1: the version byte plus reserved space.
+// Its fields are otherwise unchanged since deployment.
+const BRIDGE_STATE_V1_EXTRA = 1 + 64;
+
+function replaceData(address: PublicKey, data: Buffer) {
+  const { svm } = getTestContext();
+  const account = svm.getAccount(address)!;
+  svm.setAccount(address, {
+    lamports: account.lamports,
+    data,
+    owner: account.owner,
+    executable: false,
+  });
+}
+
+function createAccount(address: PublicKey, data: Buffer) {
+  const { svm, program } = getTestContext();
+  svm.setAccount(address, {
+    lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))),
+    data,
+    owner: program.programId,
+    executable: false,
+  });
+}
+
+function expectOk(result: ReturnType<typeof send>, label: string) {
+  if (result instanceof FailedTransactionMetadata) {
+    throw new Error(`${label} failed: ${result.err().toString()}`);
+  }
+}
+
+describe("23. Account Migration", () => {
+  it("should create accounts at the current layout version", async () => {
+    const { program } = getTestContext();
+    const config = await program.account.bridgeConfig.fetch(getBridgeConfigPDA()[0]);
+    const state = await program.account.bridgeState.fetch(getBridgeStatePDA()[0]);
+    expect(config.version).toBe(1);
+    expect(state.version).toBe(1);
+    expect(fetchValidatorRegistry().version).toBe(1);
+  });
+
+  it("should reject migrating an account that is already current", async () => {
+    const { program, authority } = getTestContext();
+    const ix = await program.methods
+      .migrateBridgeConfig()
+      .accounts({ payer: authority.publicKey })
+      .instruction();
+    const result = send(authority, ix);
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("AlreadyMigrated");
+  });
+
+  it("should migrate the originally deployed bridge config in place", async () => {
+    const { program, authority, svm } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const before = await program.account.bridgeConfig.fetch(bridgeConfig);
+    const current = svm.getAccount(bridgeConfig)!;
+
+    // Deployed layout: authority, mint, mirage_chain_id (max 32), threshold,
+    // total_minted, total_burned, burn_nonce, paused, bump
+    const legacy = Buffer.alloc(8 + 32 + 32 + 4 + 32 + 8 * 4 + 1 + 1);
+    let offset = 0;
+    offset += Buffer.from(current.data).copy(legacy, offset, 0, 8);
+    offset += before.authority.toBuffer().copy(legacy, offset);
+    offset += before.mint.toBuffer().copy(legacy, offset);
+    offset = legacy.writeUInt32LE(before.mirageChainId.length, offset);
+    offset += legacy.write(before.mirageChainId, offset);
+    for (const value of [before.attestationThreshold, before.totalMinted, before.totalBurned, before.burnNonce]) {
+      offset += value.toArrayLike(Buffer, "le", 8).copy(legacy, offset);
+    }
+    offset = legacy.writeUInt8(1, offset); // paused
+    legacy.writeUInt8(before.bump, offset);
+    replaceData(bridgeConfig, legacy);
+
+    const ix = await program.methods
+      .migrateBridgeConfig()
+      .accounts({ payer: authority.publicKey })
+      .instruction();
+    expectOk(send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }), ix), "Migrate config");
+
+    const after = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(after.version).toBe(1);
+    expect(after.authority.toBase58()).toBe(before.authority.toBase58());
+    expect(after.mint.toBase58()).toBe(before.mint.toBase58());
+    expect(after.mirageChainId).toBe(before.mirageChainId);
+    expect(after.attestationThreshold.toString()).toBe(before.attestationThreshold.toString());
+    expect(after.totalMinted.toString()).toBe(before.totalMinted.toString());
+    expect(after.burnNonce.toString()).toBe(before.burnNonce.toString());
+    expect(after.pausedFlags).toBe(PAUSE_ALL);
+    expect(after.maxBurnBatchSize).toBe(10);
+    expect(after.adminSigners.length).toBe(0);
+    expect(svm.getAccount(bridgeConfig)!.data.length).toBe(current.data.length);
+
+    // Settings added since deployment came back as defaults; put the suite's config back
+    svm.setAccount(bridgeConfig, current);
+  });
+
+  it("should migrate an originally deployed burn record", async () => {
+    const { program, authority } = getTestContext();
+    const burnId = new BN(9_000_001);
+    const [burnRecord, bump] = getBurnRecordPDA(burnId);
+    const account = Buffer.alloc(20, 7);
+    const recipient = bech32.encode("mirage", bech32.toWords(account));
+    const sender = PublicKey.unique();
+
+    // Deployed layout: burn_id, solana_sender, mirage_recipient (max 64), amount, timestamp, bump
+    const legacy = Buffer.alloc(8 + 8 + 32 + 4 + 64 + 8 + 8 + 1);
+    let offset = 0;
+    offset += Buffer.from(sha256(Buffer.from("account:BurnRecord")).slice(0, 8)).copy(legacy, offset);
+    offset += burnId.toArrayLike(Buffer, "le", 8).copy(legacy, offset);
+    offset += sender.toBuffer().copy(legacy, offset);
+    offset = legacy.writeUInt32LE(recipient.length, offset);
+    offset += legacy.write(recipient, offset);
+    offset = legacy.writeBigUInt64LE(1_000_000n, offset);
+    offset = legacy.writeBigInt64LE(1_700_000_000n, offset);
+    legacy.writeUInt8(bump, offset);
+    createAccount(burnRecord, legacy);
+
+    const ix = await program.methods
+      .migrateBurnRecord({ burnId })
+      .accounts({ payer: authority.publicKey })
+      .instruction();
+    expectOk(send(authority, ix), "Migrate burn record");
+
+    const after = await program.account.burnRecord.fetch(burnRecord);
+    expect(after.version).toBe(1);
+    expect(after.burnId.toString()).toBe(burnId.toString());
+    expect(after.solanaSender.toBase58()).toBe(sender.toBase58());
+    expect(Buffer.from(after.mirageRecipient.hex![0]).equals(account)).toBe(true);
+    expect(after.amount.toString()).toBe("1000000");
+    expect(after.callerProgram).toBeNull();
+
+    const again = send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }), ix);
+    expect((again as FailedTransactionMetadata).meta().logs().join("\n")).toContain("AlreadyMigrated");
+  });
+
+  it("should close an originally deployed mint record and refund its payer", async () => {
+    const { program, authority, svm } = getTestContext();
+    const burnTxHash = generateBurnTxHash();
+    const [mintRecord, bump] = getMintRecordPDA(burnTxHash);
+    const recordPayer = createFundedKeypair();
+
+    // Deployed layout: payer, burn_tx_hash, recipient, amount,
+    // attestations (Vec<Pubkey>, max 100), attested_power, bump
+    const legacy = Buffer.alloc(8 + 32 + 32 + 32 + 8 + 4 + 100 * 32 + 8 + 1);
+    let offset = 0;
+    offset += Buffer.from(sha256(Buffer.from("account:MintRecord")).slice(0, 8)).copy(legacy, offset);
+    offset += recordPayer.publicKey.toBuffer().copy(legacy, offset);
+    offset += burnTxHash.copy(legacy, offset);
+    offset += PublicKey.unique().toBuffer().copy(legacy, offset);
+    offset = legacy.writeBigUInt64LE(500n, offset);
+    offset = legacy.writeUInt32LE(1, offset);
+    offset += PublicKey.unique().toBuffer().copy(legacy, offset);
+    offset = legacy.writeBigUInt64LE(40n, offset);
+    legacy.writeUInt8(bump, offset);
+    createAccount(mintRecord, legacy);
+    const rent = svm.getAccount(mintRecord)!.lamports;
+    const payerBefore = svm.getBalance(recordPayer.publicKey)!;
+
+    const wrongPayerIx = await program.methods
+      .migrateMintRecord({ burnTxHash: Array.from(burnTxHash) })
+      .accounts({ payer: authority.publicKey, recordPayer: authority.publicKey })
+      .instruction();
+    const rejected = send(authority, wrongPayerIx);
+    expect((rejected as FailedTransactionMetadata).meta().logs().join("\n")).toContain("ConstraintAddress");
+
+    const ix = await program.methods
+      .migrateMintRecord({ burnTxHash: Array.from(burnTxHash) })
+      .accounts({ payer: authority.publicKey, recordPayer: recordPayer.publicKey })
+      .instruction();
+    expectOk(send(authority, ix), "Migrate mint record");
+
+    expect(svm.getAccount(mintRecord)?.lamports ?? 0).toBe(0);
+    expect(svm.getBalance(recordPayer.publicKey)!).toBe(payerBefore + BigInt(rent));
+  });
+
+  it("should migrate a legacy bridge state in place", async () => {
+    const { program, authority, svm } = getTestContext();
+    const [bridgeState] = getBridgeStatePDA();
+    const before = await program.account.bridgeState.fetch(bridgeState);
+    const data = Buffer.from(svm.getAccount(bridgeState)!.data);
+    replaceData(bridgeState, data.subarray(0, data.length - BRIDGE_STATE_V1_EXTRA));
+
+    const ix = await program.methods
+      .migrateBridgeState()
+      .accounts({ payer: authority.publicKey })
+      .instruction();
+    expectOk(send(authority, ix), "Migrate state");
+
+    const after = await program.account.bridgeState.fetch(bridgeState);
+    expect(after.version).toBe(1);
+    expect(after.lastSequence.toString()).toBe(before.lastSequence.toString());
+    expect(after.replayBitmap.map((w) => w.toString())).toEqual(before.replayBitmap.map((w) => w.toString()));
+    expect(svm.getAccount(bridgeState)!.data.length).toBe(data.length);
+  });
+
+  it("should convert the original Borsh registry before it can be used", async () => {
+    const { program, authority, svm } = getTestContext();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+    const before = fetchValidatorRegistry();
+    const current = svm.getAccount(validatorRegistry)!;
+    const bump = Buffer.from(current.data).readUInt8(32);
+
+    // Deployed layout: Vec<ValidatorInfo> (unsorted), total_stake, bump,
+    // allocated for MAX_VALIDATORS = 100 entries
+    const legacy = Buffer.alloc(8 + 4 + 100 * 96 + 8 + 1);
+    let offset = 0;
+    offset += Buffer.from(sha256(Buffer.from("account:ValidatorRegistry")).slice(0, 8)).copy(legacy, offset);
+    offset = legacy.writeUInt32LE(before.validators.length, offset);
+    for (const v of [...before.validators].reverse()) {
+      offset += v.orchestratorPubkey.toBuffer().copy(legacy, offset);
+      offset = legacy.writeUInt32LE(v.mirageValidator.length, offset);
+      offset += legacy.write(v.mirageValidator, offset);
+      offset += v.stake.toArrayLike(Buffer, "le", 8).copy(legacy, offset);
+    }
+    offset += before.totalStake.toArrayLike(Buffer, "le", 8).copy(legacy, offset);
+    legacy.writeUInt8(bump, offset);
+    replaceData(validatorRegistry, legacy);
+
+    const resizeIx = await program.methods
+      .resizeValidatorRegistry({ capacity: before.capacity })
+      .accounts({ validatorManager: authority.publicKey, bridgeConfig: getBridgeConfigPDA()[0], validatorRegistry })
+      .instruction();
+    const rejected = send(authority, resizeIx);
+    expect(rejected instanceof FailedTransactionMetadata).toBe(true);
+    expect((rejected as FailedTransactionMetadata).meta().logs().join("\n")).toContain("AccountDiscriminatorMismatch");
+
+    const migrateIx = await program.methods
+      .migrateValidatorRegistry()
+      .accounts({ payer: authority.publicKey })
+      .instruction();
+    expectOk(send(authority, migrateIx), "Migrate registry");
+
+    const after = fetchValidatorRegistry();
+    expect(after.version).toBe(1);
+    expect(after.capacity).toBe(100);
+    expect(after.valsetNonce.toNumber()).toBe(0);
+    expect(after.totalStake.toString()).toBe(before.totalStake.toString());
+    // Entries come back sorted by orchestrator key
+    expect(after.validators.map((v) => [v.orchestratorPubkey.toBase58(), v.mirageValidator, v.stake.toString()])).toEqual(
+      before.validators.map((v) => [v.orchestratorPubkey.toBase58(), v.mirageValidator, v.stake.toString()])
+    );
+    expect(svm.getAccount(validatorRegistry)!.data.length).toBe(REGISTRY_HEADER_END + 100 * 96);
+
+    const again = send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }), migrateIx);
+    expect((again as FailedTransactionMetadata).meta().logs().join("\n")).toContain("AlreadyMigrated");
+  });
+});
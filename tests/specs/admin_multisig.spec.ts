@@ -0,0 +1,240 @@
+import { describe, expect, it } from "bun:test";
+import { ComputeBudgetProgram, Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { sha256 } from "@noble/hashes/sha2.js";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getBridgeRolesPDA,
+  getValidatorRegistryPDA,
+  getValidatorStagingPDA,
+  roleIx,
+  queueActionIx,
+  executeActionIx,
+  fetchValidatorRegistry,
+  createFundedKeypair,
+} from "../utils/helpers";
+import { send } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+async function approveIx(approver: PublicKey, queuedAction: PublicKey) {
+  const { program } = getTestContext();
+  return program.methods
+    .approveAction()
+    .accounts({ approver, bridgeConfig: getBridgeConfigPDA()[0], queuedAction })
+    .instruction();
+}
+
+function currentValidators() {
+  return fetchValidatorRegistry().validators.map((v) => ({
+    orchestratorPubkey: v.orchestratorPubkey,
+    mirageValidator: v.mirageValidator,
+    stake: v.stake,
+  }));
+}
+
+// Borsh encoding of Vec<ValidatorInfo>, as hashed by `ValidatorStaging::set_hash`
+function stagedSetHash(validators: ReturnType<typeof currentValidators>): number[] {
+  const parts: Buffer[] = [Buffer.alloc(4)];
+  parts[0].writeUInt32LE(validators.length);
+  for (const v of validators) {
+    const name = Buffer.from(v.mirageValidator);
+    const len = Buffer.alloc(4);
+    len.writeUInt32LE(name.length);
+    parts.push(v.orchestratorPubkey.toBuffer(), len, name, v.stake.toArrayLike(Buffer, "le", 8));
+  }
+  return Array.from(sha256(Buffer.concat(parts)));
+}
+
+async function stageIx(validators: ReturnType<typeof currentValidators>) {
+  const { program, authority } = getTestContext();
+  return program.methods
+    .stageValidators({ validators, reset: true })
+    .accounts({
+      validatorManager: authority.publicKey,
+      bridgeConfig: getBridgeConfigPDA()[0],
+      validatorStaging: getValidatorStagingPDA()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+function expectOk(result: ReturnType<typeof send>, label: string) {
+  if (result instanceof FailedTransactionMetadata) {
+    throw new Error(`${label} failed: ${result.err().toString()}`);
+  }
+}
+
+describe("20. Admin Multisig", () => {
+  const signers: Keypair[] = [createFundedKeypair(), createFundedKeypair(), createFundedKeypair()];
+  const [alice, bob, carol] = signers;
+
+  it("should turn on a 2-of-3 admin multisig through the action queue", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const { ix, queuedAction } = await queueActionIx(authority.publicKey, {
+      setAdminMultisig: { signers: signers.map((s) => s.publicKey), threshold: 2 },
+    });
+    expectOk(send(authority, ix), "Queue");
+    expectOk(send(authority, await executeActionIx(queuedAction, authority.publicKey)), "Execute");
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.adminThreshold).toBe(2);
+    expect(config.adminSigners.map((k: PublicKey) => k.toBase58())).toEqual(
+      signers.map((s) => s.publicKey.toBase58())
+    );
+  });
+
+  it("should reject direct validator set updates and proposals from non-signers", async () => {
+    const { program, authority } = getTestContext();
+
+    const direct = await program.methods
+      .updateValidators({ validators: currentValidators() })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig: getBridgeConfigPDA()[0],
+        validatorRegistry: getValidatorRegistryPDA()[0],
+      })
+      .instruction();
+    const result = send(authority, direct);
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("MultisigRequired");
+
+    // The single-key authority is not an admin signer
+    const { ix } = await queueActionIx(authority.publicKey, { updateValidators: { 0: { validators: currentValidators() } } });
+    expect(send(authority, ix) instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should apply a validator set update only once it has two approvals", async () => {
+    const before = fetchValidatorRegistry();
+
+    const { ix, queuedAction } = await queueActionIx(alice.publicKey, {
+      updateValidators: { 0: { validators: currentValidators() } },
+    });
+    expectOk(send(alice, ix), "Propose");
+
+    // One approval (the proposer's) is not enough
+    const early = send(alice, await executeActionIx(queuedAction, alice.publicKey));
+    expect(early instanceof FailedTransactionMetadata).toBe(true);
+
+    // Approving twice does not count twice, and outsiders cannot approve
+    expect(send(alice, await approveIx(alice.publicKey, queuedAction)) instanceof FailedTransactionMetadata).toBe(true);
+    const outsider = createFundedKeypair();
+    expect(send(outsider, await approveIx(outsider.publicKey, queuedAction)) instanceof FailedTransactionMetadata).toBe(
+      true
+    );
+
+    expectOk(send(bob, await approveIx(bob.publicKey, queuedAction)), "Approve");
+    expectOk(
+      send(
+        carol,
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+        await executeActionIx(queuedAction, alice.publicKey)
+      ),
+      "Execute"
+    );
+
+    const after = fetchValidatorRegistry();
+    expect(after.valsetNonce.toString()).toBe(before.valsetNonce.add(new BN(1)).toString());
+    expect(after.validators.length).toBe(before.validators.length);
+  });
+
+  it("should commit a staged validator set only as approved", async () => {
+    const { authority, svm } = getTestContext();
+    const before = fetchValidatorRegistry();
+    const validators = currentValidators();
+    const reweighted = validators.map((v, i) => ({ ...v, stake: i === 0 ? v.stake.add(new BN(1)) : v.stake }));
+
+    expectOk(send(authority, await stageIx(reweighted)), "Stage");
+    const { ix, queuedAction } = await queueActionIx(alice.publicKey, {
+      commitValidatorSet: { stagedSetHash: stagedSetHash(reweighted) },
+    });
+    expectOk(send(alice, ix), "Propose commit");
+    expectOk(send(bob, await approveIx(bob.publicKey, queuedAction)), "Approve commit");
+
+    // Restaging after approval changes the hash, so the commit no longer applies
+    expectOk(
+      send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }), await stageIx(validators)),
+      "Restage"
+    );
+    const swapped = send(carol, await executeActionIx(queuedAction, alice.publicKey, { withValidatorStaging: true }));
+    expect(swapped instanceof FailedTransactionMetadata).toBe(true);
+    expect((swapped as FailedTransactionMetadata).meta().logs().join("\n")).toContain("StagedSetMismatch");
+
+    expectOk(
+      send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }), await stageIx(reweighted)),
+      "Stage again"
+    );
+    expectOk(
+      send(
+        carol,
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+        await executeActionIx(queuedAction, alice.publicKey, { withValidatorStaging: true })
+      ),
+      "Execute commit"
+    );
+
+    const after = fetchValidatorRegistry();
+    expect(after.valsetNonce.toString()).toBe(before.valsetNonce.add(new BN(1)).toString());
+    expect(after.totalStake.toString()).toBe(before.totalStake.add(new BN(1)).toString());
+    expect(svm.getAccount(getValidatorStagingPDA()[0])?.lamports ?? 0).toBe(0);
+  });
+
+  it("should only change roles through an approved proposal", async () => {
+    const { program, authority } = getTestContext();
+    const member = Keypair.generate().publicKey;
+    const hasPauser = async () =>
+      (await program.account.bridgeRoles.fetch(getBridgeRolesPDA()[0])).members.some(
+        (m: { member: PublicKey; roles: number }) => m.member.equals(member) && (m.roles & (1 << 1)) !== 0
+      );
+
+    const direct = send(authority, await roleIx("grantRole", authority.publicKey, member, "pauser"));
+    expect(direct instanceof FailedTransactionMetadata).toBe(true);
+    expect((direct as FailedTransactionMetadata).meta().logs().join("\n")).toContain("MultisigRequired");
+
+    const grant = await queueActionIx(alice.publicKey, { grantRole: { 0: { member, role: { pauser: {} } } } });
+    expectOk(send(alice, grant.ix), "Propose grant");
+    expectOk(send(bob, await approveIx(bob.publicKey, grant.queuedAction)), "Approve grant");
+
+    // The roles account has to grow, so a payer must come with it
+    const missing = send(
+      carol,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }),
+      await executeActionIx(grant.queuedAction, alice.publicKey)
+    );
+    expect(missing instanceof FailedTransactionMetadata).toBe(true);
+    expect((missing as FailedTransactionMetadata).meta().logs().join("\n")).toContain("MissingActionAccounts");
+
+    expectOk(
+      send(carol, await executeActionIx(grant.queuedAction, alice.publicKey, { payer: carol.publicKey })),
+      "Execute grant"
+    );
+    expect(await hasPauser()).toBe(true);
+
+    const revoke = await queueActionIx(bob.publicKey, { revokeRole: { 0: { member, role: { pauser: {} } } } });
+    expectOk(send(bob, revoke.ix), "Propose revoke");
+    expectOk(send(carol, await approveIx(carol.publicKey, revoke.queuedAction)), "Approve revoke");
+    expectOk(
+      send(carol, await executeActionIx(revoke.queuedAction, bob.publicKey, { payer: carol.publicKey })),
+      "Execute revoke"
+    );
+    expect(await hasPauser()).toBe(false);
+  });
+
+  it("should turn the multisig back off with two approvals", async () => {
+    const { program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const { ix, queuedAction } = await queueActionIx(bob.publicKey, {
+      setAdminMultisig: { signers: [], threshold: 0 },
+    });
+    expectOk(send(bob, ix), "Propose");
+    expectOk(send(carol, await approveIx(carol.publicKey, queuedAction)), "Approve");
+    expectOk(send(carol, await executeActionIx(queuedAction, bob.publicKey)), "Execute");
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.adminThreshold).toBe(0);
+    expect(config.adminSigners.length).toBe(0);
+  });
+});
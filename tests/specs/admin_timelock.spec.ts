@@ -0,0 +1,315 @@
+import { describe, expect, it } from "bun:test";
+import { ComputeBudgetProgram, Keypair, PublicKey } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getBridgeRolesPDA,
+  queueActionIx,
+  executeActionIx,
+  createFundedKeypair,
+  fetchValidatorRegistry,
+  getGasReservePDA,
+} from "../utils/helpers";
+import { send } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+const noLimits = { minAmount: new BN(0), maxAmount: new BN(0) };
+
+async function cancelIx(admin: PublicKey, queuedAction: PublicKey, queuedBy: PublicKey) {
+  const { program } = getTestContext();
+  return program.methods
+    .cancelAction()
+    .accounts({
+      admin,
+      bridgeConfig: getBridgeConfigPDA()[0],
+      bridgeRoles: getBridgeRolesPDA()[0],
+      queuedAction,
+      queuedBy,
+    })
+    .instruction();
+}
+
+function warp(seconds: number) {
+  const { svm } = getTestContext();
+  const clock = svm.getClock();
+  clock.unixTimestamp += BigInt(seconds);
+  svm.setClock(clock);
+}
+
+describe("19. Admin Timelock", () => {
+  it("should only let admins queue actions", async () => {
+    const outsider = createFundedKeypair();
+    const { ix } = await queueActionIx(outsider.publicKey, { setAdminActionDelay: { adminActionDelay: new BN(0) } });
+    expect(send(outsider, ix) instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should enable the timelock through an immediately executable action", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const { ix, queuedAction } = await queueActionIx(authority.publicKey, {
+      setAdminActionDelay: { adminActionDelay: new BN(3600) },
+    });
+    const queued = send(authority, ix);
+    if (queued instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queued.err().toString()}`);
+    }
+
+    const executed = send(authority, await executeActionIx(queuedAction, authority.publicKey));
+    if (executed instanceof FailedTransactionMetadata) {
+      throw new Error(`Execute failed: ${executed.err().toString()}`);
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.adminActionDelay.toNumber()).toBe(3600);
+    expect(getTestContext().svm.getAccount(queuedAction)).toBeNull();
+  });
+
+  it("should reject direct changes while the timelock is on", async () => {
+    const { program, authority } = getTestContext();
+    const ix = await program.methods
+      .setTransferLimits({ burnLimits: noLimits, mintLimits: noLimits })
+      .accounts({ feeManager: authority.publicKey, bridgeConfig: getBridgeConfigPDA()[0] })
+      .instruction();
+    const result = send(authority, ix);
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("TimelockRequired");
+  });
+
+  it("should hold a queued action until its eta and let anyone execute it after", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const burnLimits = { minAmount: new BN(10), maxAmount: new BN(0) };
+
+    const { ix, queuedAction } = await queueActionIx(authority.publicKey, {
+      setTransferLimits: { 0: { burnLimits, mintLimits: noLimits } },
+    });
+    const queued = send(authority, ix);
+    if (queued instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queued.err().toString()}`);
+    }
+
+    const keeper = createFundedKeypair();
+    const early = send(keeper, await executeActionIx(queuedAction, authority.publicKey));
+    expect(early instanceof FailedTransactionMetadata).toBe(true);
+
+    warp(3601);
+    const executed = send(
+      keeper,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await executeActionIx(queuedAction, authority.publicKey)
+    );
+    if (executed instanceof FailedTransactionMetadata) {
+      throw new Error(`Execute failed: ${executed.err().toString()}`);
+    }
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.burnLimits.minAmount.toNumber()).toBe(10);
+  });
+
+  it("should only change the claim config through the queue", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const before = await program.account.bridgeConfig.fetch(bridgeConfig);
+    const params = {
+      claimMode: false,
+      claimExpiry: before.claimExpiry.addn(1),
+      unclaimedSweepAddress: before.unclaimedSweepAddress,
+    };
+
+    const direct = await program.methods
+      .setClaimConfig(params)
+      .accounts({ admin: authority.publicKey, bridgeConfig })
+      .instruction();
+    const rejected = send(authority, direct);
+    expect(rejected instanceof FailedTransactionMetadata).toBe(true);
+    expect((rejected as FailedTransactionMetadata).meta().logs().join("\n")).toContain("TimelockRequired");
+
+    const { ix, queuedAction } = await queueActionIx(authority.publicKey, { setClaimConfig: { 0: params } });
+    const queued = send(authority, ix);
+    if (queued instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queued.err().toString()}`);
+    }
+
+    warp(3601);
+    const executed = send(authority, await executeActionIx(queuedAction, authority.publicKey));
+    if (executed instanceof FailedTransactionMetadata) {
+      throw new Error(`Execute failed: ${executed.err().toString()}`);
+    }
+    const after = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(after.claimExpiry.toString()).toBe(params.claimExpiry.toString());
+  });
+
+  it("should only change the burn batch size through the queue", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const { maxBurnBatchSize } = await program.account.bridgeConfig.fetch(bridgeConfig);
+
+    const direct = await program.methods
+      .setMaxBurnBatchSize({ maxBurnBatchSize: maxBurnBatchSize + 1 })
+      .accounts({ feeManager: authority.publicKey, bridgeConfig })
+      .instruction();
+    const rejected = send(authority, direct);
+    expect(rejected instanceof FailedTransactionMetadata).toBe(true);
+    expect((rejected as FailedTransactionMetadata).meta().logs().join("\n")).toContain("TimelockRequired");
+
+    const raise = await queueActionIx(authority.publicKey, {
+      setMaxBurnBatchSize: { 0: { maxBurnBatchSize: maxBurnBatchSize + 1 } },
+    });
+    const queuedRaise = send(authority, raise.ix);
+    if (queuedRaise instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queuedRaise.err().toString()}`);
+    }
+    const restore = await queueActionIx(authority.publicKey, { setMaxBurnBatchSize: { 0: { maxBurnBatchSize } } });
+    const queuedRestore = send(authority, restore.ix);
+    if (queuedRestore instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queuedRestore.err().toString()}`);
+    }
+
+    warp(3601);
+    const raised = send(authority, await executeActionIx(raise.queuedAction, authority.publicKey));
+    if (raised instanceof FailedTransactionMetadata) {
+      throw new Error(`Execute failed: ${raised.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).maxBurnBatchSize).toBe(maxBurnBatchSize + 1);
+
+    const restored = send(authority, await executeActionIx(restore.queuedAction, authority.publicKey));
+    if (restored instanceof FailedTransactionMetadata) {
+      throw new Error(`Execute failed: ${restored.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).maxBurnBatchSize).toBe(maxBurnBatchSize);
+  });
+
+  it("should queue single validator changes", async () => {
+    const { authority } = getTestContext();
+    const before = fetchValidatorRegistry();
+    const [first] = before.validators;
+    const added = {
+      orchestratorPubkey: Keypair.generate().publicKey,
+      mirageValidator: "miragevaloper1queuedvalidator",
+      stake: new BN(1_000),
+    };
+
+    const actions = [
+      { addValidator: { 0: { validator: added } } },
+      {
+        updateValidatorStake: {
+          0: { orchestratorPubkey: first.orchestratorPubkey, stake: first.stake.add(new BN(5)) },
+        },
+      },
+      { removeValidator: { 0: { orchestratorPubkey: added.orchestratorPubkey } } },
+    ];
+    const queued: PublicKey[] = [];
+    for (const action of actions) {
+      const { ix, queuedAction } = await queueActionIx(authority.publicKey, action);
+      const result = send(authority, ix);
+      if (result instanceof FailedTransactionMetadata) {
+        throw new Error(`Queue failed: ${result.err().toString()}`);
+      }
+      queued.push(queuedAction);
+    }
+
+    warp(3601);
+    for (const queuedAction of queued) {
+      const result = send(authority, await executeActionIx(queuedAction, authority.publicKey));
+      if (result instanceof FailedTransactionMetadata) {
+        throw new Error(`Execute failed: ${result.err().toString()}`);
+      }
+    }
+
+    const after = fetchValidatorRegistry();
+    expect(after.validators.length).toBe(before.validators.length);
+    expect(after.totalStake.toString()).toBe(before.totalStake.add(new BN(5)).toString());
+    expect(after.valsetNonce.toString()).toBe(before.valsetNonce.add(new BN(3)).toString());
+  });
+
+  it("should only withdraw from the gas reserve through the queue", async () => {
+    const { program, authority, svm } = getTestContext();
+    const [gasReserve] = getGasReservePDA();
+    const amount = new BN(1_000);
+
+    const direct = await program.methods
+      .withdrawGasReserve({ amount })
+      .accounts({ feeManager: authority.publicKey, bridgeConfig: getBridgeConfigPDA()[0], gasReserve })
+      .instruction();
+    const rejected = send(authority, direct);
+    expect(rejected instanceof FailedTransactionMetadata).toBe(true);
+    expect((rejected as FailedTransactionMetadata).meta().logs().join("\n")).toContain("TimelockRequired");
+
+    const { ix, queuedAction } = await queueActionIx(authority.publicKey, { withdrawGasReserve: { 0: { amount } } });
+    const queued = send(authority, ix);
+    if (queued instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queued.err().toString()}`);
+    }
+    warp(3601);
+
+    const missing = send(authority, await executeActionIx(queuedAction, authority.publicKey));
+    expect((missing as FailedTransactionMetadata).meta().logs().join("\n")).toContain("MissingActionAccounts");
+
+    const before = svm.getBalance(gasReserve)!;
+    const executed = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await executeActionIx(queuedAction, authority.publicKey, { withGasReserve: true })
+    );
+    if (executed instanceof FailedTransactionMetadata) {
+      throw new Error(`Execute failed: ${executed.err().toString()}`);
+    }
+    expect(svm.getBalance(gasReserve)!).toBe(before - 1_000n);
+  });
+
+  it("should let an admin cancel a queued action", async () => {
+    const { authority, svm } = getTestContext();
+    const { ix, queuedAction } = await queueActionIx(authority.publicKey, {
+      setTransferLimits: { 0: { burnLimits: noLimits, mintLimits: noLimits } },
+    });
+    const queued = send(authority, ix);
+    if (queued instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queued.err().toString()}`);
+    }
+
+    const outsider = createFundedKeypair();
+    expect(
+      send(outsider, await cancelIx(outsider.publicKey, queuedAction, authority.publicKey)) instanceof
+        FailedTransactionMetadata
+    ).toBe(true);
+
+    const cancelled = send(authority, await cancelIx(authority.publicKey, queuedAction, authority.publicKey));
+    if (cancelled instanceof FailedTransactionMetadata) {
+      throw new Error(`Cancel failed: ${cancelled.err().toString()}`);
+    }
+    expect(svm.getAccount(queuedAction)).toBeNull();
+  });
+
+  it("should turn the timelock back off for the remaining specs", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const reset = await queueActionIx(authority.publicKey, {
+      setTransferLimits: { 0: { burnLimits: noLimits, mintLimits: noLimits } },
+    });
+    const queuedReset = send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }), reset.ix);
+    if (queuedReset instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queuedReset.err().toString()}`);
+    }
+    const off = await queueActionIx(authority.publicKey, { setAdminActionDelay: { adminActionDelay: new BN(0) } });
+    const queuedOff = send(authority, off.ix);
+    if (queuedOff instanceof FailedTransactionMetadata) {
+      throw new Error(`Queue failed: ${queuedOff.err().toString()}`);
+    }
+
+    warp(3601);
+    const executed = send(
+      authority,
+      await executeActionIx(reset.queuedAction, authority.publicKey),
+      await executeActionIx(off.queuedAction, authority.publicKey)
+    );
+    if (executed instanceof FailedTransactionMetadata) {
+      throw new Error(`Execute failed: ${executed.err().toString()}`);
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.adminActionDelay.toNumber()).toBe(0);
+    expect(config.burnLimits.minAmount.toNumber()).toBe(0);
+  });
+});
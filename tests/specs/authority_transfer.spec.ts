@@ -0,0 +1,126 @@
+import { describe, expect, it } from "bun:test";
+import { ComputeBudgetProgram, PublicKey } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import { getBridgeConfigPDA, createFundedKeypair } from "../utils/helpers";
+import { send } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+async function proposeIx(authority: PublicKey, newAuthority: PublicKey, delaySeconds: number) {
+  const { program } = getTestContext();
+  return program.methods
+    .proposeAuthority({ newAuthority, delaySeconds: new BN(delaySeconds) })
+    .accounts({ authority, bridgeConfig: getBridgeConfigPDA()[0] })
+    .instruction();
+}
+
+async function acceptIx(newAuthority: PublicKey) {
+  const { program } = getTestContext();
+  return program.methods
+    .acceptAuthority()
+    .accounts({ newAuthority, bridgeConfig: getBridgeConfigPDA()[0] })
+    .instruction();
+}
+
+async function cancelIx(authority: PublicKey) {
+  const { program } = getTestContext();
+  return program.methods
+    .cancelAuthorityTransfer()
+    .accounts({ authority, bridgeConfig: getBridgeConfigPDA()[0] })
+    .instruction();
+}
+
+describe("18. Authority Transfer", () => {
+  const newAuthority = createFundedKeypair();
+
+  it("should only let the authority propose", async () => {
+    const outsider = createFundedKeypair();
+    const result = send(outsider, await proposeIx(outsider.publicKey, outsider.publicKey, 0));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should hold a proposal until its delay passes and allow cancelling it", async () => {
+    const { svm, program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const proposed = send(authority, await proposeIx(authority.publicKey, newAuthority.publicKey, 3600));
+    if (proposed instanceof FailedTransactionMetadata) {
+      throw new Error(`Propose failed: ${proposed.err().toString()}`);
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.pendingAuthority?.toBase58()).toBe(newAuthority.publicKey.toBase58());
+    expect(config.authority.toBase58()).toBe(authority.publicKey.toBase58());
+
+    // Still locked
+    expect(send(newAuthority, await acceptIx(newAuthority.publicKey)) instanceof FailedTransactionMetadata).toBe(true);
+
+    // Only the proposed key can accept
+    const clock = svm.getClock();
+    clock.unixTimestamp += BigInt(3601);
+    svm.setClock(clock);
+    const outsider = createFundedKeypair();
+    expect(send(outsider, await acceptIx(outsider.publicKey)) instanceof FailedTransactionMetadata).toBe(true);
+
+    const cancelled = send(authority, await cancelIx(authority.publicKey));
+    if (cancelled instanceof FailedTransactionMetadata) {
+      throw new Error(`Cancel failed: ${cancelled.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pendingAuthority).toBeNull();
+
+    const afterCancel = send(
+      newAuthority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await acceptIx(newAuthority.publicKey)
+    );
+    expect(afterCancel instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should transfer the authority once the new key accepts, and back again", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const proposed = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await proposeIx(authority.publicKey, newAuthority.publicKey, 0)
+    );
+    if (proposed instanceof FailedTransactionMetadata) {
+      throw new Error(`Propose failed: ${proposed.err().toString()}`);
+    }
+
+    const accepted = send(
+      newAuthority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }),
+      await acceptIx(newAuthority.publicKey)
+    );
+    if (accepted instanceof FailedTransactionMetadata) {
+      throw new Error(`Accept failed: ${accepted.err().toString()}`);
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.authority.toBase58()).toBe(newAuthority.publicKey.toBase58());
+    expect(config.pendingAuthority).toBeNull();
+
+    // The old authority is out
+    const stale = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }),
+      await proposeIx(authority.publicKey, authority.publicKey, 0)
+    );
+    expect(stale instanceof FailedTransactionMetadata).toBe(true);
+
+    // Hand it back for the remaining specs
+    const back = send(newAuthority, await proposeIx(newAuthority.publicKey, authority.publicKey, 0));
+    if (back instanceof FailedTransactionMetadata) {
+      throw new Error(`Propose back failed: ${back.err().toString()}`);
+    }
+    const restored = send(authority, await acceptIx(authority.publicKey));
+    if (restored instanceof FailedTransactionMetadata) {
+      throw new Error(`Accept back failed: ${restored.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).authority.toBase58()).toBe(
+      authority.publicKey.toBase58()
+    );
+  });
+});
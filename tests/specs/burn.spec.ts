@@ -1,13 +1,22 @@
 import { describe, expect, it } from "bun:test";
-import { Transaction, SystemProgram, ComputeBudgetProgram } from "@solana/web3.js";
+import { Transaction, SystemProgram, ComputeBudgetProgram, Keypair, PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { getTestContext } from "../utils/setup";
 import { 
   getBridgeConfigPDA, 
   getMintPDA, 
   getBurnRecordPDA, 
+  getBurnShardPDA,
+  nextBurnAccounts,
   createFundedKeypair,
   setupTokenAccount,
+  getBurnDenylistShards,
+  setupNonAtaTokenAccount,
+  getTokenBalance,
+  PAUSE_ALL,
+  PAUSE_MINTS,
+  PAUSE_ATTESTATIONS,
+  PAUSE_FINALIZATION,
 } from "../utils/helpers";
 import BN from "bn.js";
 import { FailedTransactionMetadata, TransactionMetadata } from "litesvm";
@@ -21,11 +30,10 @@ describe("5. Burn", () => {
 
     // Verify bridge is not paused before burn
     const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configBefore.paused).toBe(false);
+    expect(configBefore.pausedFlags).toBe(0);
 
-    // Get current burn nonce for PDA derivation
-    const burnNonce = configBefore.burnNonce;
-    const [burnRecord] = getBurnRecordPDA(burnNonce);
+    // Next burn ID comes from the shard counter
+    const { burnShard, burnRecord } = await nextBurnAccounts();
 
     // Set up user with tokens
     const user = createFundedKeypair();
@@ -37,7 +45,7 @@ describe("5. Burn", () => {
 
     const ix = await program.methods
       .burn({
-        mirageRecipient,
+        mirageRecipient: { bech32: { 0: mirageRecipient } },
         amount: new BN(burnAmount.toString()),
       })
       .accounts({
@@ -46,6 +54,8 @@ describe("5. Burn", () => {
         tokenMint,
         bridgeConfig,
         burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, mirageRecipient),
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -66,14 +76,45 @@ describe("5. Burn", () => {
     expect(result).toBeDefined();
   });
 
-  it("should update bridge config after burn", async () => {
-    const { program } = getTestContext();
+  it("should track the burn on the shard and sync it into the bridge config", async () => {
+    const { svm, program } = getTestContext();
 
     const [bridgeConfig] = getBridgeConfigPDA();
-    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    const [burnShard] = getBurnShardPDA(0);
+
+    const shard = await program.account.burnShard.fetch(burnShard);
+    expect(shard.nextNonce.toNumber()).toBe(1);
+    expect(shard.unsyncedBurned.toNumber()).toBe(100_000_000);
+
+    // Burns no longer write the config; the legacy counter stays frozen
+    const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(configBefore.burnNonce.toNumber()).toBe(0);
+    expect(configBefore.totalBurned.toNumber()).toBe(0);
 
-    expect(config.burnNonce.toNumber()).toBe(1);
+    // Anyone can fold the shard total into the config
+    const caller = createFundedKeypair();
+    const ix = await program.methods
+      .syncBurnTotals()
+      .accounts({
+        bridgeConfig,
+        burnShard,
+      })
+      .instruction();
+
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(ix);
+    tx.sign(caller);
+
+    const result = svm.sendTransaction(tx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Sync burn totals failed: ${result.err().toString()}`);
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
     expect(config.totalBurned.toNumber()).toBe(100_000_000);
+    const shardAfter = await program.account.burnShard.fetch(burnShard);
+    expect(shardAfter.unsyncedBurned.toNumber()).toBe(0);
   });
 
   it("should create burn record with correct fields", async () => {
@@ -85,7 +126,10 @@ describe("5. Burn", () => {
     // Verify all BurnRecord fields match SOLANA_BRIDGE_SPEC.md
     expect(record.burnId.toNumber()).toBe(0);
     expect(record.amount.toNumber()).toBe(100_000_000);
-    expect(record.mirageRecipient).toBe("mirage1qy352euf40x77qfrg4ncn27dauqjx3t8laxec9");
+    // Bech32 input is stored in its canonical 20-byte form
+    expect(Buffer.from(record.mirageRecipient.hex[0]).toString("hex")).toBe(
+      "0123456789abcdef0123456789abcdef01234567"
+    );
     expect(record.solanaSender).toBeDefined(); // Should be the user's pubkey
     expect(record.timestamp).toBeDefined(); // Timestamp from Clock sysvar (may be 0 in test env)
   });
@@ -96,9 +140,7 @@ describe("5. Burn", () => {
     const [bridgeConfig] = getBridgeConfigPDA();
     const [tokenMint] = getMintPDA();
 
-    const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    const burnNonce = configBefore.burnNonce;
-    const [burnRecord] = getBurnRecordPDA(burnNonce);
+    const { burnShard, burnRecord, burnId } = await nextBurnAccounts();
 
     const user = createFundedKeypair();
     const burnAmount = BigInt(50_000_000);
@@ -108,7 +150,7 @@ describe("5. Burn", () => {
 
     const ix = await program.methods
       .burn({
-        mirageRecipient,
+        mirageRecipient: { bech32: { 0: mirageRecipient } },
         amount: new BN(burnAmount.toString()),
       })
       .accounts({
@@ -117,6 +159,8 @@ describe("5. Burn", () => {
         tokenMint,
         bridgeConfig,
         burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, mirageRecipient),
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -142,7 +186,7 @@ describe("5. Burn", () => {
 
     // Verify burn record has correct burn_id (auto-incrementing)
     const record = await program.account.burnRecord.fetch(burnRecord);
-    expect(record.burnId.toNumber()).toBe(burnNonce.toNumber());
+    expect(record.burnId.toNumber()).toBe(burnId.toNumber());
   });
 
   it("should auto-increment burn_id for each burn (uniqueness)", async () => {
@@ -151,20 +195,19 @@ describe("5. Burn", () => {
     const [bridgeConfig] = getBridgeConfigPDA();
     const [tokenMint] = getMintPDA();
 
-    // Get current nonce
-    const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    const startNonce = configBefore.burnNonce.toNumber();
+    // Get current shard nonce
+    const [shardPda] = getBurnShardPDA(0);
+    const startNonce = (await program.account.burnShard.fetch(shardPda)).nextNonce.toNumber();
 
     // Do another burn
-    const burnNonce = configBefore.burnNonce;
-    const [burnRecord] = getBurnRecordPDA(burnNonce);
+    const { burnShard, burnRecord, burnId } = await nextBurnAccounts();
 
     const user = createFundedKeypair();
     const userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(25_000_000));
 
     const ix = await program.methods
       .burn({
-        mirageRecipient: "mirage1qy352euf40x77qfrg4ncn27dauqjx3t8laxec9",
+        mirageRecipient: { bech32: { 0: "mirage1qy352euf40x77qfrg4ncn27dauqjx3t8laxec9" } },
         amount: new BN(25_000_000),
       })
       .accounts({
@@ -173,6 +216,8 @@ describe("5. Burn", () => {
         tokenMint,
         bridgeConfig,
         burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, "mirage1qy352euf40x77qfrg4ncn27dauqjx3t8laxec9"),
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -189,12 +234,59 @@ describe("5. Burn", () => {
     }
 
     // Verify nonce incremented
-    const configAfter = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configAfter.burnNonce.toNumber()).toBe(startNonce + 1);
+    const shardAfter = await program.account.burnShard.fetch(burnShard);
+    expect(shardAfter.nextNonce.toNumber()).toBe(startNonce + 1);
+
+    // Verify burn record has unique ID: nonce in the high bits, shard in the low bits
+    const record = await program.account.burnRecord.fetch(burnRecord);
+    expect(record.burnId.toNumber()).toBe(burnId.toNumber());
+    expect(record.burnId.toNumber()).toBe(startNonce * 65536);
+  });
+
+  it("should accept a hex recipient and store it canonically", async () => {
+    const { svm, program } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+
+    const { burnShard, burnRecord } = await nextBurnAccounts();
+
+    const user = createFundedKeypair();
+    const userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(10_000_000));
+
+    // 0x0123...4567 is the EVM form of mirage1qy352euf40x77qfrg4ncn27dauqjx3t8laxec9
+    const hexRecipient = Buffer.from("0123456789abcdef0123456789abcdef01234567", "hex");
+
+    const ix = await program.methods
+      .burn({
+        mirageRecipient: { hex: { 0: Array.from(hexRecipient) } },
+        amount: new BN(10_000_000),
+      })
+      .accounts({
+        user: user.publicKey,
+        userTokenAccount,
+        tokenMint,
+        bridgeConfig,
+        burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, hexRecipient),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(ix);
+    tx.sign(user);
+
+    const result = svm.sendTransaction(tx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Burn failed: ${result.err().toString()}`);
+    }
 
-    // Verify burn record has unique ID
     const record = await program.account.burnRecord.fetch(burnRecord);
-    expect(record.burnId.toNumber()).toBe(startNonce);
+    expect(Buffer.from(record.mirageRecipient.hex[0]).equals(hexRecipient)).toBe(true);
   });
 
   it("should fail with invalid mirage recipient address", async () => {
@@ -203,9 +295,7 @@ describe("5. Burn", () => {
     const [bridgeConfig] = getBridgeConfigPDA();
     const [tokenMint] = getMintPDA();
 
-    const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    const burnNonce = configBefore.burnNonce;
-    const [burnRecord] = getBurnRecordPDA(burnNonce);
+    const { burnShard, burnRecord } = await nextBurnAccounts();
 
     const user = createFundedKeypair();
     const userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(100_000_000));
@@ -215,7 +305,7 @@ describe("5. Burn", () => {
 
     const ix = await program.methods
       .burn({
-        mirageRecipient: invalidRecipient,
+        mirageRecipient: { bech32: { 0: invalidRecipient } },
         amount: new BN(50_000_000),
       })
       .accounts({
@@ -224,6 +314,8 @@ describe("5. Burn", () => {
         tokenMint,
         bridgeConfig,
         burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, invalidRecipient),
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -244,9 +336,7 @@ describe("5. Burn", () => {
     const [bridgeConfig] = getBridgeConfigPDA();
     const [tokenMint] = getMintPDA();
 
-    const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    const burnNonce = configBefore.burnNonce;
-    const [burnRecord] = getBurnRecordPDA(burnNonce);
+    const { burnShard, burnRecord } = await nextBurnAccounts();
 
     const user = createFundedKeypair();
     const userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(100_000_000));
@@ -255,7 +345,7 @@ describe("5. Burn", () => {
 
     const ix = await program.methods
       .burn({
-        mirageRecipient,
+        mirageRecipient: { bech32: { 0: mirageRecipient } },
         amount: new BN(0),
       })
       .accounts({
@@ -264,6 +354,8 @@ describe("5. Burn", () => {
         tokenMint,
         bridgeConfig,
         burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, mirageRecipient),
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -286,13 +378,13 @@ describe("5. Burn", () => {
 
     // Verify current state - should be unpaused
     const configCheck = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configCheck.paused).toBe(false);
+    expect(configCheck.pausedFlags).toBe(0);
 
     // Pause the bridge - add compute budget to make tx unique
     const pauseIx = await program.methods
-      .pause()
+      .pause({ flags: PAUSE_ALL })
       .accounts({
-        authority: authority.publicKey,
+        pauser: authority.publicKey,
         bridgeConfig,
       })
       .instruction();
@@ -315,11 +407,10 @@ describe("5. Burn", () => {
 
     // Verify bridge is now paused
     const configAfterPause = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configAfterPause.paused).toBe(true);
+    expect(configAfterPause.pausedFlags).toBe(PAUSE_ALL);
 
     // Try to burn - should fail with BridgePaused error (6003)
-    const burnNonce = configAfterPause.burnNonce;
-    const [burnRecord] = getBurnRecordPDA(burnNonce);
+    const { burnShard, burnRecord } = await nextBurnAccounts();
 
     const user = createFundedKeypair();
     const userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(100_000_000));
@@ -328,7 +419,7 @@ describe("5. Burn", () => {
 
     const burnIx = await program.methods
       .burn({
-        mirageRecipient,
+        mirageRecipient: { bech32: { 0: mirageRecipient } },
         amount: new BN(50_000_000),
       })
       .accounts({
@@ -337,6 +428,8 @@ describe("5. Burn", () => {
         tokenMint,
         bridgeConfig,
         burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, mirageRecipient),
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -354,9 +447,9 @@ describe("5. Burn", () => {
 
     // Cleanup: Unpause for future tests - add compute budget to make unique
     const unpauseIx = await program.methods
-      .unpause()
+      .unpause({ flags: PAUSE_ALL })
       .accounts({
-        authority: authority.publicKey,
+        admin: authority.publicKey,
         bridgeConfig,
       })
       .instruction();
@@ -378,6 +471,162 @@ describe("5. Burn", () => {
 
     // Verify cleanup worked
     const configAfterCleanup = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configAfterCleanup.paused).toBe(false);
+    expect(configAfterCleanup.pausedFlags).toBe(0);
+  });
+
+  it("should keep burns open while only inbound operations are paused", async () => {
+    const { svm, program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+    const inbound = PAUSE_MINTS | PAUSE_ATTESTATIONS | PAUSE_FINALIZATION;
+
+    const pauseIx = await program.methods
+      .pause({ flags: inbound })
+      .accounts({
+        pauser: authority.publicKey,
+        bridgeConfig,
+      })
+      .instruction();
+
+    const pauseTx = new Transaction();
+    pauseTx.recentBlockhash = svm.latestBlockhash();
+    pauseTx.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }));
+    pauseTx.add(pauseIx);
+    pauseTx.sign(authority);
+    const pauseResult = svm.sendTransaction(pauseTx);
+    if (pauseResult instanceof FailedTransactionMetadata) {
+      throw new Error(`Pause failed: ${pauseResult.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pausedFlags).toBe(inbound);
+
+    const { burnShard, burnRecord } = await nextBurnAccounts();
+    const user = createFundedKeypair();
+    const userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(100_000_000));
+    const mirageRecipient = "mirage1qy352euf40x77qfrg4ncn27dauqjx3t8laxec9";
+
+    const burnIx = await program.methods
+      .burn({
+        mirageRecipient: { bech32: { 0: mirageRecipient } },
+        amount: new BN(50_000_000),
+      })
+      .accounts({
+        user: user.publicKey,
+        userTokenAccount,
+        tokenMint,
+        bridgeConfig,
+        burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(user.publicKey, mirageRecipient),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    const burnTx = new Transaction();
+    burnTx.recentBlockhash = svm.latestBlockhash();
+    burnTx.add(burnIx);
+    burnTx.sign(user);
+    const burnResult = svm.sendTransaction(burnTx);
+    if (burnResult instanceof FailedTransactionMetadata) {
+      throw new Error(`Burn failed: ${burnResult.err().toString()}`);
+    }
+
+    const unpauseIx = await program.methods
+      .unpause({ flags: inbound })
+      .accounts({
+        admin: authority.publicKey,
+        bridgeConfig,
+      })
+      .instruction();
+
+    const unpauseTx = new Transaction();
+    unpauseTx.recentBlockhash = svm.latestBlockhash();
+    unpauseTx.add(ComputeBudgetProgram.setComputeUnitLimit({ units: 200_003 }));
+    unpauseTx.add(unpauseIx);
+    unpauseTx.sign(authority);
+    const unpauseResult = svm.sendTransaction(unpauseTx);
+    if (unpauseResult instanceof FailedTransactionMetadata) {
+      throw new Error(`Unpause failed: ${unpauseResult.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pausedFlags).toBe(0);
+  });
+
+  async function delegatedBurnIx(signer: PublicKey, owner: PublicKey, tokenAccount: PublicKey, amount: number) {
+    const { program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+    const { burnShard, burnRecord } = await nextBurnAccounts();
+    const hexRecipient = Buffer.from("0123456789abcdef0123456789abcdef01234567", "hex");
+
+    return program.methods
+      .burn({
+        mirageRecipient: { hex: { 0: Array.from(hexRecipient) } },
+        amount: new BN(amount),
+      })
+      .accounts({
+        user: signer,
+        userTokenAccount: tokenAccount,
+        tokenMint,
+        bridgeConfig,
+        burnRecord,
+        burnShard,
+        ...getBurnDenylistShards(owner, hexRecipient, signer),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  function sendSigned(signer: Keypair, ix: TransactionInstruction) {
+    const { svm } = getTestContext();
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(ix);
+    tx.sign(signer);
+    return svm.sendTransaction(tx);
+  }
+
+  it("should let a delegate burn from a non-ATA account and record the owner", async () => {
+    const { program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+
+    const owner = Keypair.generate(); // e.g. a custodian's vault
+    const delegate = createFundedKeypair();
+    const vault = setupNonAtaTokenAccount(owner.publicKey, tokenMint, 20_000_000n, delegate.publicKey, 15_000_000n);
+    const { burnRecord } = await nextBurnAccounts();
+
+    const result = sendSigned(delegate, await delegatedBurnIx(delegate.publicKey, owner.publicKey, vault, 15_000_000));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Delegated burn failed: ${result.err().toString()}`);
+    }
+
+    expect(getTokenBalance(vault)).toBe(5_000_000n);
+    const record = await program.account.burnRecord.fetch(burnRecord);
+    expect(record.solanaSender.toBase58()).toBe(owner.publicKey.toBase58());
+    expect(record.callerProgram).toBeNull();
+  });
+
+  it("should reject a delegate burning more than approved", async () => {
+    const [tokenMint] = getMintPDA();
+
+    const owner = Keypair.generate();
+    const delegate = createFundedKeypair();
+    const vault = setupNonAtaTokenAccount(owner.publicKey, tokenMint, 20_000_000n, delegate.publicKey, 1_000_000n);
+
+    const result = sendSigned(delegate, await delegatedBurnIx(delegate.publicKey, owner.publicKey, vault, 2_000_000));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should reject a signer that is neither owner nor delegate", async () => {
+    const [tokenMint] = getMintPDA();
+
+    const owner = Keypair.generate();
+    const stranger = createFundedKeypair();
+    const vault = setupNonAtaTokenAccount(owner.publicKey, tokenMint, 20_000_000n);
+
+    const result = sendSigned(stranger, await delegatedBurnIx(stranger.publicKey, owner.publicKey, vault, 1_000_000));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
   });
 });
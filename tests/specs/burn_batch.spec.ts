@@ -0,0 +1,244 @@
+import { describe, expect, it } from "bun:test";
+import {
+  AccountMeta,
+  AddressLookupTableAccount,
+  AddressLookupTableProgram,
+  ComputeBudgetProgram,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionInstruction,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getMintPDA,
+  getBurnId,
+  getBurnRecordPDA,
+  nextBurnAccounts,
+  getBurnDenylistShards,
+  createFundedKeypair,
+  setupTokenAccount,
+  getTokenBalance,
+} from "../utils/helpers";
+import { send } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata, TransactionMetadata } from "litesvm";
+
+// Hard ceiling enforced by `set_max_burn_batch_size`
+const MAX_BURN_BATCH_SIZE = 25;
+
+// Lookup table holding every non-signer account of `ix`, written directly
+// so it is active without waiting for the extend to warm up
+function createLookupTable(ix: TransactionInstruction, signer: PublicKey): AddressLookupTableAccount {
+  const { svm } = getTestContext();
+  const addresses = [...new Set(ix.keys.map((k) => k.pubkey.toBase58()))]
+    .filter((key) => key !== signer.toBase58())
+    .map((key) => new PublicKey(key));
+
+  // LookupTableMeta (56 bytes): type, deactivation slot, last extended
+  // slot and start index, no authority; then the addresses
+  const data = Buffer.alloc(56 + addresses.length * 32);
+  data.writeUInt32LE(1, 0);
+  data.writeBigUInt64LE(0xffffffffffffffffn, 4);
+  addresses.forEach((address, i) => address.toBuffer().copy(data, 56 + i * 32));
+
+  const key = Keypair.generate().publicKey;
+  svm.setAccount(key, {
+    lamports: 1_000_000_000,
+    data,
+    owner: AddressLookupTableProgram.programId,
+    executable: false,
+  });
+  svm.warpToSlot(svm.getClock().slot + 1n);
+
+  return new AddressLookupTableAccount({
+    key,
+    state: {
+      deactivationSlot: BigInt("0xffffffffffffffff"),
+      lastExtendedSlot: 0,
+      lastExtendedSlotStartIndex: 0,
+      authority: undefined,
+      addresses,
+    },
+  });
+}
+
+async function setMaxBurnBatchSize(maxBurnBatchSize: number, units: number) {
+  const { program, authority } = getTestContext();
+  return send(
+    authority,
+    ComputeBudgetProgram.setComputeUnitLimit({ units }),
+    await program.methods
+      .setMaxBurnBatchSize({ maxBurnBatchSize })
+      .accounts({ feeManager: authority.publicKey, bridgeConfig: getBridgeConfigPDA()[0] })
+      .instruction()
+  );
+}
+
+function recipient(i: number): Buffer {
+  return Buffer.alloc(20, i + 1);
+}
+
+async function burnBatchIx(user: PublicKey, tokenAccount: PublicKey, amounts: number[], recordOffset = 0) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+  const { burnShard } = await nextBurnAccounts();
+  const shard = await program.account.burnShard.fetch(burnShard);
+
+  // Per entry: recipient denylist shard, then the burn record for the next ID
+  const remainingAccounts: AccountMeta[] = amounts.flatMap((_, i) => [
+    { pubkey: getBurnDenylistShards(user, recipient(i)).recipientDenylistShard, isSigner: false, isWritable: false },
+    {
+      pubkey: getBurnRecordPDA(getBurnId(0, shard.nextNonce.addn(i + recordOffset)))[0],
+      isSigner: false,
+      isWritable: true,
+    },
+  ]);
+
+  return program.methods
+    .burnBatch({
+      entries: amounts.map((amount, i) => ({
+        mirageRecipient: { hex: { 0: Array.from(recipient(i)) } },
+        amount: new BN(amount),
+      })),
+    })
+    .accounts({
+      user,
+      userTokenAccount: tokenAccount,
+      tokenMint,
+      bridgeConfig,
+      burnShard,
+      userDenylistShard: getBurnDenylistShards(user, recipient(0)).userDenylistShard,
+      signerDenylistShard: getBurnDenylistShards(user, recipient(0)).signerDenylistShard,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(remainingAccounts)
+    .instruction();
+}
+
+describe("16. Burn Batch", () => {
+  it("should burn once and record one transfer per entry", async () => {
+    const { program } = getTestContext();
+    const [tokenMint] = getMintPDA();
+    const user = createFundedKeypair();
+    const tokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(10_000_000));
+
+    const { burnShard, burnId: firstBurnId } = await nextBurnAccounts();
+    const amounts = [1_000_000, 2_000_000, 3_000_000];
+
+    const result = send(user, await burnBatchIx(user.publicKey, tokenAccount, amounts));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Burn batch failed: ${result.err().toString()}`);
+    }
+
+    expect(getTokenBalance(tokenAccount)).toBe(4_000_000n);
+
+    const shard = await program.account.burnShard.fetch(burnShard);
+    for (let i = 0; i < amounts.length; i++) {
+      const burnId = getBurnId(0, firstBurnId.shrn(16).addn(i));
+      const record = await program.account.burnRecord.fetch(getBurnRecordPDA(burnId)[0]);
+      expect(record.burnId.toString()).toBe(burnId.toString());
+      expect(record.amount.toNumber()).toBe(amounts[i]);
+      expect(record.solanaSender.toBase58()).toBe(user.publicKey.toBase58());
+      expect(Buffer.from(record.mirageRecipient.hex[0]).equals(recipient(i))).toBe(true);
+    }
+    expect(shard.nextNonce.toNumber()).toBe(firstBurnId.shrn(16).toNumber() + amounts.length);
+
+    // One BurnInitiated per entry
+    const events = (result as TransactionMetadata).logs().filter((log: string) => log.startsWith("Program data:"));
+    expect(events.length).toBe(amounts.length);
+  });
+
+  it("should reject a batch larger than the configured maximum", async () => {
+    const { program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+
+    const updated = await setMaxBurnBatchSize(2, 200_000);
+    if (updated instanceof FailedTransactionMetadata) {
+      throw new Error(`Set max burn batch size failed: ${updated.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).maxBurnBatchSize).toBe(2);
+
+    const user = createFundedKeypair();
+    const tokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(10_000_000));
+    const result = send(user, await burnBatchIx(user.publicKey, tokenAccount, [1_000_000, 1_000_000, 1_000_000]));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+
+    // Zero is not a valid maximum
+    expect((await setMaxBurnBatchSize(0, 200_001)) instanceof FailedTransactionMetadata).toBe(true);
+
+    const restored = await setMaxBurnBatchSize(10, 200_002);
+    if (restored instanceof FailedTransactionMetadata) {
+      throw new Error(`Set max burn batch size failed: ${restored.err().toString()}`);
+    }
+  });
+
+  it("should burn a batch at the hard ceiling in one transaction", async () => {
+    const { program, svm } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+
+    expect((await setMaxBurnBatchSize(MAX_BURN_BATCH_SIZE + 1, 200_003)) instanceof FailedTransactionMetadata).toBe(
+      true
+    );
+    const raised = await setMaxBurnBatchSize(MAX_BURN_BATCH_SIZE, 200_004);
+    if (raised instanceof FailedTransactionMetadata) {
+      throw new Error(`Set max burn batch size failed: ${raised.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).maxBurnBatchSize).toBe(MAX_BURN_BATCH_SIZE);
+
+    const user = createFundedKeypair();
+    const amounts = Array.from({ length: MAX_BURN_BATCH_SIZE }, () => 1_000_000);
+    const tokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(MAX_BURN_BATCH_SIZE * 1_000_000));
+    const ix = await burnBatchIx(user.publicKey, tokenAccount, amounts);
+    // Plus the bridge and compute budget program IDs
+    expect(new Set(ix.keys.map((k) => k.pubkey.toBase58())).size + 2).toBeLessThanOrEqual(64);
+
+    const message = new TransactionMessage({
+      payerKey: user.publicKey,
+      recentBlockhash: svm.latestBlockhash(),
+      instructions: [ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }), ix],
+    }).compileToV0Message([createLookupTable(ix, user.publicKey)]);
+    const tx = new VersionedTransaction(message);
+    tx.sign([user]);
+
+    const result = svm.sendTransaction(tx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Burn batch failed: ${result.err().toString()}`);
+    }
+    expect(getTokenBalance(tokenAccount)).toBe(0n);
+    const events = (result as TransactionMetadata).logs().filter((log: string) => log.startsWith("Program data:"));
+    expect(events.length).toBe(MAX_BURN_BATCH_SIZE);
+
+    const restored = await setMaxBurnBatchSize(10, 200_005);
+    if (restored instanceof FailedTransactionMetadata) {
+      throw new Error(`Set max burn batch size failed: ${restored.err().toString()}`);
+    }
+  });
+
+  it("should reject burn records that do not match the issued burn IDs", async () => {
+    const [tokenMint] = getMintPDA();
+    const user = createFundedKeypair();
+    const tokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(10_000_000));
+
+    const result = send(user, await burnBatchIx(user.publicKey, tokenAccount, [1_000_000, 1_000_000], 1));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect(getTokenBalance(tokenAccount)).toBe(10_000_000n);
+  });
+
+  it("should reject a batch above the user's balance", async () => {
+    const [tokenMint] = getMintPDA();
+    const user = createFundedKeypair();
+    const tokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(2_000_000));
+
+    const result = send(user, await burnBatchIx(user.publicKey, tokenAccount, [1_500_000, 1_500_000]));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+});
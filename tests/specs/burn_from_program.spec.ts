@@ -0,0 +1,129 @@
+import { describe, expect, it } from "bun:test";
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import { send } from "../utils/mint";
+import { BridgeCaller } from "../../target/types/bridge_caller";
+import CallerIDL from "../../target/idl/bridge_caller.json";
+import {
+  getBridgeConfigPDA,
+  getMintPDA,
+  nextBurnAccounts,
+  getBurnDenylistShards,
+  createFundedKeypair,
+  setupNonAtaTokenAccount,
+} from "../utils/helpers";
+import BN from "bn.js";
+import { FailedTransactionMetadata, TransactionMetadata } from "litesvm";
+
+const MIRAGE_RECIPIENT = Buffer.from("0123456789abcdef0123456789abcdef01234567", "hex");
+
+// A real caller is another program signing with invoke_signed, here the
+// bridge_caller example; a wallet must not be able to pose as one
+describe("15. Burn from Program", () => {
+  async function burnFromProgramIx(programSigner: PublicKey, callerProgram: PublicKey, signerSeeds: Buffer[]) {
+    const { program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+    const { burnShard, burnRecord } = await nextBurnAccounts();
+    const tokenAccount = setupNonAtaTokenAccount(programSigner, tokenMint, 5_000_000n);
+    const { userDenylistShard, signerDenylistShard, recipientDenylistShard } = getBurnDenylistShards(
+      programSigner,
+      MIRAGE_RECIPIENT,
+    );
+
+    return program.methods
+      .burnFromProgram({
+        mirageRecipient: { hex: { 0: Array.from(MIRAGE_RECIPIENT) } },
+        amount: new BN(1_000_000),
+        signerSeeds: signerSeeds,
+      })
+      .accounts({
+        programSigner,
+        payer: programSigner,
+        callerProgram,
+        tokenAccount,
+        tokenMint,
+        bridgeConfig,
+        burnRecord,
+        burnShard,
+        ownerDenylistShard: userDenylistShard,
+        signerDenylistShard,
+        recipientDenylistShard,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  it("should reject a wallet signer claiming to be a program PDA", async () => {
+    const { svm } = getTestContext();
+    const wallet = createFundedKeypair();
+
+    // Seeds of a genuine token-program PDA, but the signer is a wallet
+    const [, bump] = PublicKey.findProgramAddressSync([Buffer.from("vault")], TOKEN_PROGRAM_ID);
+
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(await burnFromProgramIx(wallet.publicKey, TOKEN_PROGRAM_ID, [Buffer.from("vault"), Buffer.from([bump])]));
+    tx.sign(wallet);
+
+    const result = svm.sendTransaction(tx);
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should record the calling program on a burn from its vault PDA", async () => {
+    const { program, provider } = getTestContext();
+    const caller = new Program<BridgeCaller>(CallerIDL as BridgeCaller, provider);
+    const [vault] = PublicKey.findProgramAddressSync([Buffer.from("vault")], caller.programId);
+    const payer = createFundedKeypair();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+    const { burnShard, burnRecord } = await nextBurnAccounts();
+    const tokenAccount = setupNonAtaTokenAccount(vault, tokenMint, 5_000_000n);
+    const { userDenylistShard, signerDenylistShard, recipientDenylistShard } = getBurnDenylistShards(
+      vault,
+      MIRAGE_RECIPIENT,
+    );
+
+    const ix = await caller.methods
+      .bridgeFromVault({ hex: { 0: Array.from(MIRAGE_RECIPIENT) } }, new BN(1_000_000))
+      .accounts({
+        vault,
+        payer: payer.publicKey,
+        callerProgram: caller.programId,
+        tokenAccount,
+        tokenMint,
+        bridgeConfig,
+        burnShard,
+        burnRecord,
+        ownerDenylistShard: userDenylistShard,
+        signerDenylistShard,
+        recipientDenylistShard,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        bridgeProgram: program.programId,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    const result = send(payer, ix);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Transaction failed: ${result.meta().logs().join("\n")}`);
+    }
+
+    const record = await program.account.burnRecord.fetch(burnRecord);
+    expect(record.callerProgram?.toBase58()).toBe(caller.programId.toBase58());
+    expect(record.solanaSender.toBase58()).toBe(vault.toBase58());
+    expect(record.amount.toNumber()).toBe(1_000_000);
+
+    const events = (result as TransactionMetadata)
+      .logs()
+      .filter((log: string) => log.startsWith("Program data: "))
+      .map((log: string) => program.coder.events.decode(log.slice("Program data: ".length)))
+      .filter((event) => event !== null);
+    expect(events.length).toBe(1);
+    expect(events[0]!.data.callerProgram.toBase58()).toBe(caller.programId.toBase58());
+  });
+});
@@ -0,0 +1,206 @@
+import { describe, expect, it } from "bun:test";
+import { Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, createApproveInstruction, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getMintPDA,
+  nextBurnAccounts,
+  getPermitNoncePDA,
+  getBurnDenylistShards,
+  getDenylistShardPDA,
+  solanaDenylistKey,
+  createFundedKeypair,
+  setupTokenAccount,
+  getTokenBalance,
+} from "../utils/helpers";
+import { signAttestation, MIRAGE_CHAIN_ID } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+const INSTRUCTIONS_SYSVAR = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+const MIRAGE_RECIPIENT = Buffer.from("0123456789abcdef0123456789abcdef01234567", "hex");
+
+interface Intent {
+  amount: BN;
+  nonce: BN;
+  expiry: BN;
+}
+
+function buildBurnIntentPayload(user: PublicKey, intent: Intent, mirageChainId = MIRAGE_CHAIN_ID): Buffer {
+  const { program } = getTestContext();
+  const chainLen = Buffer.alloc(4);
+  chainLen.writeUInt32LE(mirageChainId.length);
+  const recipientLen = Buffer.alloc(4);
+  recipientLen.writeUInt32LE(MIRAGE_RECIPIENT.length);
+
+  return Buffer.concat([
+    Buffer.from("mirage-bridge:burn-intent"),
+    program.programId.toBuffer(),
+    chainLen,
+    Buffer.from(mirageChainId),
+    user.toBuffer(),
+    recipientLen,
+    MIRAGE_RECIPIENT,
+    intent.amount.toArrayLike(Buffer, "le", 8),
+    intent.nonce.toArrayLike(Buffer, "le", 8),
+    intent.expiry.toTwos(64).toArrayLike(Buffer, "le", 8),
+  ]);
+}
+
+async function burnWithPermitIx(relayer: PublicKey, user: PublicKey, intent: Intent) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+  const { burnShard, burnRecord } = await nextBurnAccounts();
+  const userTokenAccount = getAssociatedTokenAddressSync(tokenMint, user, true);
+
+  return program.methods
+    .burnWithPermit({
+      mirageRecipient: { hex: { 0: Array.from(MIRAGE_RECIPIENT) } },
+      amount: intent.amount,
+      nonce: intent.nonce,
+      expiry: intent.expiry,
+    })
+    .accounts({
+      relayer,
+      user,
+      userTokenAccount,
+      tokenMint,
+      bridgeConfig,
+      burnRecord,
+      burnShard,
+      permitNonce: getPermitNoncePDA(user)[0],
+      userDenylistShard: getBurnDenylistShards(user, MIRAGE_RECIPIENT).userDenylistShard,
+      relayerDenylistShard: getDenylistShardPDA(solanaDenylistKey(relayer))[0],
+      recipientDenylistShard: getBurnDenylistShards(user, MIRAGE_RECIPIENT).recipientDenylistShard,
+      instructionsSysvar: INSTRUCTIONS_SYSVAR,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+// Relayer pays the fee; `cosigners` sign without spending SOL
+function sendAsRelayer(relayer: Keypair, cosigners: Keypair[], ...ixs: TransactionInstruction[]) {
+  const { svm } = getTestContext();
+  const tx = new Transaction();
+  tx.recentBlockhash = svm.latestBlockhash();
+  tx.feePayer = relayer.publicKey;
+  tx.add(...ixs);
+  tx.sign(relayer, ...cosigners);
+  return svm.sendTransaction(tx);
+}
+
+describe("14. Burn with Permit", () => {
+  const user = Keypair.generate(); // Holds no SOL
+  const relayer = createFundedKeypair();
+  let userTokenAccount: PublicKey;
+
+  function intent(nonce: number, amount = 2_000_000): Intent {
+    const { svm } = getTestContext();
+    return {
+      amount: new BN(amount),
+      nonce: new BN(nonce),
+      expiry: new BN(Number(svm.getClock().unixTimestamp) + 600),
+    };
+  }
+
+  it("should approve the bridge as delegate without the user paying", async () => {
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+    userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(10_000_000));
+
+    const result = sendAsRelayer(
+      relayer,
+      [user],
+      createApproveInstruction(userTokenAccount, bridgeConfig, user.publicKey, 10_000_000)
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Approve failed: ${result.err().toString()}`);
+    }
+  });
+
+  it("should burn from a signed intent submitted by a relayer", async () => {
+    const { program, svm } = getTestContext();
+    const { burnRecord } = await nextBurnAccounts();
+
+    const first = intent(0);
+    const result = sendAsRelayer(
+      relayer,
+      [],
+      signAttestation(user, buildBurnIntentPayload(user.publicKey, first)),
+      await burnWithPermitIx(relayer.publicKey, user.publicKey, first)
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Burn with permit failed: ${result.err().toString()}`);
+    }
+
+    expect(getTokenBalance(userTokenAccount)).toBe(8_000_000n);
+    expect(svm.getBalance(user.publicKey) ?? 0n).toBe(0n);
+
+    const record = await program.account.burnRecord.fetch(burnRecord);
+    expect(record.solanaSender.toBase58()).toBe(user.publicKey.toBase58());
+    expect(record.amount.toNumber()).toBe(2_000_000);
+
+    const nonce = await program.account.permitNonce.fetch(getPermitNoncePDA(user.publicKey)[0]);
+    expect(nonce.nextNonce.toNumber()).toBe(1);
+  });
+
+  it("should not replay a used intent", async () => {
+    const used = { ...intent(0), expiry: intent(0).expiry.addn(1) }; // Differs from the first tx
+    const result = sendAsRelayer(
+      relayer,
+      [],
+      signAttestation(user, buildBurnIntentPayload(user.publicKey, used)),
+      await burnWithPermitIx(relayer.publicKey, user.publicKey, used)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should reject an intent signed by someone else", async () => {
+    const next = intent(1);
+    const result = sendAsRelayer(
+      relayer,
+      [],
+      signAttestation(Keypair.generate(), buildBurnIntentPayload(user.publicKey, next)),
+      await burnWithPermitIx(relayer.publicKey, user.publicKey, next)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should reject a relayer changing the signed amount", async () => {
+    const signed = intent(1);
+    const result = sendAsRelayer(
+      relayer,
+      [],
+      signAttestation(user, buildBurnIntentPayload(user.publicKey, signed)),
+      await burnWithPermitIx(relayer.publicKey, user.publicKey, { ...signed, amount: new BN(8_000_000) })
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should reject an expired intent", async () => {
+    const { svm } = getTestContext();
+    const expired = { ...intent(1), expiry: new BN(Number(svm.getClock().unixTimestamp) - 1) };
+    const result = sendAsRelayer(
+      relayer,
+      [],
+      signAttestation(user, buildBurnIntentPayload(user.publicKey, expired)),
+      await burnWithPermitIx(relayer.publicKey, user.publicKey, expired)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should reject an intent signed for another Mirage chain", async () => {
+    const signed = intent(1);
+    const result = sendAsRelayer(
+      relayer,
+      [],
+      signAttestation(user, buildBurnIntentPayload(user.publicKey, signed, "mirage-testnet")),
+      await burnWithPermitIx(relayer.publicKey, user.publicKey, signed)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("InvalidPermitSignature");
+  });
+});
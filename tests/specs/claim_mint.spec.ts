@@ -0,0 +1,253 @@
+import { describe, expect, it } from "bun:test";
+import { Keypair, PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
+import { ACCOUNT_SIZE, AccountLayout, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getMintPDA,
+  createFundedKeypair,
+  getTokenBalance,
+  getDenylistShardPDA,
+  solanaDenylistKey,
+} from "../utils/helpers";
+import { newTransfer, signAttestation, send, setValidators, attestIx, finalizeIx, Transfer } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+const CLAIM_EXPIRY = 3600;
+
+// Empty token account at a non-ATA address
+function createTokenAccount(owner: PublicKey): PublicKey {
+  const { svm } = getTestContext();
+  const [tokenMint] = getMintPDA();
+  const address = Keypair.generate().publicKey;
+  const data = Buffer.alloc(ACCOUNT_SIZE);
+
+  AccountLayout.encode(
+    {
+      mint: tokenMint,
+      owner,
+      amount: 0n,
+      delegateOption: 0,
+      delegate: PublicKey.default,
+      delegatedAmount: 0n,
+      state: 1,
+      isNativeOption: 0,
+      isNative: 0n,
+      closeAuthorityOption: 0,
+      closeAuthority: PublicKey.default,
+    },
+    data
+  );
+
+  svm.setAccount(address, {
+    lamports: 1_000_000_000,
+    data,
+    owner: TOKEN_PROGRAM_ID,
+    executable: false,
+  });
+
+  return address;
+}
+
+async function setClaimConfig(claimMode: boolean, claimExpiry: number, sweepAddress: PublicKey) {
+  const { program, authority } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+
+  const ix = await program.methods
+    .setClaimConfig({
+      claimMode,
+      claimExpiry: new BN(claimExpiry),
+      unclaimedSweepAddress: sweepAddress,
+    })
+    .accounts({
+      admin: authority.publicKey,
+      bridgeConfig,
+    })
+    .instruction();
+
+  return send(authority, ix);
+}
+
+async function claimIx(recipient: PublicKey, transfer: Transfer, tokenAccount: PublicKey, mintRecordPayer: PublicKey) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+
+  return program.methods
+    .claimMint({ burnTxHash: Array.from(transfer.burnTxHash) })
+    .accounts({
+      recipient,
+      mintRecordPayer,
+      recipientTokenAccount: tokenAccount,
+      tokenMint,
+      bridgeConfig,
+      mintRecord: transfer.mintRecord,
+      gasReserve: null,
+      recipientDenylistShard: getDenylistShardPDA(solanaDenylistKey(recipient))[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+async function sweepIx(transfer: Transfer, sweepTokenAccount: PublicKey, mintRecordPayer: PublicKey) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+
+  return program.methods
+    .sweepUnclaimedMint({ burnTxHash: Array.from(transfer.burnTxHash) })
+    .accounts({
+      mintRecordPayer,
+      sweepTokenAccount,
+      tokenMint,
+      bridgeConfig,
+      mintRecord: transfer.mintRecord,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+describe("9. Claim Mint", () => {
+  const orchestrator = Keypair.generate();
+  const sweeper = Keypair.generate();
+
+  // Once the record is closed, the same signature must not start a new one
+  async function expectNoReattest(transfer: Transfer, relayer: Keypair) {
+    const { svm } = getTestContext();
+    const result = send(
+      relayer,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }),
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, transfer)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("AlreadyMinted");
+    expect(svm.getAccount(transfer.mintRecord)).toBeNull();
+  }
+
+  // Attests and finalizes a transfer so it becomes claimable
+  async function makeClaimable(transfer: Transfer, relayer: Keypair) {
+    const result = send(
+      relayer,
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, transfer),
+      await finalizeIx(relayer.publicKey, transfer, relayer.publicKey, false)
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Finalize failed: ${result.err().toString()}`);
+    }
+  }
+
+  it("should reject claim mode without an expiry", async () => {
+    const result = await setClaimConfig(true, 0, sweeper.publicKey);
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should enable claim mode", async () => {
+    const { program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    await setValidators([orchestrator], 10000, "claim");
+
+    const result = await setClaimConfig(true, CLAIM_EXPIRY, sweeper.publicKey);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Set claim config failed: ${result.err().toString()}`);
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.claimMode).toBe(true);
+    expect(config.claimExpiry.toNumber()).toBe(CLAIM_EXPIRY);
+    expect(config.unclaimedSweepAddress.toBase58()).toBe(sweeper.publicKey.toBase58());
+  });
+
+  it("should make the mint claimable instead of delivering it", async () => {
+    const { svm, program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+
+    const relayer = createFundedKeypair();
+    const recipient = createFundedKeypair();
+    const transfer = newTransfer(new BN(4_000_000), "mirage1claim", recipient.publicKey);
+    const mintedBefore = (await program.account.bridgeConfig.fetch(bridgeConfig)).totalMinted.toNumber();
+
+    await makeClaimable(transfer, relayer);
+
+    // No ATA was created and nothing was minted yet
+    expect(svm.getAccount(getAssociatedTokenAddressSync(tokenMint, recipient.publicKey, true))).toBeNull();
+    const record = await program.account.mintRecord.fetch(transfer.mintRecord);
+    expect(record.claimExpiresAt.toNumber()).toBeGreaterThan(0);
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).totalMinted.toNumber()).toBe(mintedBefore);
+
+    // Only the recipient can claim
+    const outsider = createFundedKeypair();
+    const outsiderAccount = createTokenAccount(outsider.publicKey);
+    const stolen = send(outsider, await claimIx(outsider.publicKey, transfer, outsiderAccount, relayer.publicKey));
+    expect(stolen instanceof FailedTransactionMetadata).toBe(true);
+
+    // Any token account the recipient owns will do
+    const tokenAccount = createTokenAccount(recipient.publicKey);
+    const result = send(recipient, await claimIx(recipient.publicKey, transfer, tokenAccount, relayer.publicKey));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Claim failed: ${result.err().toString()}`);
+    }
+
+    expect(getTokenBalance(tokenAccount)).toBe(4_000_000n);
+    expect(svm.getAccount(transfer.mintRecord)).toBeNull();
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).totalMinted.toNumber()).toBe(
+      mintedBefore + 4_000_000
+    );
+
+    await expectNoReattest(transfer, relayer);
+  });
+
+  it("should sweep an expired claim to the configured address", async () => {
+    const { svm } = getTestContext();
+
+    const relayer = createFundedKeypair();
+    const recipient = createFundedKeypair();
+    const transfer = newTransfer(new BN(2_500_000), "mirage1sweep", recipient.publicKey);
+    const sweepAccount = createTokenAccount(sweeper.publicKey);
+
+    await makeClaimable(transfer, relayer);
+
+    // Too early to sweep
+    const early = send(relayer, await sweepIx(transfer, sweepAccount, relayer.publicKey));
+    expect(early instanceof FailedTransactionMetadata).toBe(true);
+
+    const clock = svm.getClock();
+    clock.unixTimestamp += BigInt(CLAIM_EXPIRY + 1);
+    svm.setClock(clock);
+
+    // Too late to claim
+    const tokenAccount = createTokenAccount(recipient.publicKey);
+    const late = send(recipient, await claimIx(recipient.publicKey, transfer, tokenAccount, relayer.publicKey));
+    expect(late instanceof FailedTransactionMetadata).toBe(true);
+
+    // Sweeping into an account the sweep address does not own is rejected
+    const wrongAccount = createTokenAccount(recipient.publicKey);
+    const wrong = send(relayer, await sweepIx(transfer, wrongAccount, relayer.publicKey));
+    expect(wrong instanceof FailedTransactionMetadata).toBe(true);
+
+    const result = send(
+      relayer,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await sweepIx(transfer, sweepAccount, relayer.publicKey)
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Sweep failed: ${result.err().toString()}`);
+    }
+
+    expect(getTokenBalance(sweepAccount)).toBe(2_500_000n);
+    expect(svm.getAccount(transfer.mintRecord)).toBeNull();
+
+    await expectNoReattest(transfer, relayer);
+  });
+
+  it("should disable claim mode", async () => {
+    const result = await setClaimConfig(false, 0, PublicKey.default);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Set claim config failed: ${result.err().toString()}`);
+    }
+  });
+});
@@ -0,0 +1,264 @@
+import { describe, expect, it } from "bun:test";
+import { Keypair, PublicKey, SystemProgram, ComputeBudgetProgram } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getMintPDA,
+  nextBurnAccounts,
+  getBridgeRolesPDA,
+  getDenylistShardPDA,
+  solanaDenylistKey,
+  mirageDenylistKey,
+  getBurnDenylistShards,
+  roleIx,
+  createFundedKeypair,
+  setupTokenAccount,
+  setupNonAtaTokenAccount,
+  getTokenBalance,
+} from "../utils/helpers";
+import { newTransfer, signAttestation, send, setValidators, attestIx, finalizeIx } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+type DenylistAddress = { solana: { 0: PublicKey } } | { mirage: { 0: any } };
+
+async function denylistIx(
+  method: "addToDenylist" | "removeFromDenylist",
+  complianceAuthority: PublicKey,
+  address: DenylistAddress,
+  key: Uint8Array
+) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [denylistShard] = getDenylistShardPDA(key);
+
+  return program.methods[method]({ address, shard: key[0] })
+    .accounts({
+      complianceAuthority,
+      bridgeConfig,
+      bridgeRoles: getBridgeRolesPDA()[0],
+      denylistShard,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+// `user` signs; it burns from `owner`'s account as a delegate when they differ
+async function burnIx(
+  user: PublicKey,
+  mirageRecipient: Buffer,
+  owner: PublicKey = user,
+  userTokenAccount?: PublicKey
+) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+  const { burnShard, burnRecord } = await nextBurnAccounts();
+
+  return program.methods
+    .burn({
+      mirageRecipient: { hex: { 0: Array.from(mirageRecipient) } },
+      amount: new BN(1_000_000),
+    })
+    .accounts({
+      user,
+      userTokenAccount: userTokenAccount ?? getAssociatedTokenAddressSync(tokenMint, owner, true),
+      tokenMint,
+      bridgeConfig,
+      burnRecord,
+      burnShard,
+      ...getBurnDenylistShards(owner, mirageRecipient, user),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+describe("11. Denylist", () => {
+  const orchestrator = Keypair.generate();
+  const sanctioned = createFundedKeypair();
+  const sanctionedMirage = Buffer.from("00112233445566778899aabbccddeeff00112233", "hex");
+
+  it("should only let compliance role holders edit the denylist", async () => {
+    const outsider = createFundedKeypair();
+    const key = solanaDenylistKey(sanctioned.publicKey);
+
+    const result = send(
+      outsider,
+      await denylistIx("addToDenylist", outsider.publicKey, { solana: { 0: sanctioned.publicKey } }, key)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should reject an address submitted for the wrong shard", async () => {
+    const { authority } = getTestContext();
+    const key = solanaDenylistKey(sanctioned.publicKey);
+    const wrongKey = Uint8Array.from([(key[0] + 1) % 256]);
+
+    const result = send(
+      authority,
+      await denylistIx("addToDenylist", authority.publicKey, { solana: { 0: sanctioned.publicKey } }, wrongKey)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should add Solana and Mirage addresses", async () => {
+    const { program, authority } = getTestContext();
+    const solanaKey = solanaDenylistKey(sanctioned.publicKey);
+    const mirageKey = mirageDenylistKey(sanctionedMirage);
+
+    const result = send(
+      authority,
+      await denylistIx("addToDenylist", authority.publicKey, { solana: { 0: sanctioned.publicKey } }, solanaKey),
+      await denylistIx(
+        "addToDenylist",
+        authority.publicKey,
+        { mirage: { 0: { hex: { 0: Array.from(sanctionedMirage) } } } },
+        mirageKey
+      )
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Add to denylist failed: ${result.err().toString()}`);
+    }
+
+    const shard = await program.account.denylistShard.fetch(getDenylistShardPDA(solanaKey)[0]);
+    expect(shard.shard).toBe(solanaKey[0]);
+    expect(shard.entries.some((e: number[]) => Buffer.from(e).equals(Buffer.from(solanaKey)))).toBe(true);
+
+    // Adding the same address twice is rejected
+    const duplicate = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await denylistIx("addToDenylist", authority.publicKey, { solana: { 0: sanctioned.publicKey } }, solanaKey)
+    );
+    expect(duplicate instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should block burns from a denylisted wallet", async () => {
+    const [tokenMint] = getMintPDA();
+    setupTokenAccount(sanctioned.publicKey, tokenMint, BigInt(1_000_000));
+
+    const result = send(
+      sanctioned,
+      await burnIx(sanctioned.publicKey, Buffer.from("0123456789abcdef0123456789abcdef01234567", "hex"))
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should block burns signed by a denylisted delegate", async () => {
+    const [tokenMint] = getMintPDA();
+    const owner = Keypair.generate();
+    const vault = setupNonAtaTokenAccount(owner.publicKey, tokenMint, 1_000_000n, sanctioned.publicKey, 1_000_000n);
+
+    const result = send(
+      sanctioned,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await burnIx(
+        sanctioned.publicKey,
+        Buffer.from("0123456789abcdef0123456789abcdef01234567", "hex"),
+        owner.publicKey,
+        vault
+      )
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("AddressDenylisted");
+    expect(getTokenBalance(vault)).toBe(1_000_000n);
+  });
+
+  it("should block burns to a denylisted Mirage recipient", async () => {
+    const [tokenMint] = getMintPDA();
+    const user = createFundedKeypair();
+    setupTokenAccount(user.publicKey, tokenMint, BigInt(1_000_000));
+
+    const result = send(user, await burnIx(user.publicKey, sanctionedMirage));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should park a mint to a denylisted recipient and release it once delisted", async () => {
+    const { program, authority } = getTestContext();
+    const [tokenMint] = getMintPDA();
+
+    await setValidators([orchestrator], 10000, "denylist");
+
+    const relayer = createFundedKeypair();
+    const transfer = newTransfer(new BN(3_000_000), "mirage1denylist", sanctioned.publicKey);
+    const recipientAta = getAssociatedTokenAddressSync(tokenMint, sanctioned.publicKey, true);
+
+    // The attestation itself still succeeds; finalization parks the record
+    const parked = send(
+      relayer,
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, transfer),
+      await finalizeIx(relayer.publicKey, transfer, relayer.publicKey, false)
+    );
+    if (parked instanceof FailedTransactionMetadata) {
+      throw new Error(`Finalize failed: ${parked.err().toString()}`);
+    }
+
+    const record = await program.account.mintRecord.fetch(transfer.mintRecord);
+    expect(record.parked).toBe(true);
+    expect(getTokenBalance(recipientAta)).toBe(1_000_000n); // Only the balance set up for the burn test
+
+    // Still denylisted
+    const early = send(relayer, await finalizeIx(relayer.publicKey, transfer, relayer.publicKey));
+    expect(early instanceof FailedTransactionMetadata).toBe(true);
+
+    const removed = send(
+      authority,
+      await denylistIx(
+        "removeFromDenylist",
+        authority.publicKey,
+        { solana: { 0: sanctioned.publicKey } },
+        solanaDenylistKey(sanctioned.publicKey)
+      )
+    );
+    if (removed instanceof FailedTransactionMetadata) {
+      throw new Error(`Remove from denylist failed: ${removed.err().toString()}`);
+    }
+
+    const released = send(
+      relayer,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await finalizeIx(relayer.publicKey, transfer, relayer.publicKey)
+    );
+    if (released instanceof FailedTransactionMetadata) {
+      throw new Error(`Release failed: ${released.err().toString()}`);
+    }
+
+    expect(getTokenBalance(recipientAta)).toBe(4_000_000n);
+  });
+
+  it("should let a compliance role holder edit the denylist until revoked", async () => {
+    const { authority } = getTestContext();
+    const compliance = createFundedKeypair();
+
+    const granted = send(authority, await roleIx("grantRole", authority.publicKey, compliance.publicKey, "compliance"));
+    if (granted instanceof FailedTransactionMetadata) {
+      throw new Error(`Grant role failed: ${granted.err().toString()}`);
+    }
+
+    // The role holder can remove the Mirage entry
+    const mirageAddress = { mirage: { 0: { hex: { 0: Array.from(sanctionedMirage) } } } };
+    const mirageKey = mirageDenylistKey(sanctionedMirage);
+
+    const byCompliance = send(
+      compliance,
+      await denylistIx("removeFromDenylist", compliance.publicKey, mirageAddress, mirageKey)
+    );
+    if (byCompliance instanceof FailedTransactionMetadata) {
+      throw new Error(`Remove from denylist failed: ${byCompliance.err().toString()}`);
+    }
+
+    const revoked = send(authority, await roleIx("revokeRole", authority.publicKey, compliance.publicKey, "compliance"));
+    if (revoked instanceof FailedTransactionMetadata) {
+      throw new Error(`Revoke role failed: ${revoked.err().toString()}`);
+    }
+
+    const afterRevoke = send(
+      compliance,
+      await denylistIx("addToDenylist", compliance.publicKey, mirageAddress, mirageKey)
+    );
+    expect(afterRevoke instanceof FailedTransactionMetadata).toBe(true);
+  });
+});
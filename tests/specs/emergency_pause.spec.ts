@@ -0,0 +1,195 @@
+import { describe, expect, it } from "bun:test";
+import { ComputeBudgetProgram, Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getBridgeRolesPDA,
+  getProgramId,
+  getValidatorRegistryPDA,
+  createFundedKeypair,
+  roleIx,
+  PAUSE_ALL,
+  PAUSE_MINTS,
+} from "../utils/helpers";
+import { send, setValidators, signAttestation } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+const INSTRUCTIONS_SYSVAR = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+
+function getPauseSignaturesPDA(flags: number): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("pause_signatures"), Buffer.from([flags])], getProgramId());
+}
+
+function buildEmergencyPausePayload(chainId: string, pauseNonce: BN, flags: number): Buffer {
+  const chainLen = Buffer.alloc(4);
+  chainLen.writeUInt32LE(chainId.length, 0);
+  return Buffer.concat([
+    Buffer.from("mirage-bridge:emergency-pause"),
+    getProgramId().toBuffer(),
+    chainLen,
+    Buffer.from(chainId),
+    pauseNonce.toArrayLike(Buffer, "le", 8),
+    Buffer.from([flags]),
+  ]);
+}
+
+async function emergencyPauseIx(payer: PublicKey, flags: number, pauseSignaturesPayer: PublicKey = payer) {
+  const { program } = getTestContext();
+  return program.methods
+    .emergencyPause({ flags })
+    .accounts({
+      payer,
+      bridgeConfig: getBridgeConfigPDA()[0],
+      validatorRegistry: getValidatorRegistryPDA()[0],
+      pauseSignatures: getPauseSignaturesPDA(flags)[0],
+      pauseSignaturesPayer,
+      instructionsSysvar: INSTRUCTIONS_SYSVAR,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+async function setThresholdIx(admin: PublicKey, emergencyPauseThreshold: number) {
+  const { program } = getTestContext();
+  return program.methods
+    .setEmergencyPauseThreshold({ emergencyPauseThreshold: new BN(emergencyPauseThreshold) })
+    .accounts({ admin, bridgeConfig: getBridgeConfigPDA()[0], bridgeRoles: getBridgeRolesPDA()[0] })
+    .instruction();
+}
+
+async function unpauseIx(admin: PublicKey, flags: number) {
+  const { program } = getTestContext();
+  return program.methods
+    .unpause({ flags })
+    .accounts({ admin, bridgeConfig: getBridgeConfigPDA()[0] })
+    .instruction();
+}
+
+function expectOk(result: ReturnType<typeof send>, label: string) {
+  if (result instanceof FailedTransactionMetadata) {
+    throw new Error(`${label} failed: ${result.err().toString()}`);
+  }
+}
+
+describe("21. Emergency Pause", () => {
+  const validators = [Keypair.generate(), Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+  it("should be off until an admin sets a threshold", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    await setValidators(validators, 100, "emergency");
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    const payload = buildEmergencyPausePayload(config.mirageChainId, config.emergencyPauseNonce, PAUSE_MINTS);
+    const result = send(
+      authority,
+      signAttestation(validators[0], payload),
+      signAttestation(validators[1], payload),
+      await emergencyPauseIx(authority.publicKey, PAUSE_MINTS)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+    expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("EmergencyPauseDisabled");
+  });
+
+  it("should only let an admin set a threshold no higher than the attestation threshold", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const outsider = createFundedKeypair();
+    expect(send(outsider, await setThresholdIx(outsider.publicKey, 3000)) instanceof FailedTransactionMetadata).toBe(true);
+
+    const { attestationThreshold } = await program.account.bridgeConfig.fetch(bridgeConfig);
+    const tooHigh = send(authority, await setThresholdIx(authority.publicKey, attestationThreshold.toNumber() + 1));
+    expect(tooHigh instanceof FailedTransactionMetadata).toBe(true);
+
+    expectOk(send(authority, await setThresholdIx(authority.publicKey, 3000)), "Set threshold");
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.emergencyPauseThreshold.toNumber()).toBe(3000);
+  });
+
+  it("should pause once signatures from 30% of the stake accumulate", async () => {
+    const { program, svm } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const relayer = createFundedKeypair();
+    const otherRelayer = createFundedKeypair();
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    const pauseNonce = config.emergencyPauseNonce;
+    const payload = buildEmergencyPausePayload(config.mirageChainId, pauseNonce, PAUSE_MINTS);
+
+    // 100 of 400 stake is below 30%
+    expectOk(
+      send(relayer, signAttestation(validators[0], payload), await emergencyPauseIx(relayer.publicKey, PAUSE_MINTS)),
+      "First signature"
+    );
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pausedFlags).toBe(0);
+
+    // Another relayer adds a second signature; the creator gets the rent back
+    expectOk(
+      send(
+        otherRelayer,
+        signAttestation(validators[1], payload),
+        await emergencyPauseIx(otherRelayer.publicKey, PAUSE_MINTS, relayer.publicKey)
+      ),
+      "Second signature"
+    );
+
+    const after = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(after.pausedFlags).toBe(PAUSE_MINTS);
+    expect(after.emergencyPauseNonce.toString()).toBe(pauseNonce.add(new BN(1)).toString());
+    expect(svm.getAccount(getPauseSignaturesPDA(PAUSE_MINTS)[0])).toBeNull();
+  });
+
+  it("should not let used pause messages re-pause after an unpause", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    expectOk(send(authority, await unpauseIx(authority.publicKey, PAUSE_ALL)), "Unpause");
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    const stalePayload = buildEmergencyPausePayload(
+      config.mirageChainId,
+      config.emergencyPauseNonce.sub(new BN(1)),
+      PAUSE_MINTS
+    );
+    const relayer = createFundedKeypair();
+    expectOk(
+      send(
+        relayer,
+        signAttestation(validators[0], stalePayload),
+        signAttestation(validators[1], stalePayload),
+        await emergencyPauseIx(relayer.publicKey, PAUSE_MINTS)
+      ),
+      "Replay"
+    );
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pausedFlags).toBe(0);
+  });
+
+  it("should let a guardian pause but not unpause", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const guardian = createFundedKeypair();
+
+    expectOk(send(authority, await roleIx("grantRole", authority.publicKey, guardian.publicKey, "pauser")), "Grant");
+
+    const pauseIx = await program.methods
+      .pause({ flags: PAUSE_ALL })
+      .accounts({ pauser: guardian.publicKey, bridgeConfig })
+      .instruction();
+    expectOk(send(guardian, pauseIx), "Guardian pause");
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pausedFlags).toBe(PAUSE_ALL);
+
+    expect(send(guardian, await unpauseIx(guardian.publicKey, PAUSE_ALL)) instanceof FailedTransactionMetadata).toBe(true);
+
+    expectOk(
+      send(
+        authority,
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+        await unpauseIx(authority.publicKey, PAUSE_ALL)
+      ),
+      "Unpause"
+    );
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pausedFlags).toBe(0);
+  });
+});
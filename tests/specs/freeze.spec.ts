@@ -0,0 +1,116 @@
+import { describe, expect, it } from "bun:test";
+import { PublicKey, ComputeBudgetProgram } from "@solana/web3.js";
+import { AccountLayout, AccountState, MintLayout, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getBridgeRolesPDA,
+  getMintPDA,
+  createFundedKeypair,
+  setupTokenAccount,
+  roleIx,
+} from "../utils/helpers";
+import { send } from "../utils/mint";
+import { FailedTransactionMetadata } from "litesvm";
+
+async function freezeIx(method: "freezeAccount" | "thawAccount", complianceAuthority: PublicKey, tokenAccount: PublicKey) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+
+  return program.methods[method]()
+    .accounts({
+      complianceAuthority,
+      bridgeConfig,
+      bridgeRoles: getBridgeRolesPDA()[0],
+      tokenMint,
+      tokenAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction();
+}
+
+function tokenAccountState(tokenAccount: PublicKey): AccountState {
+  const { svm } = getTestContext();
+  return AccountLayout.decode(Buffer.from(svm.getAccount(tokenAccount)!.data)).state;
+}
+
+describe("12. Freeze", () => {
+  const compliance = createFundedKeypair();
+
+  it("should hold the mint's freeze authority in the bridge config", async () => {
+    const { svm, program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+
+    const mint = MintLayout.decode(Buffer.from(svm.getAccount(tokenMint)!.data));
+    expect(mint.freezeAuthorityOption).toBe(1);
+    expect(mint.freezeAuthority.toBase58()).toBe(bridgeConfig.toBase58());
+
+    const result = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }),
+      await roleIx("grantRole", authority.publicKey, compliance.publicKey, "compliance")
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Grant role failed: ${result.err().toString()}`);
+    }
+  });
+
+  it("should let only the compliance authority freeze and thaw", async () => {
+    const [tokenMint] = getMintPDA();
+    const holder = createFundedKeypair();
+    const tokenAccount = setupTokenAccount(holder.publicKey, tokenMint, BigInt(5_000_000));
+
+    const outsider = createFundedKeypair();
+    const denied = send(outsider, await freezeIx("freezeAccount", outsider.publicKey, tokenAccount));
+    expect(denied instanceof FailedTransactionMetadata).toBe(true);
+
+    const frozen = send(compliance, await freezeIx("freezeAccount", compliance.publicKey, tokenAccount));
+    if (frozen instanceof FailedTransactionMetadata) {
+      throw new Error(`Freeze failed: ${frozen.err().toString()}`);
+    }
+    expect(tokenAccountState(tokenAccount)).toBe(AccountState.Frozen);
+
+    const thawed = send(compliance, await freezeIx("thawAccount", compliance.publicKey, tokenAccount));
+    if (thawed instanceof FailedTransactionMetadata) {
+      throw new Error(`Thaw failed: ${thawed.err().toString()}`);
+    }
+    expect(tokenAccountState(tokenAccount)).toBe(AccountState.Initialized);
+  });
+
+  it("should permanently renounce the freeze authority", async () => {
+    const { svm, program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [tokenMint] = getMintPDA();
+
+    const renounceIx = async (signer: PublicKey) =>
+      program.methods
+        .renounceFreezeAuthority()
+        .accounts({
+          admin: signer,
+          bridgeConfig,
+          bridgeRoles: getBridgeRolesPDA()[0],
+          tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction();
+
+    // Renouncing is a bridge authority decision, not a compliance one
+    const denied = send(compliance, await renounceIx(compliance.publicKey));
+    expect(denied instanceof FailedTransactionMetadata).toBe(true);
+
+    const result = send(authority, await renounceIx(authority.publicKey));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Renounce failed: ${result.err().toString()}`);
+    }
+
+    const mint = MintLayout.decode(Buffer.from(svm.getAccount(tokenMint)!.data));
+    expect(mint.freezeAuthorityOption).toBe(0);
+
+    const holder = createFundedKeypair();
+    const tokenAccount = setupTokenAccount(holder.publicKey, tokenMint, BigInt(1_000_000));
+    const frozen = send(compliance, await freezeIx("freezeAccount", compliance.publicKey, tokenAccount));
+    expect(frozen instanceof FailedTransactionMetadata).toBe(true);
+  });
+});
@@ -0,0 +1,217 @@
+import { describe, expect, it } from "bun:test";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, ComputeBudgetProgram } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getGasReservePDA,
+  getMintPDA,
+  createFundedKeypair,
+  getTokenBalance,
+} from "../utils/helpers";
+import { newTransfer, signAttestation, send, setValidators, attestIx, finalizeIx } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+const DROPOFF = new BN(LAMPORTS_PER_SOL / 100); // 0.01 SOL
+
+async function setMaxNativeDropoff(maxNativeDropoffLamports: BN) {
+  const { program, authority } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+
+  const ix = await program.methods
+    .setMaxNativeDropoff({ maxNativeDropoffLamports })
+    .accounts({
+      feeManager: authority.publicKey,
+      bridgeConfig,
+    })
+    .instruction();
+
+  return send(authority, ix);
+}
+
+async function withdrawIx(authority: PublicKey, amount: BN) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [gasReserve] = getGasReservePDA();
+
+  return program.methods
+    .withdrawGasReserve({ amount })
+    .accounts({
+      feeManager: authority,
+      bridgeConfig,
+      gasReserve,
+    })
+    .instruction();
+}
+
+async function fundIx(funder: PublicKey, amount: BN) {
+  const { program } = getTestContext();
+  const [gasReserve] = getGasReservePDA();
+
+  return program.methods
+    .fundGasReserve({ amount })
+    .accounts({
+      funder,
+      gasReserve,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+describe("10. Gas Drop-off", () => {
+  const orchestrator = Keypair.generate();
+
+  it("should reject a drop-off above the configured maximum", async () => {
+    await setValidators([orchestrator], 10000, "dropoff");
+
+    const relayer = createFundedKeypair();
+    const transfer = newTransfer(new BN(1_000_000), "mirage1dropoff", Keypair.generate().publicKey, DROPOFF);
+
+    // The maximum starts at 0 (disabled)
+    const result = send(
+      relayer,
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, transfer)
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should fund the gas reserve", async () => {
+    const { svm } = getTestContext();
+    const [gasReserve] = getGasReservePDA();
+
+    const funder = createFundedKeypair();
+    const result = send(funder, await fundIx(funder.publicKey, new BN(LAMPORTS_PER_SOL)));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Fund gas reserve failed: ${result.err().toString()}`);
+    }
+
+    expect(svm.getBalance(gasReserve)!).toBeGreaterThan(BigInt(LAMPORTS_PER_SOL));
+  });
+
+  it("should deliver SOL to a fresh wallet alongside the mint", async () => {
+    const { svm } = getTestContext();
+    const [tokenMint] = getMintPDA();
+    const [gasReserve] = getGasReservePDA();
+
+    const setResult = await setMaxNativeDropoff(DROPOFF);
+    if (setResult instanceof FailedTransactionMetadata) {
+      throw new Error(`Set max drop-off failed: ${setResult.err().toString()}`);
+    }
+
+    const relayer = createFundedKeypair();
+    const recipient = Keypair.generate().publicKey; // Holds no SOL
+    const transfer = newTransfer(new BN(1_000_000), "mirage1dropoff", recipient, DROPOFF);
+    const reserveBefore = svm.getBalance(gasReserve)!;
+
+    const result = send(
+      relayer,
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, transfer),
+      await finalizeIx(relayer.publicKey, transfer, relayer.publicKey)
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Mint with drop-off failed: ${result.err().toString()}`);
+    }
+
+    expect(getTokenBalance(getAssociatedTokenAddressSync(tokenMint, recipient, true))).toBe(1_000_000n);
+    expect(svm.getBalance(recipient)).toBe(BigInt(DROPOFF.toString()));
+    expect(svm.getBalance(gasReserve)).toBe(reserveBefore - BigInt(DROPOFF.toString()));
+  });
+
+  it("should not let a relayer strip the drop-off from the signed payload", async () => {
+    const relayer = createFundedKeypair();
+    const transfer = newTransfer(new BN(1_000_000), "mirage1dropoff", Keypair.generate().publicKey, DROPOFF);
+
+    // Signature covers the drop-off; claiming none changes the payload
+    const result = send(
+      relayer,
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, { ...transfer, nativeDropoffLamports: new BN(0) })
+    );
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should only let the authority withdraw from the reserve", async () => {
+    const { svm, authority } = getTestContext();
+    const [gasReserve] = getGasReservePDA();
+
+    const outsider = createFundedKeypair();
+    const stolen = send(outsider, await withdrawIx(outsider.publicKey, new BN(1000)));
+    expect(stolen instanceof FailedTransactionMetadata).toBe(true);
+
+    const reserveBefore = svm.getBalance(gasReserve)!;
+    const result = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await withdrawIx(authority.publicKey, new BN(LAMPORTS_PER_SOL / 2))
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Withdraw failed: ${result.err().toString()}`);
+    }
+
+    expect(svm.getBalance(gasReserve)).toBe(reserveBefore - BigInt(LAMPORTS_PER_SOL / 2));
+  });
+
+  it("should hold delivery until the reserve covers the drop-off", async () => {
+    const { svm, authority } = getTestContext();
+    const [tokenMint] = getMintPDA();
+    const [gasReserve] = getGasReservePDA();
+
+    // Leave less than one drop-off above the reserve's rent-exempt minimum
+    const reserveRent = svm.minimumBalanceForRentExemption(BigInt(svm.getAccount(gasReserve)!.data.length));
+    const spare = svm.getBalance(gasReserve)! - reserveRent;
+    const drained = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_002 }),
+      await withdrawIx(authority.publicKey, new BN((spare - BigInt(DROPOFF.toString()) / 2n).toString()))
+    );
+    if (drained instanceof FailedTransactionMetadata) {
+      throw new Error(`Withdraw failed: ${drained.err().toString()}`);
+    }
+
+    const relayer = createFundedKeypair();
+    const recipient = Keypair.generate().publicKey;
+    const transfer = newTransfer(new BN(1_000_000), "mirage1dropoff", recipient, DROPOFF);
+
+    const attested = send(
+      relayer,
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, transfer)
+    );
+    if (attested instanceof FailedTransactionMetadata) {
+      throw new Error(`Attest failed: ${attested.err().toString()}`);
+    }
+
+    const held = send(relayer, await finalizeIx(relayer.publicKey, transfer, relayer.publicKey));
+    expect(held instanceof FailedTransactionMetadata).toBe(true);
+    expect((held as FailedTransactionMetadata).meta().logs().join("\n")).toContain("InsufficientGasReserve");
+    expect(svm.getAccount(transfer.mintRecord)).not.toBeNull();
+
+    const funder = createFundedKeypair();
+    const funded = send(funder, await fundIx(funder.publicKey, DROPOFF));
+    if (funded instanceof FailedTransactionMetadata) {
+      throw new Error(`Fund gas reserve failed: ${funded.err().toString()}`);
+    }
+
+    const result = send(
+      relayer,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await finalizeIx(relayer.publicKey, transfer, relayer.publicKey)
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Retried mint failed: ${result.err().toString()}`);
+    }
+
+    expect(getTokenBalance(getAssociatedTokenAddressSync(tokenMint, recipient, true))).toBe(1_000_000n);
+    expect(svm.getBalance(recipient)).toBe(BigInt(DROPOFF.toString()));
+  });
+
+  it("should disable drop-offs", async () => {
+    const result = await setMaxNativeDropoff(new BN(0));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Set max drop-off failed: ${result.err().toString()}`);
+    }
+  });
+});
@@ -2,7 +2,7 @@ import { describe, expect, it } from "bun:test";
 import { Transaction, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { getTestContext } from "../utils/setup";
-import { getBridgeConfigPDA, getValidatorRegistryPDA, getMintPDA, getMetadataPDA, METADATA_PROGRAM_ID } from "../utils/helpers";
+import { getBridgeConfigPDA, getValidatorRegistryPDA, getMintPDA, getMetadataPDA, METADATA_PROGRAM_ID, fetchValidatorRegistry } from "../utils/helpers";
 import BN from "bn.js";
 import { FailedTransactionMetadata } from "litesvm";
 
@@ -86,14 +86,14 @@ describe("1. Initialize", () => {
     expect(config.totalMinted.toNumber()).toBe(0);
     expect(config.totalBurned.toNumber()).toBe(0);
     expect(config.burnNonce.toNumber()).toBe(0);
-    expect(config.paused).toBe(false);
+    expect(config.pausedFlags).toBe(0);
   });
 
   it("should have empty validator registry after initialization", async () => {
     const { program } = getTestContext();
 
     const [validatorRegistry] = getValidatorRegistryPDA();
-    const registry = await program.account.validatorRegistry.fetch(validatorRegistry);
+    const registry = fetchValidatorRegistry();
 
     expect(registry.validators.length).toBe(0);
     expect(registry.totalStake.toNumber()).toBe(0);
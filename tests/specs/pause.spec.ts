@@ -1,7 +1,7 @@
 import { describe, expect, it } from "bun:test";
 import { Transaction } from "@solana/web3.js";
 import { getTestContext } from "../utils/setup";
-import { getBridgeConfigPDA, createFundedKeypair } from "../utils/helpers";
+import { getBridgeConfigPDA, createFundedKeypair, PAUSE_ALL } from "../utils/helpers";
 import { FailedTransactionMetadata } from "litesvm";
 
 describe("3. Pause", () => {
@@ -12,12 +12,12 @@ describe("3. Pause", () => {
 
     // First verify it's not paused
     const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configBefore.paused).toBe(false);
+    expect(configBefore.pausedFlags).toBe(0);
 
     const ix = await program.methods
-      .pause()
+      .pause({ flags: PAUSE_ALL })
       .accounts({
-        authority: authority.publicKey,
+        pauser: authority.publicKey,
         bridgeConfig,
       })
       .instruction();
@@ -42,7 +42,7 @@ describe("3. Pause", () => {
     const [bridgeConfig] = getBridgeConfigPDA();
     const config = await program.account.bridgeConfig.fetch(bridgeConfig);
 
-    expect(config.paused).toBe(true);
+    expect(config.pausedFlags).toBe(PAUSE_ALL);
   });
 
   it("should fail when non-authority tries to pause", async () => {
@@ -52,9 +52,9 @@ describe("3. Pause", () => {
     const fakeAuthority = createFundedKeypair();
 
     const ix = await program.methods
-      .pause()
+      .pause({ flags: PAUSE_ALL })
       .accounts({
-        authority: fakeAuthority.publicKey,
+        pauser: fakeAuthority.publicKey,
         bridgeConfig,
       })
       .instruction();
@@ -67,4 +67,29 @@ describe("3. Pause", () => {
     const result = svm.sendTransaction(tx);
     expect(result instanceof FailedTransactionMetadata).toBe(true);
   });
+
+  it("should reject an empty or unknown flags mask", async () => {
+    const { svm, program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    for (const flags of [0, 1 << 4]) {
+      const ix = await program.methods
+        .pause({ flags })
+        .accounts({
+          pauser: authority.publicKey,
+          bridgeConfig,
+        })
+        .instruction();
+
+      const tx = new Transaction();
+      tx.recentBlockhash = svm.latestBlockhash();
+      tx.add(ix);
+      tx.sign(authority);
+
+      const result = svm.sendTransaction(tx);
+      expect(result instanceof FailedTransactionMetadata).toBe(true);
+      expect((result as FailedTransactionMetadata).meta().logs().join("\n")).toContain("InvalidPauseFlags");
+    }
+  });
 });
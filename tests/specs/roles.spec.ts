@@ -0,0 +1,100 @@
+import { describe, expect, it } from "bun:test";
+import { ComputeBudgetProgram } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import { getBridgeConfigPDA, getBridgeRolesPDA, createFundedKeypair, roleIx, PAUSE_ALL } from "../utils/helpers";
+import { send } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+describe("17. Roles", () => {
+  const pauser = createFundedKeypair();
+
+  async function pauseIx(signer = pauser) {
+    const { program } = getTestContext();
+    return program.methods
+      .pause({ flags: PAUSE_ALL })
+      .accounts({ pauser: signer.publicKey, bridgeConfig: getBridgeConfigPDA()[0] })
+      .instruction();
+  }
+
+  async function unpauseIx(signer = pauser) {
+    const { program } = getTestContext();
+    return program.methods
+      .unpause({ flags: PAUSE_ALL })
+      .accounts({ admin: signer.publicKey, bridgeConfig: getBridgeConfigPDA()[0] })
+      .instruction();
+  }
+
+  it("should only let an admin grant roles", async () => {
+    const outsider = createFundedKeypair();
+    const result = send(outsider, await roleIx("grantRole", outsider.publicKey, outsider.publicKey, "pauser"));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should grant the pauser role to a hot key", async () => {
+    const { program, authority } = getTestContext();
+
+    const result = send(authority, await roleIx("grantRole", authority.publicKey, pauser.publicKey, "pauser"));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Grant role failed: ${result.err().toString()}`);
+    }
+
+    const roles = await program.account.bridgeRoles.fetch(getBridgeRolesPDA()[0]);
+    const member = roles.members.find((m: any) => m.member.equals(pauser.publicKey));
+    expect(member).toBeDefined();
+
+    // Granting the same role twice is rejected
+    const duplicate = send(
+      authority,
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+      await roleIx("grantRole", authority.publicKey, pauser.publicKey, "pauser")
+    );
+    expect(duplicate instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should let the pauser pause but nothing else", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const paused = send(pauser, await pauseIx());
+    if (paused instanceof FailedTransactionMetadata) {
+      throw new Error(`Pause failed: ${paused.err().toString()}`);
+    }
+    expect((await program.account.bridgeConfig.fetch(bridgeConfig)).pausedFlags).toBe(PAUSE_ALL);
+
+    expect(send(pauser, await unpauseIx()) instanceof FailedTransactionMetadata).toBe(true);
+
+    const limitsIx = await program.methods
+      .setTransferLimits({
+        burnLimits: { minAmount: new BN(0), maxAmount: new BN(1) },
+        mintLimits: { minAmount: new BN(0), maxAmount: new BN(0) },
+      })
+      .accounts({ feeManager: pauser.publicKey, bridgeConfig })
+      .instruction();
+    expect(send(pauser, limitsIx) instanceof FailedTransactionMetadata).toBe(true);
+
+    const grantIx = await roleIx("grantRole", pauser.publicKey, pauser.publicKey, "admin");
+    expect(send(pauser, grantIx) instanceof FailedTransactionMetadata).toBe(true);
+
+    // The authority holds every role implicitly
+    const unpaused = send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_003 }), await unpauseIx(authority));
+    if (unpaused instanceof FailedTransactionMetadata) {
+      throw new Error(`Unpause failed: ${unpaused.err().toString()}`);
+    }
+  });
+
+  it("should revoke the pauser role", async () => {
+    const { program, authority } = getTestContext();
+
+    const result = send(authority, await roleIx("revokeRole", authority.publicKey, pauser.publicKey, "pauser"));
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Revoke role failed: ${result.err().toString()}`);
+    }
+
+    const roles = await program.account.bridgeRoles.fetch(getBridgeRolesPDA()[0]);
+    expect(roles.members.some((m: any) => m.member.equals(pauser.publicKey))).toBe(false);
+
+    const denied = send(pauser, ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }), await pauseIx());
+    expect(denied instanceof FailedTransactionMetadata).toBe(true);
+  });
+});
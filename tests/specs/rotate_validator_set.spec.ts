@@ -0,0 +1,201 @@
+import { describe, expect, it } from "bun:test";
+import { Transaction, Keypair, PublicKey, SystemProgram, Ed25519Program } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getValidatorRegistryPDA,
+  getValsetUpdatePDA,
+  getProgramId,
+  createFundedKeypair,
+  fetchValidatorRegistry,
+} from "../utils/helpers";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+import * as ed from "@noble/ed25519";
+import { sha256, sha512 } from "@noble/hashes/sha2.js";
+
+// Required for @noble/ed25519 v3 to work synchronously
+ed.hashes.sha512 = sha512;
+
+const INSTRUCTIONS_SYSVAR = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+
+interface Validator {
+  orchestratorPubkey: PublicKey;
+  mirageValidator: string;
+  stake: BN;
+}
+
+function u32LE(value: number): Buffer {
+  const buf = Buffer.alloc(4);
+  buf.writeUInt32LE(value, 0);
+  return buf;
+}
+
+// SHA-256 over the Borsh encoding of Vec<ValidatorInfo>
+function hashValidatorSet(validators: Validator[]): Buffer {
+  const parts = [u32LE(validators.length)];
+  for (const v of validators) {
+    parts.push(
+      v.orchestratorPubkey.toBuffer(),
+      u32LE(v.mirageValidator.length),
+      Buffer.from(v.mirageValidator),
+      v.stake.toArrayLike(Buffer, "le", 8)
+    );
+  }
+  return Buffer.from(sha256(Buffer.concat(parts)));
+}
+
+function buildValsetUpdatePayload(chainId: string, valsetNonce: BN, valsetHash: Buffer): Buffer {
+  return Buffer.concat([
+    Buffer.from("mirage-bridge:valset-update"),
+    getProgramId().toBuffer(),
+    u32LE(chainId.length),
+    Buffer.from(chainId),
+    valsetNonce.toArrayLike(Buffer, "le", 8),
+    valsetHash,
+  ]);
+}
+
+function signPayload(signer: Keypair, payload: Buffer) {
+  return Ed25519Program.createInstructionWithPublicKey({
+    publicKey: signer.publicKey.toBytes(),
+    message: payload,
+    signature: Buffer.from(ed.sign(payload, signer.secretKey.slice(0, 32))),
+  });
+}
+
+describe("8. Rotate Validator Set", () => {
+  const current = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+  it("should install a known validator set via the authority path", async () => {
+    const { svm, program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const ix = await program.methods
+      .updateValidators({
+        validators: current.map((kp, i) => ({
+          orchestratorPubkey: kp.publicKey,
+          mirageValidator: `miragevaloper1current${i}`,
+          stake: new BN(2000),
+        })),
+      })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(ix);
+    tx.sign(authority);
+
+    const result = svm.sendTransaction(tx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Update validators failed: ${result.err().toString()}`);
+    }
+  });
+
+  it("should not rotate with signatures below threshold", async () => {
+    const { svm, program } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const registry = fetchValidatorRegistry();
+    const valsetNonce = registry.valsetNonce.addn(1);
+
+    const next: Validator[] = [
+      { orchestratorPubkey: Keypair.generate().publicKey, mirageValidator: "miragevaloper1next", stake: new BN(5000) },
+    ];
+    const valsetHash = hashValidatorSet(next);
+    const payload = buildValsetUpdatePayload("mirage-1", valsetNonce, valsetHash);
+
+    const payer = createFundedKeypair();
+    const [valsetUpdate] = getValsetUpdatePDA(payer.publicKey, valsetNonce);
+
+    const ix = await program.methods
+      .rotateValidatorSet({ valsetNonce, valsetHash: Array.from(valsetHash), validators: next })
+      .accounts({
+        payer: payer.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+        valsetUpdate,
+        instructionsSysvar: INSTRUCTIONS_SYSVAR,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    // One of three equal validators is 33%, below the 66.67% threshold
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(signPayload(current[0], payload));
+    tx.add(ix);
+    tx.sign(payer);
+
+    const result = svm.sendTransaction(tx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Rotate failed: ${result.err().toString()}`);
+    }
+
+    const pending = await program.account.valsetUpdate.fetch(valsetUpdate);
+    expect(pending.signedPower.toNumber()).toBe(2000);
+
+    const after = fetchValidatorRegistry();
+    expect(after.valsetNonce.toString()).toBe(registry.valsetNonce.toString());
+  });
+
+  it("should rotate once signatures from the current set reach threshold", async () => {
+    const { svm, program } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const registry = fetchValidatorRegistry();
+    const valsetNonce = registry.valsetNonce.addn(1);
+
+    const next: Validator[] = [
+      { orchestratorPubkey: Keypair.generate().publicKey, mirageValidator: "miragevaloper1next", stake: new BN(5000) },
+    ];
+    const valsetHash = hashValidatorSet(next);
+    const payload = buildValsetUpdatePayload("mirage-1", valsetNonce, valsetHash);
+
+    const payer = createFundedKeypair();
+    const [valsetUpdate] = getValsetUpdatePDA(payer.publicKey, valsetNonce);
+
+    const ix = await program.methods
+      .rotateValidatorSet({ valsetNonce, valsetHash: Array.from(valsetHash), validators: next })
+      .accounts({
+        payer: payer.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+        valsetUpdate,
+        instructionsSysvar: INSTRUCTIONS_SYSVAR,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(signPayload(current[0], payload));
+    tx.add(signPayload(current[1], payload));
+    tx.add(ix);
+    tx.sign(payer);
+
+    const result = svm.sendTransaction(tx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Rotate failed: ${result.err().toString()}`);
+    }
+
+    const after = fetchValidatorRegistry();
+    expect(after.valsetNonce.toString()).toBe(valsetNonce.toString());
+    expect(after.validators.length).toBe(1);
+    expect(after.totalStake.toNumber()).toBe(5000);
+
+    // Record is closed once applied
+    expect(svm.getAccount(valsetUpdate)).toBeNull();
+  });
+});
@@ -0,0 +1,135 @@
+import { describe, expect, it } from "bun:test";
+import { Keypair, PublicKey, SystemProgram, ComputeBudgetProgram } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getMintPDA,
+  nextBurnAccounts,
+  getBurnDenylistShards,
+  createFundedKeypair,
+  setupTokenAccount,
+} from "../utils/helpers";
+import { newTransfer, signAttestation, send, setValidators, attestIx } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+const MIRAGE_RECIPIENT = Buffer.from("0123456789abcdef0123456789abcdef01234567", "hex");
+
+const noLimits = { minAmount: new BN(0), maxAmount: new BN(0) };
+
+async function setTransferLimits(burnLimits: typeof noLimits, mintLimits: typeof noLimits, unique = 200_000) {
+  const { program, authority } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+
+  const ix = await program.methods
+    .setTransferLimits({ burnLimits, mintLimits })
+    .accounts({
+      feeManager: authority.publicKey,
+      bridgeConfig,
+    })
+    .instruction();
+
+  return send(authority, ComputeBudgetProgram.setComputeUnitLimit({ units: unique }), ix);
+}
+
+async function burn(amount: number) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+  const { burnShard, burnRecord } = await nextBurnAccounts();
+
+  const user = createFundedKeypair();
+  const userTokenAccount = setupTokenAccount(user.publicKey, tokenMint, BigInt(amount));
+
+  const ix = await program.methods
+    .burn({
+      mirageRecipient: { hex: { 0: Array.from(MIRAGE_RECIPIENT) } },
+      amount: new BN(amount),
+    })
+    .accounts({
+      user: user.publicKey,
+      userTokenAccount,
+      tokenMint,
+      bridgeConfig,
+      burnRecord,
+      burnShard,
+      ...getBurnDenylistShards(user.publicKey, MIRAGE_RECIPIENT),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+
+  return send(user, ix);
+}
+
+describe("13. Transfer Limits", () => {
+  const orchestrator = Keypair.generate();
+
+  it("should reject a minimum above the maximum", async () => {
+    const result = await setTransferLimits({ minAmount: new BN(10), maxAmount: new BN(5) }, noLimits);
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should set burn and mint limits", async () => {
+    const { program } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+
+    const result = await setTransferLimits(
+      { minAmount: new BN(1_000_000), maxAmount: new BN(100_000_000) },
+      { minAmount: new BN(500_000), maxAmount: new BN(50_000_000) }
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Set transfer limits failed: ${result.err().toString()}`);
+    }
+
+    const config = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(config.burnLimits.minAmount.toNumber()).toBe(1_000_000);
+    expect(config.burnLimits.maxAmount.toNumber()).toBe(100_000_000);
+    expect(config.mintLimits.minAmount.toNumber()).toBe(500_000);
+    expect(config.mintLimits.maxAmount.toNumber()).toBe(50_000_000);
+  });
+
+  it("should enforce burn limits", async () => {
+    expect((await burn(999_999)) instanceof FailedTransactionMetadata).toBe(true);
+    expect((await burn(100_000_001)) instanceof FailedTransactionMetadata).toBe(true);
+
+    const result = await burn(1_000_000);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Burn within limits failed: ${result.err().toString()}`);
+    }
+  });
+
+  it("should enforce mint limits when attesting", async () => {
+    await setValidators([orchestrator], 10000, "limits");
+
+    for (const amount of [499_999, 50_000_001]) {
+      const relayer = createFundedKeypair();
+      const transfer = newTransfer(new BN(amount), "mirage1limits");
+      const result = send(
+        relayer,
+        signAttestation(orchestrator, transfer.payload),
+        await attestIx(relayer.publicKey, transfer)
+      );
+      expect(result instanceof FailedTransactionMetadata).toBe(true);
+    }
+
+    const relayer = createFundedKeypair();
+    const transfer = newTransfer(new BN(500_000), "mirage1limits");
+    const result = send(
+      relayer,
+      signAttestation(orchestrator, transfer.payload),
+      await attestIx(relayer.publicKey, transfer)
+    );
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Attest within limits failed: ${result.err().toString()}`);
+    }
+  });
+
+  it("should remove the limits", async () => {
+    const result = await setTransferLimits(noLimits, noLimits, 200_001);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Set transfer limits failed: ${result.err().toString()}`);
+    }
+  });
+});
@@ -1,7 +1,7 @@
 import { describe, expect, it } from "bun:test";
 import { Transaction } from "@solana/web3.js";
 import { getTestContext } from "../utils/setup";
-import { getBridgeConfigPDA, createFundedKeypair } from "../utils/helpers";
+import { getBridgeConfigPDA, createFundedKeypair, PAUSE_ALL } from "../utils/helpers";
 import { FailedTransactionMetadata } from "litesvm";
 
 describe("4. Unpause", () => {
@@ -13,15 +13,15 @@ describe("4. Unpause", () => {
 
     // Verify bridge is still paused from pause.spec
     const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configBefore.paused).toBe(true);
+    expect(configBefore.pausedFlags).toBe(PAUSE_ALL);
 
     // Try to unpause with fake authority - should fail
     const fakeAuthority = createFundedKeypair();
 
     const ix = await program.methods
-      .unpause()
+      .unpause({ flags: PAUSE_ALL })
       .accounts({
-        authority: fakeAuthority.publicKey,
+        admin: fakeAuthority.publicKey,
         bridgeConfig,
       })
       .instruction();
@@ -36,7 +36,7 @@ describe("4. Unpause", () => {
 
     // Verify still paused (unauthorized unpause should have failed)
     const configAfter = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configAfter.paused).toBe(true);
+    expect(configAfter.pausedFlags).toBe(PAUSE_ALL);
   });
 
   it("should unpause the bridge", async () => {
@@ -46,12 +46,12 @@ describe("4. Unpause", () => {
 
     // Verify it's still paused
     const configBefore = await program.account.bridgeConfig.fetch(bridgeConfig);
-    expect(configBefore.paused).toBe(true);
+    expect(configBefore.pausedFlags).toBe(PAUSE_ALL);
 
     const ix = await program.methods
-      .unpause()
+      .unpause({ flags: PAUSE_ALL })
       .accounts({
-        authority: authority.publicKey,
+        admin: authority.publicKey,
         bridgeConfig,
       })
       .instruction();
@@ -76,6 +76,6 @@ describe("4. Unpause", () => {
     const [bridgeConfig] = getBridgeConfigPDA();
     const config = await program.account.bridgeConfig.fetch(bridgeConfig);
 
-    expect(config.paused).toBe(false);
+    expect(config.pausedFlags).toBe(0);
   });
 });
@@ -0,0 +1,123 @@
+import { describe, expect, it } from "bun:test";
+import { ComputeBudgetProgram, Keypair, PublicKey } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import { getBridgeConfigPDA, getBridgeRolesPDA, createFundedKeypair } from "../utils/helpers";
+import { newTransfer, signAttestation, send, setValidators, attestIx, finalizeIx } from "../utils/mint";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+const unchanged = {
+  attestationThreshold: null,
+  mirageChainId: null,
+  burnLimits: null,
+  mintLimits: null,
+  claimExpiry: null,
+};
+
+async function updateConfigIx(admin: PublicKey, changes: Partial<Record<keyof typeof unchanged, any>>, unique = 200_000) {
+  const { program } = getTestContext();
+  const ix = await program.methods
+    .updateConfig({ ...unchanged, ...changes })
+    .accounts({ admin, bridgeConfig: getBridgeConfigPDA()[0], bridgeRoles: getBridgeRolesPDA()[0] })
+    .instruction();
+  return [ComputeBudgetProgram.setComputeUnitLimit({ units: unique }), ix];
+}
+
+function expectOk(result: ReturnType<typeof send>, label: string) {
+  if (result instanceof FailedTransactionMetadata) {
+    throw new Error(`${label} failed: ${result.err().toString()}`);
+  }
+}
+
+describe("22. Update Config", () => {
+  it("should only let admins update the config", async () => {
+    const outsider = createFundedKeypair();
+    const result = send(outsider, ...(await updateConfigIx(outsider.publicKey, { attestationThreshold: new BN(5000) })));
+    expect(result instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should validate like initialize", async () => {
+    const { authority } = getTestContext();
+    const invalid = [
+      { attestationThreshold: new BN(0) },
+      { attestationThreshold: new BN(10_001) },
+      { mirageChainId: "" },
+      { mirageChainId: "m".repeat(33) },
+      { burnLimits: { minAmount: new BN(10), maxAmount: new BN(5) } },
+      { claimExpiry: new BN(-1) },
+    ];
+    for (const [i, changes] of invalid.entries()) {
+      const result = send(authority, ...(await updateConfigIx(authority.publicKey, changes, 200_000 + i)));
+      expect(result instanceof FailedTransactionMetadata).toBe(true);
+    }
+  });
+
+  it("should update only the fields that are set", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const before = await program.account.bridgeConfig.fetch(bridgeConfig);
+
+    expectOk(
+      send(
+        authority,
+        ...(await updateConfigIx(authority.publicKey, { mirageChainId: "mirage-2", claimExpiry: new BN(3600) }))
+      ),
+      "Update"
+    );
+    const after = await program.account.bridgeConfig.fetch(bridgeConfig);
+    expect(after.mirageChainId).toBe("mirage-2");
+    expect(after.claimExpiry.toNumber()).toBe(3600);
+    expect(after.attestationThreshold.toString()).toBe(before.attestationThreshold.toString());
+    expect(after.burnLimits.maxAmount.toString()).toBe(before.burnLimits.maxAmount.toString());
+
+    expectOk(
+      send(
+        authority,
+        ...(await updateConfigIx(
+          authority.publicKey,
+          { mirageChainId: before.mirageChainId, claimExpiry: before.claimExpiry },
+          200_001
+        ))
+      ),
+      "Restore"
+    );
+  });
+
+  it("should apply a new threshold to mint records still collecting signatures", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const { attestationThreshold } = await program.account.bridgeConfig.fetch(bridgeConfig);
+
+    const orchestrators = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    await setValidators(orchestrators, 100, "config");
+
+    const relayer = createFundedKeypair();
+    const transfer = newTransfer(new BN(1_000_000));
+    expectOk(
+      send(relayer, signAttestation(orchestrators[0], transfer.payload), await attestIx(relayer.publicKey, transfer)),
+      "Attest"
+    );
+
+    // One of three validators is short of the initial threshold
+    const early = send(relayer, await finalizeIx(relayer.publicKey, transfer, relayer.publicKey));
+    expect(early instanceof FailedTransactionMetadata).toBe(true);
+
+    expectOk(
+      send(authority, ...(await updateConfigIx(authority.publicKey, { attestationThreshold: new BN(3000) }, 200_002))),
+      "Lower threshold"
+    );
+    expectOk(
+      send(
+        relayer,
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 200_001 }),
+        await finalizeIx(relayer.publicKey, transfer, relayer.publicKey)
+      ),
+      "Finalize"
+    );
+
+    expectOk(
+      send(authority, ...(await updateConfigIx(authority.publicKey, { attestationThreshold }, 200_003))),
+      "Restore threshold"
+    );
+  });
+});
@@ -1,7 +1,7 @@
 import { describe, expect, it } from "bun:test";
 import { Transaction, Keypair } from "@solana/web3.js";
 import { getTestContext } from "../utils/setup";
-import { getBridgeConfigPDA, getValidatorRegistryPDA, createFundedKeypair } from "../utils/helpers";
+import { getBridgeConfigPDA, getValidatorRegistryPDA, createFundedKeypair, fetchValidatorRegistry } from "../utils/helpers";
 import BN from "bn.js";
 import { FailedTransactionMetadata } from "litesvm";
 
@@ -25,7 +25,7 @@ describe("2. Update Validators", () => {
     const ix = await program.methods
       .updateValidators({ validators })
       .accounts({
-        authority: authority.publicKey,
+        validatorManager: authority.publicKey,
         bridgeConfig,
         validatorRegistry,
       })
@@ -50,7 +50,7 @@ describe("2. Update Validators", () => {
     const { program } = getTestContext();
 
     const [validatorRegistry] = getValidatorRegistryPDA();
-    const registry = await program.account.validatorRegistry.fetch(validatorRegistry);
+    const registry = fetchValidatorRegistry();
 
     expect(registry.validators.length).toBe(1);
     expect(registry.totalStake.toNumber()).toBe(1000);
@@ -89,7 +89,7 @@ describe("2. Update Validators", () => {
     const ix = await program.methods
       .updateValidators({ validators })
       .accounts({
-        authority: authority.publicKey,
+        validatorManager: authority.publicKey,
         bridgeConfig,
         validatorRegistry,
       })
@@ -106,7 +106,7 @@ describe("2. Update Validators", () => {
       throw new Error(`Transaction failed: ${result.err().toString()}`);
     }
 
-    const registry = await program.account.validatorRegistry.fetch(validatorRegistry);
+    const registry = fetchValidatorRegistry();
     expect(registry.validators.length).toBe(3);
     expect(registry.totalStake.toNumber()).toBe(6000);
   });
@@ -131,7 +131,7 @@ describe("2. Update Validators", () => {
     const ix = await program.methods
       .updateValidators({ validators })
       .accounts({
-        authority: fakeAuthority.publicKey,
+        validatorManager: fakeAuthority.publicKey,
         bridgeConfig,
         validatorRegistry,
       })
@@ -155,7 +155,7 @@ describe("2. Update Validators", () => {
     const ix = await program.methods
       .updateValidators({ validators: [] })
       .accounts({
-        authority: authority.publicKey,
+        validatorManager: authority.publicKey,
         bridgeConfig,
         validatorRegistry,
       })
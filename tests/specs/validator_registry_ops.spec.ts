@@ -0,0 +1,260 @@
+import { describe, expect, it } from "bun:test";
+import { Transaction, Keypair, SystemProgram, TransactionInstruction } from "@solana/web3.js";
+import { getTestContext } from "../utils/setup";
+import {
+  getBridgeConfigPDA,
+  getValidatorRegistryPDA,
+  getValidatorStagingPDA,
+  fetchValidatorRegistry,
+} from "../utils/helpers";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+
+function sendAsAuthority(ix: TransactionInstruction) {
+  const { svm, authority } = getTestContext();
+
+  const tx = new Transaction();
+  tx.recentBlockhash = svm.latestBlockhash();
+  tx.add(ix);
+  tx.sign(authority);
+
+  return svm.sendTransaction(tx);
+}
+
+describe("7. Validator Registry Operations", () => {
+  const added = Keypair.generate();
+
+  it("should add a single validator and recompute total stake", async () => {
+    const { program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const before = fetchValidatorRegistry();
+
+    const ix = await program.methods
+      .addValidator({
+        validator: {
+          orchestratorPubkey: added.publicKey,
+          mirageValidator: "miragevaloper1added",
+          stake: new BN(500),
+        },
+      })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    const result = sendAsAuthority(ix);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Add validator failed: ${result.err().toString()}`);
+    }
+
+    const after = fetchValidatorRegistry();
+    expect(after.validators.length).toBe(before.validators.length + 1);
+    expect(after.totalStake.toNumber()).toBe(before.totalStake.toNumber() + 500);
+  });
+
+  it("should reject a duplicate orchestrator", async () => {
+    const { program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const ix = await program.methods
+      .addValidator({
+        validator: {
+          orchestratorPubkey: added.publicKey,
+          mirageValidator: "miragevaloper1duplicate",
+          stake: new BN(700),
+        },
+      })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    expect(sendAsAuthority(ix) instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should update a validator's stake", async () => {
+    const { program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const before = fetchValidatorRegistry();
+
+    const ix = await program.methods
+      .updateValidatorStake({
+        orchestratorPubkey: added.publicKey,
+        stake: new BN(1500),
+      })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    const result = sendAsAuthority(ix);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Update stake failed: ${result.err().toString()}`);
+    }
+
+    const after = fetchValidatorRegistry();
+    expect(after.totalStake.toNumber()).toBe(before.totalStake.toNumber() + 1000);
+  });
+
+  it("should reject a zero stake update", async () => {
+    const { program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const ix = await program.methods
+      .updateValidatorStake({
+        orchestratorPubkey: added.publicKey,
+        stake: new BN(0),
+      })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    expect(sendAsAuthority(ix) instanceof FailedTransactionMetadata).toBe(true);
+  });
+
+  it("should remove a validator", async () => {
+    const { program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const before = fetchValidatorRegistry();
+
+    const ix = await program.methods
+      .removeValidator({ orchestratorPubkey: added.publicKey })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    const result = sendAsAuthority(ix);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Remove validator failed: ${result.err().toString()}`);
+    }
+
+    const after = fetchValidatorRegistry();
+    expect(after.validators.length).toBe(before.validators.length - 1);
+    expect(after.totalStake.toNumber()).toBe(before.totalStake.toNumber() - 1500);
+  });
+
+  it("should stage validators in chunks and commit the full set", async () => {
+    const { program, authority } = getTestContext();
+
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+    const [validatorStaging] = getValidatorStagingPDA();
+
+    const validators = Array.from({ length: 12 }, (_, i) => ({
+      orchestratorPubkey: Keypair.generate().publicKey,
+      mirageValidator: `miragevaloper1staged${i}`,
+      stake: new BN(100 * (i + 1)),
+    }));
+
+    for (let i = 0; i < validators.length; i += 4) {
+      const ix = await program.methods
+        .stageValidators({ validators: validators.slice(i, i + 4), reset: i === 0 })
+        .accounts({
+          validatorManager: authority.publicKey,
+          bridgeConfig,
+          validatorStaging,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      const result = sendAsAuthority(ix);
+      if (result instanceof FailedTransactionMetadata) {
+        throw new Error(`Stage validators failed: ${result.err().toString()}`);
+      }
+    }
+
+    const commitIx = await program.methods
+      .commitValidatorSet()
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+        validatorStaging,
+      })
+      .instruction();
+
+    const result = sendAsAuthority(commitIx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Commit validator set failed: ${result.err().toString()}`);
+    }
+
+    const registry = fetchValidatorRegistry();
+    expect(registry.validators.length).toBe(12);
+    expect(registry.totalStake.toNumber()).toBe(7800);
+
+    // Staging account is closed on commit
+    const { svm } = getTestContext();
+    expect(svm.getAccount(validatorStaging)).toBeNull();
+  });
+
+  it("should resize the registry capacity", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const resizeIx = await program.methods
+      .resizeValidatorRegistry({ capacity: 150 })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    const result = sendAsAuthority(resizeIx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Resize registry failed: ${result.err().toString()}`);
+    }
+
+    const registry = fetchValidatorRegistry();
+    expect(registry.capacity).toBe(150);
+    expect(registry.validators.length).toBe(12);
+
+    const { svm } = getTestContext();
+    const account = svm.getAccount(validatorRegistry);
+    expect(account!.data.length).toBe(72 + 150 * 96);
+  });
+
+  it("should reject shrinking below the current validator count", async () => {
+    const { program, authority } = getTestContext();
+    const [bridgeConfig] = getBridgeConfigPDA();
+    const [validatorRegistry] = getValidatorRegistryPDA();
+
+    const resizeIx = await program.methods
+      .resizeValidatorRegistry({ capacity: 4 })
+      .accounts({
+        validatorManager: authority.publicKey,
+        bridgeConfig,
+        validatorRegistry,
+      })
+      .instruction();
+
+    const result = sendAsAuthority(resizeIx);
+    expect(result).toBeInstanceOf(FailedTransactionMetadata);
+  });
+});
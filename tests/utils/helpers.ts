@@ -1,7 +1,10 @@
-import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { PublicKey, Keypair, LAMPORTS_PER_SOL, SystemProgram, Transaction } from "@solana/web3.js";
+import { FailedTransactionMetadata } from "litesvm";
 import { getTestContext } from "./setup";
 import { ACCOUNT_SIZE, AccountLayout, getAssociatedTokenAddressSync, TOKEN_PROGRAM_ID, MintLayout, MINT_SIZE } from "@solana/spl-token";
 import BN from "bn.js";
+import { bech32 } from "bech32";
+import { sha256 } from "@noble/hashes/sha2.js";
 
 export function getProgramId(): PublicKey {
   const { program } = getTestContext();
@@ -15,6 +18,13 @@ export function getBridgeConfigPDA(): [PublicKey, number] {
   );
 }
 
+export function getBridgeStatePDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bridge_state")],
+    getProgramId()
+  );
+}
+
 export function getValidatorRegistryPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("validator_registry")],
@@ -22,6 +32,13 @@ export function getValidatorRegistryPDA(): [PublicKey, number] {
   );
 }
 
+export function getValidatorStagingPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("validator_staging")],
+    getProgramId()
+  );
+}
+
 export function getMintPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("mint")],
@@ -46,13 +63,209 @@ export function getMintRecordPDA(burnTxHash: Buffer): [PublicKey, number] {
   );
 }
 
-export function getBurnRecordPDA(burnNonce: BN): [PublicKey, number] {
+export function getProcessedMintPDA(burnTxHash: Buffer): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("processed_mint"), burnTxHash],
+    getProgramId()
+  );
+}
+
+export function getBurnRecordPDA(burnId: BN): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("burn_record"), burnId.toArrayLike(Buffer, "le", 8)],
+    getProgramId()
+  );
+}
+
+export function getBurnShardPDA(shardId: number): [PublicKey, number] {
+  const id = Buffer.alloc(2);
+  id.writeUInt16LE(shardId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("burn_shard"), id],
+    getProgramId()
+  );
+}
+
+// burn_id = (shard nonce << 16) | shard_id
+export function getBurnId(shardId: number, nonce: BN): BN {
+  return nonce.shln(16).or(new BN(shardId));
+}
+
+// Creates the shard on first use and returns the accounts for the next burn through it
+export async function nextBurnAccounts(shardId = 0) {
+  const { svm, program } = getTestContext();
+  const [burnShard] = getBurnShardPDA(shardId);
+
+  if (!svm.getAccount(burnShard)) {
+    const payer = createFundedKeypair();
+    const ix = await program.methods
+      .initBurnShard({ shardId })
+      .accounts({
+        payer: payer.publicKey,
+        bridgeConfig: getBridgeConfigPDA()[0],
+        burnShard,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.add(ix);
+    tx.sign(payer);
+    const result = svm.sendTransaction(tx);
+    if (result instanceof FailedTransactionMetadata) {
+      throw new Error(`Init burn shard failed: ${result.err().toString()}`);
+    }
+  }
+
+  const shard = await program.account.burnShard.fetch(burnShard);
+  const burnId = getBurnId(shardId, shard.nextNonce);
+  const [burnRecord] = getBurnRecordPDA(burnId);
+  return { burnShard, burnRecord, burnId };
+}
+
+export function getValsetUpdatePDA(payer: PublicKey, valsetNonce: BN): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("valset_update"), payer.toBuffer(), valsetNonce.toArrayLike(Buffer, "le", 8)],
+    getProgramId()
+  );
+}
+
+export function getBridgeRolesPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bridge_roles")],
+    getProgramId()
+  );
+}
+
+export type RoleName = "admin" | "pauser" | "validatorManager" | "metadataManager" | "feeManager" | "compliance";
+
+export async function roleIx(method: "grantRole" | "revokeRole", admin: PublicKey, member: PublicKey, role: RoleName) {
+  const { program } = getTestContext();
+  return program.methods[method]({ member, role: { [role]: {} } as any })
+    .accounts({
+      admin,
+      bridgeConfig: getBridgeConfigPDA()[0],
+      bridgeRoles: getBridgeRolesPDA()[0],
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export function getQueuedActionPDA(actionId: BN): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("queued_action"), actionId.toArrayLike(Buffer, "le", 8)],
+    getProgramId()
+  );
+}
+
+export async function queueActionIx(admin: PublicKey, action: any) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const { adminActionNonce } = await program.account.bridgeConfig.fetch(bridgeConfig);
+  const [queuedAction] = getQueuedActionPDA(adminActionNonce);
+
+  const ix = await program.methods
+    .queueAction({ action })
+    .accounts({
+      admin,
+      bridgeConfig,
+      bridgeRoles: getBridgeRolesPDA()[0],
+      queuedAction,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  return { ix, queuedAction };
+}
+
+// Role changes pass `payer`, who must also sign, to resize `bridge_roles`;
+// renouncing the freeze authority needs the token mint, committing a staged
+// validator set needs the staging account, and withdrawals need the reserve
+export async function executeActionIx(
+  queuedAction: PublicKey,
+  queuedBy: PublicKey,
+  {
+    payer,
+    withTokenMint = false,
+    withValidatorStaging = false,
+    withGasReserve = false,
+  }: { payer?: PublicKey; withTokenMint?: boolean; withValidatorStaging?: boolean; withGasReserve?: boolean } = {}
+) {
+  const { program } = getTestContext();
+  return program.methods
+    .executeAction()
+    .accounts({
+      bridgeConfig: getBridgeConfigPDA()[0],
+      validatorRegistry: getValidatorRegistryPDA()[0],
+      queuedAction,
+      queuedBy,
+      validatorStaging: withValidatorStaging ? getValidatorStagingPDA()[0] : null,
+      bridgeRoles: payer ? getBridgeRolesPDA()[0] : null,
+      payer: payer ?? null,
+      systemProgram: payer ? SystemProgram.programId : null,
+      tokenMint: withTokenMint ? getMintPDA()[0] : null,
+      tokenProgram: withTokenMint ? TOKEN_PROGRAM_ID : null,
+      gasReserve: withGasReserve ? getGasReservePDA()[0] : null,
+    })
+    .instruction();
+}
+
+// `BridgeConfig.paused_flags` bits
+export const PAUSE_BURNS = 1 << 0;
+export const PAUSE_MINTS = 1 << 1;
+export const PAUSE_ATTESTATIONS = 1 << 2;
+export const PAUSE_FINALIZATION = 1 << 3;
+export const PAUSE_ALL = PAUSE_BURNS | PAUSE_MINTS | PAUSE_ATTESTATIONS | PAUSE_FINALIZATION;
+
+export function getGasReservePDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("burn_record"), burnNonce.toArrayLike(Buffer, "le", 8)],
+    [Buffer.from("gas_reserve")],
     getProgramId()
   );
 }
 
+export function getPermitNoncePDA(user: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("permit_nonce"), user.toBuffer()],
+    getProgramId()
+  );
+}
+
+export function getDenylistShardPDA(key: Uint8Array): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("denylist"), Buffer.from([key[0]])],
+    getProgramId()
+  );
+}
+
+export function solanaDenylistKey(address: PublicKey): Uint8Array {
+  return sha256(Buffer.concat([Buffer.from("solana"), address.toBuffer()]));
+}
+
+// Bech32 recipients are hashed in their decoded byte form, as on-chain
+export function mirageDenylistKey(recipient: string | Uint8Array): Uint8Array {
+  const bytes =
+    typeof recipient === "string" ? Buffer.from(bech32.fromWords(bech32.decode(recipient, 90).words)) : recipient;
+  return sha256(Buffer.concat([Buffer.from("mirage"), Buffer.from(bytes)]));
+}
+
+// Denylist shards the burn instruction checks; `signer` differs from the
+// token account owner when a delegate burns
+export function getBurnDenylistShards(user: PublicKey, mirageRecipient: string | Uint8Array, signer: PublicKey = user) {
+  let recipientKey: Uint8Array;
+  try {
+    recipientKey = mirageDenylistKey(mirageRecipient);
+  } catch {
+    recipientKey = new Uint8Array(32); // Undecodable; the program rejects the recipient first
+  }
+
+  return {
+    userDenylistShard: getDenylistShardPDA(solanaDenylistKey(user))[0],
+    signerDenylistShard: getDenylistShardPDA(solanaDenylistKey(signer))[0],
+    recipientDenylistShard: getDenylistShardPDA(recipientKey)[0],
+  };
+}
+
 export function fundAccount(pubkey: PublicKey, lamports: number = 10 * LAMPORTS_PER_SOL) {
   const { svm } = getTestContext();
   svm.airdrop(pubkey, BigInt(lamports));
@@ -142,6 +355,49 @@ export function setupTokenAccount(
   return ata;
 }
 
+// Token account at a random (non-ATA) address, optionally with a delegate,
+// as custodians and vault programs use
+export function setupNonAtaTokenAccount(
+  owner: PublicKey,
+  mint: PublicKey,
+  balance: bigint,
+  delegate?: PublicKey,
+  delegatedAmount: bigint = 0n
+): PublicKey {
+  const { svm } = getTestContext();
+
+  const address = Keypair.generate().publicKey;
+  const data = Buffer.alloc(ACCOUNT_SIZE);
+
+  AccountLayout.encode(
+    {
+      mint,
+      owner,
+      amount: balance,
+      delegateOption: delegate ? 1 : 0,
+      delegate: delegate ?? PublicKey.default,
+      delegatedAmount,
+      state: 1,
+      isNativeOption: 0,
+      isNative: 0n,
+      closeAuthorityOption: 0,
+      closeAuthority: PublicKey.default,
+    },
+    data
+  );
+
+  svm.setAccount(address, {
+    lamports: 1_000_000_000,
+    data,
+    owner: TOKEN_PROGRAM_ID,
+    executable: false,
+  });
+
+  updateMintSupply(mint, balance);
+
+  return address;
+}
+
 export function getTokenBalance(tokenAccount: PublicKey): bigint {
   const { svm } = getTestContext();
   const account = svm.getAccount(tokenAccount);
@@ -150,3 +406,58 @@ export function getTokenBalance(tokenAccount: PublicKey): bigint {
   const decoded = AccountLayout.decode(Buffer.from(account.data));
   return decoded.amount;
 }
+
+// Zero-copy registry layout: 8-byte discriminator, 64-byte header, then
+// `capacity` 96-byte entries of which the first `len` are in use
+export const REGISTRY_HEADER_END = 72;
+export const REGISTRY_ENTRY_SIZE = 96;
+
+export interface ValidatorRegistryData {
+  totalStake: BN;
+  valsetNonce: BN;
+  capacity: number;
+  version: number;
+  validators: { orchestratorPubkey: PublicKey; stake: BN; mirageValidator: string }[];
+}
+
+export function decodeValidatorRegistry(data: Buffer): ValidatorRegistryData {
+  const len = data.readUInt32LE(28);
+  const validators = [];
+  for (let i = 0; i < len; i++) {
+    const offset = REGISTRY_HEADER_END + i * REGISTRY_ENTRY_SIZE;
+    const addressLen = data.readUInt8(offset + 92);
+    validators.push({
+      orchestratorPubkey: new PublicKey(data.subarray(offset, offset + 32)),
+      stake: new BN(data.subarray(offset + 32, offset + 40), "le"),
+      mirageValidator: data.subarray(offset + 40, offset + 40 + addressLen).toString("utf8"),
+    });
+  }
+
+  return {
+    totalStake: new BN(data.subarray(8, 16), "le"),
+    valsetNonce: new BN(data.subarray(16, 24), "le"),
+    capacity: data.readUInt32LE(24),
+    version: data.readUInt8(33),
+    validators,
+  };
+}
+
+export function fetchValidatorRegistry(): ValidatorRegistryData {
+  const { svm } = getTestContext();
+  const [validatorRegistry] = getValidatorRegistryPDA();
+  const account = svm.getAccount(validatorRegistry);
+  if (!account) throw new Error("Validator registry not found");
+
+  return decodeValidatorRegistry(Buffer.from(account.data));
+}
+
+// Number of set bits in a MintRecord attestation bitmap
+export function countAttestations(bitmap: BN[]): number {
+  return bitmap.reduce((count, word) => {
+    let bits = 0;
+    for (let i = 0; i < 128; i++) {
+      if (word.testn(i)) bits++;
+    }
+    return count + bits;
+  }, 0);
+}
@@ -4,6 +4,7 @@ import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { MirageBridge } from "../../target/types/mirage_bridge";
 import { LiteSVM } from "litesvm";
 import IDL from "../../target/idl/mirage_bridge.json";
+import CallerIDL from "../../target/idl/bridge_caller.json";
 import { TestContext } from "./setup";
 
 const METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -18,6 +19,9 @@ export async function initializeTestContext(): Promise<TestContext> {
   const programId = new PublicKey(IDL.address);
   svm.addProgramFromFile(programId, "target/deploy/mirage_bridge.so");
 
+  // Example program that bridges through `bridge_cpi`
+  svm.addProgramFromFile(new PublicKey(CallerIDL.address), "target/deploy/bridge_caller.so");
+
   // Load Token Metadata program for metadata creation
   svm.addProgramFromFile(METADATA_PROGRAM_ID, "tests/programs/mpl_token_metadata.so");
 
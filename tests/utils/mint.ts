@@ -0,0 +1,195 @@
+import { Transaction, SystemProgram, Keypair, PublicKey, Ed25519Program, TransactionInstruction } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import BN from "bn.js";
+import { FailedTransactionMetadata } from "litesvm";
+import * as ed from "@noble/ed25519";
+import { sha512 } from "@noble/hashes/sha2.js";
+import { getTestContext } from "./setup";
+import {
+  getBridgeConfigPDA,
+  getMintPDA,
+  getValidatorRegistryPDA,
+  getMintRecordPDA,
+  getProcessedMintPDA,
+  getProgramId,
+  getGasReservePDA,
+  getDenylistShardPDA,
+  solanaDenylistKey,
+  generateBurnTxHash,
+} from "./helpers";
+
+// Required for @noble/ed25519 v3 to work synchronously
+ed.hashes.sha512 = sha512;
+
+const INSTRUCTIONS_SYSVAR = new PublicKey("Sysvar1nstructions1111111111111111111111111");
+
+// Chain ID the bridge is initialized with in the Initialize spec
+export const MIRAGE_CHAIN_ID = "mirage-1";
+
+export function buildAttestationPayload(
+  burnTxHash: Buffer,
+  mirageSender: string,
+  amount: BN,
+  recipient: PublicKey,
+  sequence: BN,
+  nativeDropoffLamports: BN = new BN(0),
+  mirageChainId: string = MIRAGE_CHAIN_ID
+): Buffer {
+  const chainLen = Buffer.alloc(4);
+  chainLen.writeUInt32LE(mirageChainId.length, 0);
+
+  const senderLen = Buffer.alloc(4);
+  senderLen.writeUInt32LE(mirageSender.length, 0);
+
+  return Buffer.concat([
+    Buffer.from("mirage-bridge:mint-attestation"),
+    getProgramId().toBuffer(),
+    chainLen,
+    Buffer.from(mirageChainId),
+    burnTxHash,
+    senderLen,
+    Buffer.from(mirageSender),
+    amount.toArrayLike(Buffer, "le", 8),
+    recipient.toBuffer(),
+    sequence.toArrayLike(Buffer, "le", 8),
+    nativeDropoffLamports.toArrayLike(Buffer, "le", 8),
+  ]);
+}
+
+// Each test uses a fresh sequence so replay protection never interferes
+let nextSequence = 1;
+
+export interface Transfer {
+  burnTxHash: Buffer;
+  mintRecord: PublicKey;
+  recipient: PublicKey;
+  mirageSender: string;
+  amount: BN;
+  sequence: BN;
+  nativeDropoffLamports: BN;
+  payload: Buffer;
+}
+
+export function newTransfer(
+  amount: BN,
+  mirageSender = "mirage1sender123",
+  recipient: PublicKey = Keypair.generate().publicKey,
+  nativeDropoffLamports: BN = new BN(0)
+): Transfer {
+  const burnTxHash = generateBurnTxHash();
+  const [mintRecord] = getMintRecordPDA(burnTxHash);
+  const sequence = new BN(nextSequence++);
+  return {
+    burnTxHash,
+    mintRecord,
+    recipient,
+    mirageSender,
+    amount,
+    sequence,
+    nativeDropoffLamports,
+    payload: buildAttestationPayload(burnTxHash, mirageSender, amount, recipient, sequence, nativeDropoffLamports),
+  };
+}
+
+export function signAttestation(orchestrator: Keypair, payload: Buffer) {
+  return Ed25519Program.createInstructionWithPublicKey({
+    publicKey: orchestrator.publicKey.toBytes(),
+    message: payload,
+    signature: Buffer.from(ed.sign(payload, orchestrator.secretKey.slice(0, 32))),
+  });
+}
+
+export function send(signer: Keypair, ...ixs: TransactionInstruction[]) {
+  const { svm } = getTestContext();
+  const tx = new Transaction();
+  tx.recentBlockhash = svm.latestBlockhash();
+  tx.add(...ixs);
+  tx.sign(signer);
+  return svm.sendTransaction(tx);
+}
+
+export async function setValidators(orchestrators: Keypair[], stake: number, label: string) {
+  const { program, authority } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [validatorRegistry] = getValidatorRegistryPDA();
+
+  const ix = await program.methods
+    .updateValidators({
+      validators: orchestrators.map((o, i) => ({
+        orchestratorPubkey: o.publicKey,
+        mirageValidator: `miragevaloper1${label}${i}`,
+        stake: new BN(stake),
+      })),
+    })
+    .accounts({
+      validatorManager: authority.publicKey,
+      bridgeConfig,
+      validatorRegistry,
+    })
+    .instruction();
+
+  const result = send(authority, ix);
+  if (result instanceof FailedTransactionMetadata) {
+    throw new Error(`Update validators failed: ${result.err().toString()}`);
+  }
+}
+
+export async function attestIx(relayer: PublicKey, transfer: Transfer) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [validatorRegistry] = getValidatorRegistryPDA();
+
+  return program.methods
+    .attest({
+      burnTxHash: Array.from(transfer.burnTxHash),
+      mirageSender: transfer.mirageSender,
+      amount: transfer.amount,
+      sequence: transfer.sequence,
+      nativeDropoffLamports: transfer.nativeDropoffLamports,
+    })
+    .accounts({
+      relayer,
+      recipient: transfer.recipient,
+      bridgeConfig,
+      mintRecord: transfer.mintRecord,
+      processedMint: getProcessedMintPDA(transfer.burnTxHash)[0],
+      validatorRegistry,
+      instructionsSysvar: INSTRUCTIONS_SYSVAR,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}
+
+export async function finalizeIx(
+  payer: PublicKey,
+  transfer: Transfer,
+  mintRecordPayer: PublicKey,
+  withRecipientTokenAccount = true
+) {
+  const { program } = getTestContext();
+  const [bridgeConfig] = getBridgeConfigPDA();
+  const [tokenMint] = getMintPDA();
+  const [validatorRegistry] = getValidatorRegistryPDA();
+
+  return program.methods
+    .finalizeMint({ burnTxHash: Array.from(transfer.burnTxHash) })
+    .accounts({
+      payer,
+      recipient: transfer.recipient,
+      mintRecordPayer,
+      recipientTokenAccount: withRecipientTokenAccount
+        ? getAssociatedTokenAddressSync(tokenMint, transfer.recipient, true)
+        : null,
+      tokenMint,
+      bridgeConfig,
+      mintRecord: transfer.mintRecord,
+      processedMint: getProcessedMintPDA(transfer.burnTxHash)[0],
+      validatorRegistry,
+      gasReserve: transfer.nativeDropoffLamports.isZero() ? null : getGasReservePDA()[0],
+      recipientDenylistShard: getDenylistShardPDA(solanaDenylistKey(transfer.recipient))[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+}